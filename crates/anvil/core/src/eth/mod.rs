@@ -768,6 +768,28 @@ pub enum EthRequest {
         serde(rename = "anvil_removePoolTransactions", with = "sequence")
     )]
     RemovePoolTransactions(Address),
+
+    /// zkSync's `zks_estimateFee` endpoint
+    /// Returns the fee parameters to use for the given zkSync transaction.
+    #[cfg_attr(feature = "serde", serde(rename = "zks_estimateFee", with = "sequence"))]
+    ZksEstimateFee(WithOtherFields<TransactionRequest>),
+
+    /// zkSync's `zks_getBytecodeByHash` endpoint
+    /// Returns the bytecode deployed in the zkVM with the given bytecode hash.
+    #[cfg_attr(feature = "serde", serde(rename = "zks_getBytecodeByHash", with = "sequence"))]
+    ZksGetBytecodeByHash(B256),
+
+    /// zkSync's `zks_L1ChainId` endpoint
+    #[cfg_attr(feature = "serde", serde(rename = "zks_L1ChainId", with = "empty_params"))]
+    ZksL1ChainId(()),
+
+    /// zkSync's `zks_getRawBlockTransactions` endpoint
+    /// Returns the EIP-2718 encoded transactions of the given block.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "zks_getRawBlockTransactions", with = "sequence")
+    )]
+    ZksGetRawBlockTransactions(BlockNumber),
 }
 
 /// Represents ethereum JSON-RPC API
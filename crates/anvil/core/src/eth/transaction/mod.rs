@@ -1,6 +1,9 @@
 //! Transaction related types
 
-use crate::eth::transaction::optimism::{DepositTransaction, DepositTransactionRequest};
+use crate::eth::transaction::{
+    optimism::{DepositTransaction, DepositTransactionRequest},
+    zksync::Eip712Transaction,
+};
 use alloy_consensus::{
     transaction::{
         eip4844::{TxEip4844, TxEip4844Variant, TxEip4844WithSidecar},
@@ -29,6 +32,7 @@ use serde::{Deserialize, Serialize};
 use std::ops::{Deref, Mul};
 
 pub mod optimism;
+pub mod zksync;
 
 /// Converts a [TransactionRequest] into a [TypedTransactionRequest].
 /// Should be removed once the call builder abstraction for providers is in place.
@@ -436,6 +440,29 @@ pub fn to_alloy_transaction_with_hash_and_sender(
             other: Default::default(),
             authorization_list: None,
         },
+        TypedTransaction::EIP712(t) => RpcTransaction {
+            hash,
+            nonce: t.nonce,
+            block_hash: None,
+            block_number: None,
+            transaction_index: None,
+            from,
+            to: Some(t.to),
+            value: t.value,
+            gas_price: Some(t.max_fee_per_gas),
+            max_fee_per_gas: Some(t.max_fee_per_gas),
+            max_priority_fee_per_gas: Some(t.max_priority_fee_per_gas),
+            gas: t.gas_limit,
+            input: t.input.clone(),
+            chain_id: Some(t.chain_id),
+            signature: None,
+            access_list: None,
+            transaction_type: Some(Eip712Transaction::TX_TYPE),
+            max_fee_per_blob_gas: None,
+            blob_versioned_hashes: None,
+            other: Default::default(),
+            authorization_list: None,
+        },
     }
 }
 
@@ -653,6 +680,18 @@ impl PendingTransaction {
                     ..Default::default()
                 }
             }
+            TypedTransaction::EIP712(tx) => TxEnv {
+                caller,
+                transact_to: tx.kind(),
+                data: tx.input.clone(),
+                chain_id: Some(tx.chain_id),
+                nonce: Some(tx.nonce),
+                value: tx.value,
+                gas_price: U256::from(tx.max_fee_per_gas),
+                gas_priority_fee: Some(U256::from(tx.max_priority_fee_per_gas)),
+                gas_limit: tx.gas_limit as u64,
+                ..Default::default()
+            },
         }
     }
 }
@@ -672,6 +711,8 @@ pub enum TypedTransaction {
     EIP7702(Signed<TxEip7702>),
     /// op-stack deposit transaction
     Deposit(DepositTransaction),
+    /// zkSync EIP-712 transaction
+    EIP712(Eip712Transaction),
 }
 
 impl TypedTransaction {
@@ -688,6 +729,7 @@ impl TypedTransaction {
             Self::EIP4844(tx) => tx.tx().tx().max_fee_per_gas,
             Self::EIP7702(tx) => tx.tx().max_fee_per_gas,
             Self::Deposit(_) => 0,
+            Self::EIP712(tx) => tx.max_fee_per_gas,
         }
     }
 
@@ -699,6 +741,7 @@ impl TypedTransaction {
             Self::EIP4844(tx) => tx.tx().tx().gas_limit,
             Self::EIP7702(tx) => tx.tx().gas_limit,
             Self::Deposit(tx) => tx.gas_limit,
+            Self::EIP712(tx) => tx.gas_limit,
         }
     }
 
@@ -710,6 +753,7 @@ impl TypedTransaction {
             Self::EIP4844(tx) => tx.tx().tx().value,
             Self::EIP7702(tx) => tx.tx().value,
             Self::Deposit(tx) => tx.value,
+            Self::EIP712(tx) => tx.value,
         })
     }
 
@@ -721,6 +765,7 @@ impl TypedTransaction {
             Self::EIP4844(tx) => &tx.tx().tx().input,
             Self::EIP7702(tx) => &tx.tx().input,
             Self::Deposit(tx) => &tx.input,
+            Self::EIP712(tx) => &tx.input,
         }
     }
 
@@ -733,6 +778,7 @@ impl TypedTransaction {
             Self::EIP4844(_) => Some(3),
             Self::EIP7702(_) => Some(4),
             Self::Deposit(_) => Some(0x7E),
+            Self::EIP712(_) => Some(Eip712Transaction::TX_TYPE),
         }
     }
 
@@ -853,6 +899,20 @@ impl TypedTransaction {
                 chain_id: t.chain_id(),
                 access_list: Default::default(),
             },
+            Self::EIP712(t) => TransactionEssentials {
+                kind: t.kind(),
+                input: t.input.clone(),
+                nonce: t.nonce,
+                gas_limit: t.gas_limit,
+                gas_price: None,
+                max_fee_per_gas: Some(t.max_fee_per_gas),
+                max_priority_fee_per_gas: Some(t.max_priority_fee_per_gas),
+                max_fee_per_blob_gas: None,
+                blob_versioned_hashes: None,
+                value: t.value,
+                chain_id: Some(t.chain_id),
+                access_list: Default::default(),
+            },
         }
     }
 
@@ -864,6 +924,7 @@ impl TypedTransaction {
             Self::EIP4844(t) => t.tx().tx().nonce,
             Self::EIP7702(t) => t.tx().nonce,
             Self::Deposit(t) => t.nonce,
+            Self::EIP712(t) => t.nonce,
         }
     }
 
@@ -875,6 +936,7 @@ impl TypedTransaction {
             Self::EIP4844(t) => Some(t.tx().tx().chain_id),
             Self::EIP7702(t) => Some(t.tx().chain_id),
             Self::Deposit(t) => t.chain_id(),
+            Self::EIP712(t) => Some(t.chain_id),
         }
     }
 
@@ -905,6 +967,11 @@ impl TypedTransaction {
         matches!(self, Self::EIP4844(_))
     }
 
+    /// Returns true whether this tx is a zkSync EIP712 transaction
+    pub fn is_eip712(&self) -> bool {
+        matches!(self, Self::EIP712(_))
+    }
+
     /// Returns the hash of the transaction.
     ///
     /// Note: If this transaction has the Impersonated signature then this returns a modified unique
@@ -917,6 +984,7 @@ impl TypedTransaction {
             Self::EIP4844(t) => *t.hash(),
             Self::EIP7702(t) => *t.hash(),
             Self::Deposit(t) => t.hash(),
+            Self::EIP712(t) => t.hash(),
         }
     }
 
@@ -940,6 +1008,7 @@ impl TypedTransaction {
             Self::EIP4844(tx) => tx.recover_signer(),
             Self::EIP7702(tx) => tx.recover_signer(),
             Self::Deposit(tx) => tx.recover(),
+            Self::EIP712(tx) => tx.recover(),
         }
     }
 
@@ -952,6 +1021,7 @@ impl TypedTransaction {
             Self::EIP4844(tx) => TxKind::Call(tx.tx().tx().to),
             Self::EIP7702(tx) => tx.tx().to,
             Self::Deposit(tx) => tx.kind,
+            Self::EIP712(tx) => tx.kind(),
         }
     }
 
@@ -968,7 +1038,7 @@ impl TypedTransaction {
             Self::EIP1559(tx) => *tx.signature(),
             Self::EIP4844(tx) => *tx.signature(),
             Self::EIP7702(tx) => *tx.signature(),
-            Self::Deposit(_) => Signature::from_scalars_and_parity(
+            Self::Deposit(_) | Self::EIP712(_) => Signature::from_scalars_and_parity(
                 B256::with_last_byte(1),
                 B256::with_last_byte(1),
                 Parity::Parity(false),
@@ -1098,10 +1168,17 @@ impl Decodable for TypedTransaction {
         // Check byte after header
         let ty = *h_decode_copy.first().ok_or(alloy_rlp::Error::Custom("empty slice"))?;
 
-        if ty != 0x7E {
-            Ok(TxEnvelope::decode(buf)?.into())
-        } else {
-            Ok(Self::Deposit(DepositTransaction::decode(&mut h_decode_copy)?))
+        match ty {
+            0x7E => Ok(Self::Deposit(DepositTransaction::decode(&mut h_decode_copy)?)),
+            Eip712Transaction::TX_TYPE => {
+                if header.payload_length > h_decode_copy.len() {
+                    return Err(alloy_rlp::Error::InputTooShort);
+                }
+                let tx = Eip712Transaction::decode(&mut &h_decode_copy[1..header.payload_length])?;
+                *buf = &h_decode_copy[header.payload_length..];
+                Ok(Self::EIP712(tx))
+            }
+            _ => Ok(TxEnvelope::decode(buf)?.into()),
         }
     }
 }
@@ -1122,6 +1199,7 @@ impl Encodable2718 for TypedTransaction {
                 Header { list: true, payload_length }.length() + payload_length + 1
             }
             Self::Deposit(tx) => 1 + tx.length(),
+            Self::EIP712(tx) => tx.raw.len(),
         }
     }
 
@@ -1136,6 +1214,7 @@ impl Encodable2718 for TypedTransaction {
                 out.put_u8(0x7E);
                 tx.encode(out);
             }
+            Self::EIP712(tx) => out.put_slice(&tx.raw),
         }
     }
 }
@@ -1145,6 +1224,7 @@ impl Decodable2718 for TypedTransaction {
         match ty {
             0x04 => return Ok(Self::EIP7702(TxEip7702::decode_signed_fields(buf)?)),
             0x7E => return Ok(Self::Deposit(DepositTransaction::decode(buf)?)),
+            Eip712Transaction::TX_TYPE => return Ok(Self::EIP712(Eip712Transaction::decode(buf)?)),
             _ => {}
         }
         match TxEnvelope::typed_decode(ty, buf)? {
@@ -1319,6 +1399,8 @@ pub enum TypedReceipt<T = alloy_primitives::Log> {
     EIP7702(ReceiptWithBloom<T>),
     #[serde(rename = "0x7E", alias = "0x7e")]
     Deposit(DepositReceipt<T>),
+    #[serde(rename = "0x71")]
+    EIP712(ReceiptWithBloom<T>),
 }
 
 impl<T> TypedReceipt<T> {
@@ -1328,7 +1410,8 @@ impl<T> TypedReceipt<T> {
             Self::EIP1559(r) |
             Self::EIP2930(r) |
             Self::EIP4844(r) |
-            Self::EIP7702(r) => r,
+            Self::EIP7702(r) |
+            Self::EIP712(r) => r,
             Self::Deposit(r) => &r.inner,
         }
     }
@@ -1341,7 +1424,8 @@ impl<T> From<TypedReceipt<T>> for ReceiptWithBloom<T> {
             TypedReceipt::EIP1559(r) |
             TypedReceipt::EIP2930(r) |
             TypedReceipt::EIP4844(r) |
-            TypedReceipt::EIP7702(r) => r,
+            TypedReceipt::EIP7702(r) |
+            TypedReceipt::EIP712(r) => r,
             TypedReceipt::Deposit(r) => r.inner,
         }
     }
@@ -1356,6 +1440,7 @@ impl From<TypedReceipt<alloy_rpc_types::Log>> for OtsReceipt {
             TypedReceipt::EIP4844(_) => 0x03,
             TypedReceipt::EIP7702(_) => 0x04,
             TypedReceipt::Deposit(_) => 0x7E,
+            TypedReceipt::EIP712(_) => Eip712Transaction::TX_TYPE,
         } as u8;
         let receipt = ReceiptWithBloom::<alloy_rpc_types::Log>::from(value);
         let status = receipt.status();
@@ -1403,6 +1488,7 @@ impl Encodable for TypedReceipt {
                     Self::EIP1559(r) => r.length() + 1,
                     Self::EIP4844(r) => r.length() + 1,
                     Self::Deposit(r) => r.length() + 1,
+                    Self::EIP712(r) => r.length() + 1,
                     _ => unreachable!("receipt already matched"),
                 };
 
@@ -1427,6 +1513,11 @@ impl Encodable for TypedReceipt {
                         0x7Eu8.encode(out);
                         r.encode(out);
                     }
+                    Self::EIP712(r) => {
+                        Header { list: true, payload_length: payload_len }.encode(out);
+                        Eip712Transaction::TX_TYPE.encode(out);
+                        r.encode(out);
+                    }
                     _ => unreachable!("receipt already matched"),
                 }
             }
@@ -1465,6 +1556,9 @@ impl Decodable for TypedReceipt {
                 } else if receipt_type == 0x7E {
                     buf.advance(1);
                     <DepositReceipt as Decodable>::decode(buf).map(TypedReceipt::Deposit)
+                } else if receipt_type == Eip712Transaction::TX_TYPE {
+                    buf.advance(1);
+                    <ReceiptWithBloom as Decodable>::decode(buf).map(TypedReceipt::EIP712)
                 } else {
                     Err(alloy_rlp::Error::Custom("invalid receipt type"))
                 }
@@ -1488,6 +1582,7 @@ impl Encodable2718 for TypedReceipt {
             Self::EIP4844(_) => Some(3),
             Self::EIP7702(_) => Some(4),
             Self::Deposit(_) => Some(0x7E),
+            Self::EIP712(_) => Some(Eip712Transaction::TX_TYPE),
         }
     }
 
@@ -1497,7 +1592,7 @@ impl Encodable2718 for TypedReceipt {
             Self::EIP2930(r) => ReceiptEnvelope::Eip2930(r.clone()).encode_2718_len(),
             Self::EIP1559(r) => ReceiptEnvelope::Eip1559(r.clone()).encode_2718_len(),
             Self::EIP4844(r) => ReceiptEnvelope::Eip4844(r.clone()).encode_2718_len(),
-            Self::EIP7702(r) => 1 + r.length(),
+            Self::EIP7702(r) | Self::EIP712(r) => 1 + r.length(),
             Self::Deposit(r) => 1 + r.length(),
         }
     }
@@ -1511,7 +1606,8 @@ impl Encodable2718 for TypedReceipt {
             Self::EIP2930(r) |
             Self::EIP1559(r) |
             Self::EIP4844(r) |
-            Self::EIP7702(r) => r.encode(out),
+            Self::EIP7702(r) |
+            Self::EIP712(r) => r.encode(out),
             Self::Deposit(r) => r.encode(out),
         }
     }
@@ -1522,6 +1618,9 @@ impl Decodable2718 for TypedReceipt {
         if ty == 0x7E {
             return Ok(Self::Deposit(DepositReceipt::decode(buf)?));
         }
+        if ty == Eip712Transaction::TX_TYPE {
+            return Ok(Self::EIP712(<ReceiptWithBloom as Decodable>::decode(buf)?));
+        }
         match ReceiptEnvelope::typed_decode(ty, buf)? {
             ReceiptEnvelope::Eip2930(tx) => Ok(Self::EIP2930(tx)),
             ReceiptEnvelope::Eip1559(tx) => Ok(Self::EIP1559(tx)),
@@ -1581,6 +1680,7 @@ pub fn convert_to_anvil_receipt(receipt: AnyTransactionReceipt) -> Option<Receip
             0x01 => TypedReceipt::EIP2930(receipt_with_bloom),
             0x02 => TypedReceipt::EIP1559(receipt_with_bloom),
             0x03 => TypedReceipt::EIP4844(receipt_with_bloom),
+            Eip712Transaction::TX_TYPE => TypedReceipt::EIP712(receipt_with_bloom),
             0x7E => TypedReceipt::Deposit(DepositReceipt {
                 inner: receipt_with_bloom,
                 deposit_nonce: other
@@ -0,0 +1,144 @@
+//! zkSync EIP-712 transactions, see <https://docs.zksync.io/zk-stack/concepts/transaction-lifecycle#eip-712-0x71>
+
+use alloy_primitives::{Address, Bytes, TxKind, B256, U256};
+use alloy_rlp::Header as RlpHeader;
+use bytes::Buf;
+use foundry_zksync_core::{
+    convert::{ConvertAddress, ConvertH160, ConvertH256, ConvertU256},
+    PaymasterParams, CONTRACT_DEPLOYER_ADDRESS,
+};
+use serde::{Deserialize, Serialize};
+use zksync_types::{transaction_request::TransactionRequest, L2ChainId};
+
+/// Position of the `chain_id` field in the RLP list of an EIP-712 transaction.
+const CHAIN_ID_FIELD_INDEX: usize = 10;
+
+/// A signed zkSync EIP-712 transaction (type `0x71`).
+///
+/// These transactions carry the factory dependencies and paymaster parameters of the transaction,
+/// and are only executed by anvil when running in zkSync mode.
+///
+/// The transaction is decoded with zkSync's encoding rules, which also verify its signature and
+/// compute its hash, the raw encoding is kept so it can be re-encoded as is.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Eip712Transaction {
+    pub hash: B256,
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub from: Address,
+    pub to: Address,
+    pub value: U256,
+    pub input: Bytes,
+    pub gas_limit: u128,
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+    pub gas_per_pubdata_limit: U256,
+    pub factory_deps: Vec<Bytes>,
+    pub paymaster: Address,
+    pub paymaster_input: Bytes,
+    /// The EIP-2718 encoding of the transaction, including the type byte.
+    pub raw: Bytes,
+}
+
+impl Eip712Transaction {
+    /// The EIP-2718 transaction type.
+    pub const TX_TYPE: u8 = 0x71;
+
+    pub fn hash(&self) -> B256 {
+        self.hash
+    }
+
+    /// Returns the sender of the transaction, the signature was already verified when decoding.
+    pub fn recover(&self) -> Result<Address, alloy_primitives::SignatureError> {
+        Ok(self.from)
+    }
+
+    /// Returns the kind of the transaction.
+    ///
+    /// Contracts are deployed by calling the `ContractDeployer` system contract, so these calls
+    /// are considered to be creations.
+    pub fn kind(&self) -> TxKind {
+        if self.to == CONTRACT_DEPLOYER_ADDRESS.to_address() {
+            TxKind::Create
+        } else {
+            TxKind::Call(self.to)
+        }
+    }
+
+    /// Returns the factory dependencies of the transaction.
+    pub fn factory_deps(&self) -> Vec<Vec<u8>> {
+        self.factory_deps.iter().map(|dep| dep.to_vec()).collect()
+    }
+
+    /// Returns the paymaster parameters of the transaction, if it uses a paymaster.
+    pub fn paymaster_params(&self) -> Option<PaymasterParams> {
+        (!self.paymaster.is_zero()).then(|| PaymasterParams {
+            paymaster: self.paymaster.to_h160(),
+            paymaster_input: self.paymaster_input.to_vec(),
+        })
+    }
+
+    /// Decodes the RLP encoded transaction, _without_ the leading type byte.
+    pub fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let encoded = *buf;
+        let header = RlpHeader::decode(buf)?;
+        if !header.list {
+            return Err(alloy_rlp::Error::UnexpectedString);
+        }
+        if header.payload_length > buf.len() {
+            return Err(alloy_rlp::Error::InputTooShort);
+        }
+        let chain_id = decode_chain_id(&buf[..header.payload_length])?;
+        let encoded_len = encoded.len() - buf.len() + header.payload_length;
+        buf.advance(header.payload_length);
+
+        let mut raw = Vec::with_capacity(1 + encoded_len);
+        raw.push(Self::TX_TYPE);
+        raw.extend_from_slice(&encoded[..encoded_len]);
+
+        Self::from_raw(raw.into(), chain_id)
+            .ok_or(alloy_rlp::Error::Custom("invalid zkSync EIP-712 transaction"))
+    }
+
+    /// Decodes and verifies the given EIP-2718 encoded transaction for the given chain.
+    fn from_raw(raw: Bytes, chain_id: u64) -> Option<Self> {
+        let l2_chain_id = L2ChainId::from(u32::try_from(chain_id).ok()?);
+        let (request, hash) = TransactionRequest::from_bytes(&raw, l2_chain_id).ok()?;
+        let meta = request.eip712_meta?;
+        let paymaster = meta.paymaster_params.unwrap_or_default();
+
+        Some(Self {
+            hash: hash.to_b256(),
+            chain_id,
+            nonce: request.nonce.as_u64(),
+            from: request.from?.to_address(),
+            to: request.to.unwrap_or_default().to_address(),
+            value: request.value.to_ru256(),
+            input: request.input.0.into(),
+            gas_limit: request.gas.as_u128(),
+            max_fee_per_gas: request.gas_price.as_u128(),
+            max_priority_fee_per_gas: request
+                .max_priority_fee_per_gas
+                .unwrap_or_default()
+                .as_u128(),
+            gas_per_pubdata_limit: meta.gas_per_pubdata.to_ru256(),
+            factory_deps: meta.factory_deps.into_iter().map(Into::into).collect(),
+            paymaster: paymaster.paymaster.to_address(),
+            paymaster_input: paymaster.paymaster_input.into(),
+            raw,
+        })
+    }
+}
+
+/// Reads the chain id out of the fields of an RLP encoded EIP-712 transaction, which is needed to
+/// verify the signature of the transaction.
+fn decode_chain_id(mut fields: &[u8]) -> alloy_rlp::Result<u64> {
+    for _ in 0..CHAIN_ID_FIELD_INDEX {
+        let header = RlpHeader::decode(&mut fields)?;
+        if header.payload_length > fields.len() {
+            return Err(alloy_rlp::Error::InputTooShort);
+        }
+        fields.advance(header.payload_length);
+    }
+    alloy_rlp::Decodable::decode(&mut fields)
+}
@@ -238,6 +238,7 @@ impl NodeArgs {
             .with_init_state(self.load_state.or_else(|| self.state.and_then(|s| s.state)))
             .with_transaction_block_keeper(self.transaction_block_keeper)
             .with_optimism(self.evm_opts.optimism)
+            .with_zksync(self.evm_opts.zksync)
             .with_disable_default_create2_deployer(self.evm_opts.disable_default_create2_deployer)
             .with_slots_in_an_epoch(self.slots_in_an_epoch)
             .with_memory_limit(self.evm_opts.memory_limit)
@@ -520,6 +521,10 @@ pub struct AnvilEvmArgs {
     #[arg(long, visible_alias = "optimism")]
     pub optimism: bool,
 
+    /// Run a zkSync chain, executing transactions in the zkVM
+    #[arg(long, conflicts_with = "optimism")]
+    pub zksync: bool,
+
     /// Disable the default create2 deployer
     #[arg(long, visible_alias = "no-create2")]
     pub disable_default_create2_deployer: bool,
@@ -173,6 +173,8 @@ pub struct NodeConfig {
     pub disable_default_create2_deployer: bool,
     /// Enable Optimism deposit transaction
     pub enable_optimism: bool,
+    /// Execute transactions in the zkVM
+    pub enable_zksync: bool,
     /// Slots in an epoch
     pub slots_in_an_epoch: u64,
     /// The memory limit per EVM execution in bytes.
@@ -428,6 +430,7 @@ impl Default for NodeConfig {
             transaction_block_keeper: None,
             disable_default_create2_deployer: false,
             enable_optimism: false,
+            enable_zksync: false,
             slots_in_an_epoch: 32,
             memory_limit: None,
             precompile_factory: None,
@@ -869,6 +872,13 @@ impl NodeConfig {
         self
     }
 
+    /// Sets whether to execute transactions in the zkVM
+    #[must_use]
+    pub fn with_zksync(mut self, enable_zksync: bool) -> Self {
+        self.enable_zksync = enable_zksync;
+        self
+    }
+
     /// Sets whether to disable the default create2 deployer
     #[must_use]
     pub fn with_disable_default_create2_deployer(mut self, yes: bool) -> Self {
@@ -440,6 +440,16 @@ impl EthApi {
             EthRequest::RemovePoolTransactions(address) => {
                 self.anvil_remove_pool_transactions(address).await.to_rpc_result()
             }
+            EthRequest::ZksEstimateFee(request) => {
+                self.zks_estimate_fee(request).await.to_rpc_result()
+            }
+            EthRequest::ZksGetBytecodeByHash(hash) => {
+                self.zks_get_bytecode_by_hash(hash).await.to_rpc_result()
+            }
+            EthRequest::ZksL1ChainId(_) => self.zks_l1_chain_id().await.to_rpc_result(),
+            EthRequest::ZksGetRawBlockTransactions(num) => {
+                self.zks_get_raw_block_transactions(num).await.to_rpc_result()
+            }
         }
     }
 
@@ -1418,7 +1428,7 @@ impl EthApi {
     /// Returns the suggested fee cap.
     ///
     /// Returns at least [MIN_SUGGESTED_PRIORITY_FEE]
-    fn lowest_suggestion_tip(&self) -> u128 {
+    pub(crate) fn lowest_suggestion_tip(&self) -> u128 {
         let block_number = self.backend.best_number();
         let latest_cached_block = self.fee_history_cache.lock().get(&block_number).cloned();
 
@@ -2710,6 +2720,7 @@ impl EthApi {
             TypedTransaction::EIP4844(_) => self.backend.ensure_eip4844_active(),
             TypedTransaction::EIP7702(_) => self.backend.ensure_eip7702_active(),
             TypedTransaction::Deposit(_) => self.backend.ensure_op_deposits_active(),
+            TypedTransaction::EIP712(_) => self.backend.ensure_zksync_active(),
             TypedTransaction::Legacy(_) => Ok(()),
         }
     }
@@ -1,6 +1,6 @@
 use crate::{
     eth::{
-        backend::{db::Db, validate::TransactionValidator},
+        backend::{db::Db, validate::TransactionValidator, zksync},
         error::InvalidTransactionError,
         pool::transactions::PoolTransaction,
     },
@@ -24,13 +24,15 @@ use foundry_evm::{
         interpreter::InstructionResult,
         primitives::{
             BlockEnv, CfgEnvWithHandlerCfg, EVMError, EnvWithHandlerCfg, ExecutionResult, Output,
-            SpecId,
+            ResultAndState, SpecId,
         },
+        DatabaseCommit,
     },
     traces::CallTraceNode,
 };
+use foundry_zksync_core::H256;
 use revm::primitives::MAX_BLOB_GAS_PER_BLOCK;
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 /// Represents an executed transaction (transacted on the DB)
 #[derive(Debug)]
@@ -72,6 +74,7 @@ impl ExecutedTransaction {
                 deposit_nonce: Some(tx.nonce),
                 deposit_receipt_version: Some(1),
             }),
+            TypedTransaction::EIP712(_) => TypedReceipt::EIP712(receipt_with_bloom),
         }
     }
 }
@@ -108,6 +111,8 @@ pub struct TransactionExecutor<'a, Db: ?Sized, Validator: TransactionValidator>
     pub print_logs: bool,
     /// Precompiles to inject to the EVM.
     pub precompile_factory: Option<Arc<dyn PrecompileFactory>>,
+    /// The bytecodes deployed in the zkVM, set if transactions are executed in the zkVM.
+    pub zk_factory_deps: Option<&'a mut HashMap<H256, Vec<u8>>>,
 }
 
 impl<'a, DB: Db + ?Sized, Validator: TransactionValidator> TransactionExecutor<'a, DB, Validator> {
@@ -269,11 +274,16 @@ impl<'a, 'b, DB: Db + ?Sized, Validator: TransactionValidator> Iterator
     fn next(&mut self) -> Option<Self::Item> {
         let transaction = self.pending.next()?;
         let sender = *transaction.pending_transaction.sender();
-        let account = match self.db.basic(sender).map(|acc| acc.unwrap_or_default()) {
+        let account = if self.zk_factory_deps.is_some() {
+            zksync::account_info(&*self.db, sender)
+        } else {
+            self.db.basic(sender).map(|acc| acc.unwrap_or_default())
+        };
+        let account = match account {
             Ok(account) => account,
             Err(err) => return Some(TransactionExecutionOutcome::DatabaseError(transaction, err)),
         };
-        let env = self.env_for(&transaction.pending_transaction);
+        let mut env = self.env_for(&transaction.pending_transaction);
 
         // check that we comply with the block's gas limit, if not disabled
         let max_gas = self.gas_used.saturating_add(env.tx.gas_limit as u128);
@@ -310,7 +320,32 @@ impl<'a, 'b, DB: Db + ?Sized, Validator: TransactionValidator> Iterator
             inspector = inspector.with_log_collector();
         }
 
-        let exec_result = {
+        let exec_result = if let Some(zk_factory_deps) = self.zk_factory_deps.as_deref_mut() {
+            let (factory_deps, paymaster_params) =
+                match &transaction.pending_transaction.transaction.transaction {
+                    TypedTransaction::EIP712(tx) => (tx.factory_deps(), tx.paymaster_params()),
+                    _ => Default::default(),
+                };
+
+            trace!(target: "backend", "[{:?}] executing in zkVM", transaction.hash());
+            match zksync::transact(
+                &mut &mut *self.db,
+                &mut env.env,
+                factory_deps,
+                paymaster_params,
+                zk_factory_deps,
+                &mut inspector,
+            ) {
+                Ok(ResultAndState { result, state }) => {
+                    self.db.commit(state);
+                    result
+                }
+                Err(err) => {
+                    warn!(target: "backend", "[{:?}] failed to execute: {:?}", transaction.hash(), err);
+                    return Some(TransactionExecutionOutcome::Invalid(transaction, err))
+                }
+            }
+        } else {
             let mut evm =
                 foundry_evm::utils::new_evm_with_inspector(&mut *self.db, env, &mut inspector);
             if let Some(factory) = &self.precompile_factory {
@@ -17,6 +17,7 @@ use crate::{
             notifications::{NewBlockNotification, NewBlockNotifications},
             time::{utc_from_secs, TimeManager},
             validate::TransactionValidator,
+            zksync,
         },
         error::{BlockchainError, ErrDetail, InvalidTransactionError},
         fees::{FeeDetails, FeeManager, MIN_SUGGESTED_PRIORITY_FEE},
@@ -85,6 +86,7 @@ use foundry_evm::{
     utils::new_evm_with_inspector_ref,
     InspectorExt,
 };
+use foundry_zksync_core::H256;
 use futures::channel::mpsc::{unbounded, UnboundedSender};
 use parking_lot::{Mutex, RwLock};
 use revm::{
@@ -185,6 +187,10 @@ pub struct Backend {
     slots_in_an_epoch: u64,
     /// Precompiles to inject to the EVM.
     precompile_factory: Option<Arc<dyn PrecompileFactory>>,
+    /// Whether transactions are executed in the zkVM
+    zksync: bool,
+    /// Bytecodes deployed in the zkVM, by their bytecode hash
+    zk_factory_deps: Arc<RwLock<HashMap<H256, Vec<u8>>>>,
 }
 
 impl Backend {
@@ -232,9 +238,9 @@ impl Backend {
             Default::default()
         };
 
-        let (slots_in_an_epoch, precompile_factory) = {
+        let (slots_in_an_epoch, precompile_factory, zksync) = {
             let cfg = node_config.read().await;
-            (cfg.slots_in_an_epoch, cfg.precompile_factory.clone())
+            (cfg.slots_in_an_epoch, cfg.precompile_factory.clone(), cfg.enable_zksync)
         };
 
         let backend = Self {
@@ -256,6 +262,8 @@ impl Backend {
             node_config,
             slots_in_an_epoch,
             precompile_factory,
+            zksync,
+            zk_factory_deps: Default::default(),
         };
 
         if let Some(interval_block_time) = automine_block_time {
@@ -308,10 +316,16 @@ impl Backend {
                 let (address, mut info) = res.unwrap()?;
                 info.balance = self.genesis.balance;
                 db.insert_account(address, info.clone());
+                if self.zksync {
+                    zksync::set_balance(&mut **db, address, self.genesis.balance)?;
+                }
             }
         } else {
             let mut db = self.db.write().await;
             for (account, info) in self.genesis.account_infos() {
+                if self.zksync {
+                    zksync::set_balance(&mut **db, account, info.balance)?;
+                }
                 db.insert_account(account, info);
             }
 
@@ -363,7 +377,11 @@ impl Backend {
 
     /// Returns the `AccountInfo` from the database
     pub async fn get_account(&self, address: Address) -> DatabaseResult<AccountInfo> {
-        Ok(self.db.read().await.basic_ref(address)?.unwrap_or_default())
+        let db = self.db.read().await;
+        if self.zksync {
+            return zksync::account_info(&**db, address)
+        }
+        Ok(db.basic_ref(address)?.unwrap_or_default())
     }
 
     /// Whether we're forked off some remote client
@@ -532,12 +550,21 @@ impl Backend {
 
     /// Sets the nonce of the given address
     pub async fn set_nonce(&self, address: Address, nonce: U256) -> DatabaseResult<()> {
-        self.db.write().await.set_nonce(address, nonce.try_into().unwrap_or(u64::MAX))
+        let nonce = nonce.try_into().unwrap_or(u64::MAX);
+        let mut db = self.db.write().await;
+        if self.zksync {
+            zksync::set_nonce(&mut **db, address, nonce)?;
+        }
+        db.set_nonce(address, nonce)
     }
 
     /// Sets the balance of the given address
     pub async fn set_balance(&self, address: Address, balance: U256) -> DatabaseResult<()> {
-        self.db.write().await.set_balance(address, balance)
+        let mut db = self.db.write().await;
+        if self.zksync {
+            zksync::set_balance(&mut **db, address, balance)?;
+        }
+        db.set_balance(address, balance)
     }
 
     /// Sets the code of the given address
@@ -590,6 +617,16 @@ impl Backend {
         self.env.read().handler_cfg.is_optimism
     }
 
+    /// Returns true if transactions are executed in the zkVM
+    pub fn is_zksync(&self) -> bool {
+        self.zksync
+    }
+
+    /// Returns the bytecode deployed in the zkVM with the given bytecode hash
+    pub fn zk_bytecode_by_hash(&self, hash: B256) -> Option<Bytes> {
+        self.zk_factory_deps.read().get(&H256(hash.0)).cloned().map(Into::into)
+    }
+
     /// Returns an error if EIP1559 is not active (pre Berlin)
     pub fn ensure_eip1559_active(&self) -> Result<(), BlockchainError> {
         if self.is_eip1559() {
@@ -628,6 +665,22 @@ impl Backend {
         Err(BlockchainError::DepositTransactionUnsupported)
     }
 
+    /// Returns an error if zkSync EIP-712 transactions are not supported
+    pub fn ensure_zksync_active(&self) -> Result<(), BlockchainError> {
+        if self.is_zksync() {
+            return Ok(())
+        }
+        Err(BlockchainError::EIP712TransactionUnsupported)
+    }
+
+    /// Returns an error if zkSync mode is not enabled
+    pub fn ensure_zksync_rpc_active(&self) -> Result<(), BlockchainError> {
+        if self.is_zksync() {
+            return Ok(())
+        }
+        Err(BlockchainError::ZkSyncUnsupported)
+    }
+
     /// Returns the block gas limit
     pub fn gas_limit(&self) -> u128 {
         self.env.read().block.gas_limit.to()
@@ -886,6 +939,8 @@ impl Backend {
         let env = self.next_env();
 
         let mut cache_db = CacheDB::new(&*db);
+        // bytecodes deployed by pending transactions are discarded with the pending block
+        let mut zk_factory_deps = self.zksync.then(|| self.zk_factory_deps.read().clone());
 
         let storage = self.blockchain.storage.read();
 
@@ -902,6 +957,7 @@ impl Backend {
             enable_steps_tracing: self.enable_steps_tracing,
             print_logs: self.print_logs,
             precompile_factory: self.precompile_factory.clone(),
+            zk_factory_deps: zk_factory_deps.as_mut(),
         };
 
         // create a new pending block
@@ -956,6 +1012,7 @@ impl Backend {
 
             let (executed_tx, block_hash) = {
                 let mut db = self.db.write().await;
+                let mut zk_factory_deps = self.zk_factory_deps.write();
 
                 // finally set the next block timestamp, this is done just before execution, because
                 // there can be concurrent requests that can delay acquiring the db lock and we want
@@ -974,6 +1031,7 @@ impl Backend {
                     enable_steps_tracing: self.enable_steps_tracing,
                     print_logs: self.print_logs,
                     precompile_factory: self.precompile_factory.clone(),
+                    zk_factory_deps: self.zksync.then_some(&mut *zk_factory_deps),
                 };
                 let executed_tx = executor.execute();
 
@@ -1202,9 +1260,22 @@ impl Backend {
     {
         let mut inspector = Inspector::default();
 
-        let env = self.build_call_env(request, fee_details, block_env);
-        let mut evm = self.new_evm_with_inspector_ref(state, env, &mut inspector);
-        let ResultAndState { result, state } = evm.transact()?;
+        let (factory_deps, paymaster_params) = zksync::request_meta(&request);
+        let mut env = self.build_call_env(request, fee_details, block_env);
+        let ResultAndState { result, state } = if self.zksync {
+            let mut zk_factory_deps = self.zk_factory_deps.read().clone();
+            zksync::transact(
+                &mut CacheDB::new(state),
+                &mut env.env,
+                factory_deps,
+                paymaster_params,
+                &mut zk_factory_deps,
+                &mut inspector,
+            )?
+        } else {
+            let mut evm = self.new_evm_with_inspector_ref(state, env, &mut inspector);
+            evm.transact()?
+        };
         let (exit_reason, gas_used, out) = match result {
             ExecutionResult::Success { reason, gas_used, output, .. } => {
                 (reason.into(), gas_used, Some(output))
@@ -1214,7 +1285,6 @@ impl Backend {
             }
             ExecutionResult::Halt { reason, gas_used } => (reason.into(), gas_used, None),
         };
-        drop(evm);
         inspector.print_logs();
         Ok((exit_reason, out, gas_used as u128, state))
     }
@@ -1903,6 +1973,9 @@ impl Backend {
         D: DatabaseRef<Error = DatabaseError>,
     {
         trace!(target: "backend", "get balance for {:?}", address);
+        if self.zksync {
+            return Ok(zksync::balance(state, address)?)
+        }
         Ok(state.basic_ref(address)?.unwrap_or_default().balance)
     }
 
@@ -1926,6 +1999,9 @@ impl Backend {
 
         self.with_database_at(Some(final_block_request), |db, _| {
             trace!(target: "backend", "get nonce for {:?}", address);
+            if self.zksync {
+                return Ok(zksync::nonce(db, address)?)
+            }
             Ok(db.basic_ref(address)?.unwrap_or_default().nonce)
         })
         .await?
@@ -2153,6 +2229,12 @@ impl Backend {
                 .unwrap_or_else(|| self.base_fee())
                 .saturating_add(t.tx().max_priority_fee_per_gas),
             TypedTransaction::Deposit(_) => 0_u128,
+            TypedTransaction::EIP712(t) => block
+                .header
+                .base_fee_per_gas
+                .unwrap_or_else(|| self.base_fee())
+                .saturating_add(t.max_priority_fee_per_gas)
+                .min(t.max_fee_per_gas),
         };
 
         let receipts = self.get_receipts(block.transactions.iter().map(|tx| tx.hash()));
@@ -2187,6 +2269,7 @@ impl Backend {
             TypedReceipt::EIP2930(_) => TypedReceipt::EIP2930(receipt_with_bloom),
             TypedReceipt::EIP4844(_) => TypedReceipt::EIP4844(receipt_with_bloom),
             TypedReceipt::EIP7702(_) => TypedReceipt::EIP7702(receipt_with_bloom),
+            TypedReceipt::EIP712(_) => TypedReceipt::EIP712(receipt_with_bloom),
             TypedReceipt::Deposit(r) => TypedReceipt::Deposit(DepositReceipt {
                 inner: receipt_with_bloom,
                 deposit_nonce: r.deposit_nonce,
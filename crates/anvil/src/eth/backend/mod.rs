@@ -14,3 +14,4 @@ pub mod genesis;
 pub mod info;
 pub mod notifications;
 pub mod validate;
+pub mod zksync;
@@ -0,0 +1,173 @@
+//! Support for running anvil as a zkSync node, see `anvil --zksync`.
+//!
+//! Transactions are executed in the zkVM, which tracks account balances and nonces in the storage
+//! of the zkSync system contracts instead of in the account info, so these are read and written
+//! from there as well.
+
+use crate::eth::{
+    backend::{db::Db, mem::inspector::Inspector},
+    error::InvalidTransactionError,
+};
+use alloy_primitives::{Address, Bytes, U256};
+use alloy_rpc_types::TransactionRequest;
+use alloy_serde::WithOtherFields;
+use foundry_evm::{
+    backend::{DatabaseError, DatabaseResult},
+    inspectors::trace_zksync_transaction,
+    revm::{
+        db::DatabaseRef,
+        primitives::{AccountInfo, Env, ExecutionResult, ResultAndState},
+        Database, EvmContext,
+    },
+};
+use foundry_zksync_core::{
+    convert::ConvertAddress,
+    state::{get_balance_storage, get_nonce_storage, new_full_nonce, parse_full_nonce},
+    vm::Call,
+    PaymasterParams, H256,
+};
+use serde::Deserialize;
+use std::{collections::HashMap, fmt::Debug};
+
+/// The L1 chain id reported by `zks_L1ChainId`, which is the one used by era-test-node.
+pub const L1_CHAIN_ID: u64 = 9;
+
+/// The zkSync specific fields of a transaction request, sent in its `customData` field.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Eip712Meta {
+    #[serde(default)]
+    factory_deps: Vec<Bytes>,
+    #[serde(default)]
+    paymaster_params: Option<Eip712PaymasterParams>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Eip712PaymasterParams {
+    paymaster: Address,
+    paymaster_input: Bytes,
+}
+
+/// Returns the factory dependencies and paymaster parameters of the given transaction request, if
+/// it carries any in its `customData` field.
+pub fn request_meta(
+    request: &WithOtherFields<TransactionRequest>,
+) -> (Vec<Vec<u8>>, Option<PaymasterParams>) {
+    let meta = request
+        .other
+        .get("customData")
+        .and_then(|meta| Eip712Meta::deserialize(meta).ok())
+        .unwrap_or_default();
+    let factory_deps = meta.factory_deps.into_iter().map(|dep| dep.to_vec()).collect();
+    let paymaster_params = meta.paymaster_params.map(|params| PaymasterParams {
+        paymaster: params.paymaster.to_h160(),
+        paymaster_input: params.paymaster_input.to_vec(),
+    });
+    (factory_deps, paymaster_params)
+}
+
+/// Returns the account info of the given address, with the balance and nonce tracked by the zkSync
+/// system contracts.
+pub fn account_info<D>(db: D, address: Address) -> DatabaseResult<AccountInfo>
+where
+    D: DatabaseRef<Error = DatabaseError>,
+{
+    let mut info = db.basic_ref(address)?.unwrap_or_default();
+    info.balance = balance(&db, address)?;
+    info.nonce = nonce(&db, address)?;
+    Ok(info)
+}
+
+/// Returns the balance of the given address, as tracked by the `L2BaseToken` system contract.
+pub fn balance<D>(db: D, address: Address) -> DatabaseResult<U256>
+where
+    D: DatabaseRef<Error = DatabaseError>,
+{
+    let (account, slot) = get_balance_storage(address);
+    db.storage_ref(account, slot)
+}
+
+/// Returns the transaction nonce of the given address, as tracked by the `NonceHolder` system
+/// contract.
+pub fn nonce<D>(db: D, address: Address) -> DatabaseResult<u64>
+where
+    D: DatabaseRef<Error = DatabaseError>,
+{
+    let (account, slot) = get_nonce_storage(address);
+    Ok(parse_full_nonce(db.storage_ref(account, slot)?).tx_nonce)
+}
+
+/// Sets the balance of the given address in the `L2BaseToken` system contract.
+pub fn set_balance<D>(db: &mut D, address: Address, balance: U256) -> DatabaseResult<()>
+where
+    D: Db + ?Sized,
+{
+    let (account, slot) = get_balance_storage(address);
+    db.set_storage_at(account, slot, balance)
+}
+
+/// Sets the transaction nonce of the given address in the `NonceHolder` system contract, keeping
+/// its deployment nonce.
+pub fn set_nonce<D>(db: &mut D, address: Address, nonce: u64) -> DatabaseResult<()>
+where
+    D: Db + ?Sized,
+{
+    let (account, slot) = get_nonce_storage(address);
+    let full_nonce = parse_full_nonce(db.storage_ref(account, slot)?);
+    db.set_storage_at(account, slot, new_full_nonce(nonce, full_nonce.deploy_nonce))
+}
+
+/// Executes the transaction configured in the `env` in the zkVM, without committing the changes.
+///
+/// The bytecodes known after the execution are added to `persisted_factory_deps`, so they can be
+/// used by later transactions. The calls and logs of the transaction are recorded in the
+/// `inspector`.
+pub fn transact<DB>(
+    db: &mut DB,
+    env: &mut Env,
+    factory_deps: Vec<Vec<u8>>,
+    paymaster_params: Option<PaymasterParams>,
+    persisted_factory_deps: &mut HashMap<H256, Vec<u8>>,
+    inspector: &mut Inspector,
+) -> Result<ResultAndState, InvalidTransactionError>
+where
+    DB: Database,
+    <DB as Database>::Error: Debug,
+{
+    let (result_and_state, call_traces) = foundry_zksync_core::vm::transact_with_call_traces(
+        Some(persisted_factory_deps),
+        Some(factory_deps),
+        paymaster_params,
+        env,
+        db,
+    )
+    .map_err(|err| InvalidTransactionError::ZkVm(err.to_string()))?;
+
+    inspect(inspector, db, env, call_traces, &result_and_state.result);
+    Ok(result_and_state)
+}
+
+/// Records the calls of a transaction executed in the zkVM in the tracer of the `inspector`, and
+/// its logs in the log collector, since the zkVM does not run the inspector itself.
+fn inspect<DB: Database>(
+    inspector: &mut Inspector,
+    db: DB,
+    env: &Env,
+    call_traces: Vec<Call>,
+    result: &ExecutionResult,
+) {
+    let logs = match result {
+        ExecutionResult::Success { logs, .. } => logs.as_slice(),
+        _ => &[],
+    };
+
+    if let Some(tracer) = &mut inspector.tracer {
+        let mut ecx = EvmContext::new_with_env(db, Box::new(env.clone()));
+        trace_zksync_transaction(tracer, &mut ecx, call_traces, logs);
+    }
+    // `console.log` calls are reported as logs of the transaction
+    if let Some(collector) = &mut inspector.log_collector {
+        collector.logs.extend(logs.iter().cloned());
+    }
+}
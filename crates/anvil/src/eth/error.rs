@@ -89,6 +89,10 @@ pub enum BlockchainError {
     EIP7702TransactionUnsupportedAtHardfork,
     #[error("op-stack deposit tx received but is not supported.\n\nYou can use it by running anvil with '--optimism'.")]
     DepositTransactionUnsupported,
+    #[error("zkSync EIP-712 tx received but is not supported.\n\nYou can use it by running anvil with '--zksync'.")]
+    EIP712TransactionUnsupported,
+    #[error("zkSync RPC method called but zkSync mode is not enabled.\n\nYou can use it by running anvil with '--zksync'.")]
+    ZkSyncUnsupported,
     #[error("Excess blob gas not set.")]
     ExcessBlobGasNotSet,
     #[error("{0}")]
@@ -231,6 +235,9 @@ pub enum InvalidTransactionError {
     /// Thrown when there are no `blob_hashes` in the transaction.
     #[error("There should be at least one blob in a Blob transaction.")]
     EmptyBlobs,
+    /// Thrown when the zkVM rejects or fails to execute the transaction.
+    #[error("zkVM execution failed: {0}")]
+    ZkVm(String),
 }
 
 impl From<revm::primitives::InvalidTransaction> for InvalidTransactionError {
@@ -426,6 +433,12 @@ impl<T: Serialize> ToRpcResponseResult for Result<T> {
                 err @ BlockchainError::DepositTransactionUnsupported => {
                     RpcError::invalid_params(err.to_string())
                 }
+                err @ BlockchainError::EIP712TransactionUnsupported => {
+                    RpcError::invalid_params(err.to_string())
+                }
+                err @ BlockchainError::ZkSyncUnsupported => {
+                    RpcError::invalid_params(err.to_string())
+                }
                 err @ BlockchainError::ExcessBlobGasNotSet => {
                     RpcError::invalid_params(err.to_string())
                 }
@@ -290,6 +290,9 @@ impl FeeHistoryService {
                             .max_priority_fee_per_gas
                             .min(t.tx().max_fee_per_gas.saturating_sub(base_fee)),
                         Some(TypedTransaction::Deposit(_)) => 0,
+                        Some(TypedTransaction::EIP712(t)) => t
+                            .max_priority_fee_per_gas
+                            .min(t.max_fee_per_gas.saturating_sub(base_fee)),
                         None => 0,
                     };
 
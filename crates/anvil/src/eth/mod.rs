@@ -12,3 +12,4 @@ pub(crate) mod macros;
 pub mod miner;
 pub mod pool;
 pub mod util;
+pub mod zksync;
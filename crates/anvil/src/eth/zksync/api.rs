@@ -0,0 +1,79 @@
+use crate::eth::{
+    backend::zksync::L1_CHAIN_ID,
+    error::{BlockchainError, Result},
+    macros::node_info,
+    EthApi,
+};
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::{Bytes, B256, U256, U64};
+use alloy_rpc_types::{BlockNumberOrTag as BlockNumber, TransactionRequest};
+use alloy_serde::WithOtherFields;
+use foundry_zksync_core::vm::ZkEnv;
+use serde::Serialize;
+
+/// The fee parameters of a zkSync transaction, as returned by `zks_estimateFee`.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Fee {
+    pub gas_limit: U256,
+    pub gas_per_pubdata_limit: U256,
+    pub max_fee_per_gas: U256,
+    pub max_priority_fee_per_gas: U256,
+}
+
+impl EthApi {
+    /// Estimates the fee parameters of the given transaction, executing it in the zkVM.
+    ///
+    /// The gas per pubdata byte is derived from the current base fee the same way the zkVM does
+    /// when executing the transaction, and the fees follow the node's fee model.
+    ///
+    /// Handler for zkSync RPC call: `zks_estimateFee`
+    pub async fn zks_estimate_fee(
+        &self,
+        request: WithOtherFields<TransactionRequest>,
+    ) -> Result<Fee> {
+        node_info!("zks_estimateFee");
+        self.backend.ensure_zksync_rpc_active()?;
+
+        let gas_limit = self.estimate_gas(request, None, None).await?;
+        let base_fee = self.backend.base_fee().try_into().unwrap_or(u64::MAX);
+        let (_, gas_per_pubdata) = ZkEnv::local(base_fee).fee_params();
+        Ok(Fee {
+            gas_limit,
+            gas_per_pubdata_limit: U256::from(gas_per_pubdata),
+            max_fee_per_gas: U256::from(self.gas_price()),
+            max_priority_fee_per_gas: U256::from(self.lowest_suggestion_tip()),
+        })
+    }
+
+    /// Returns the bytecode deployed in the zkVM with the given bytecode hash, if any.
+    ///
+    /// Handler for zkSync RPC call: `zks_getBytecodeByHash`
+    pub async fn zks_get_bytecode_by_hash(&self, hash: B256) -> Result<Option<Bytes>> {
+        node_info!("zks_getBytecodeByHash");
+        self.backend.ensure_zksync_rpc_active()?;
+
+        Ok(self.backend.zk_bytecode_by_hash(hash))
+    }
+
+    /// Returns the chain id of the L1 the node settles to.
+    ///
+    /// Handler for zkSync RPC call: `zks_L1ChainId`
+    pub async fn zks_l1_chain_id(&self) -> Result<U64> {
+        node_info!("zks_L1ChainId");
+        self.backend.ensure_zksync_rpc_active()?;
+
+        Ok(U64::from(L1_CHAIN_ID))
+    }
+
+    /// Returns the EIP-2718 encoded transactions of the given block.
+    ///
+    /// Handler for zkSync RPC call: `zks_getRawBlockTransactions`
+    pub async fn zks_get_raw_block_transactions(&self, number: BlockNumber) -> Result<Vec<Bytes>> {
+        node_info!("zks_getRawBlockTransactions");
+        self.backend.ensure_zksync_rpc_active()?;
+
+        let block = self.backend.get_block(number).ok_or(BlockchainError::BlockNotFound)?;
+        Ok(block.transactions.iter().map(|tx| tx.transaction.encoded_2718().into()).collect())
+    }
+}
@@ -21,6 +21,7 @@ mod transaction;
 mod txpool;
 pub mod utils;
 mod wsapi;
+mod zksync;
 
 #[allow(unused)]
 pub(crate) fn init_tracing() {
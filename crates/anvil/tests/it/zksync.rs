@@ -0,0 +1,169 @@
+//! Tests for zkSync mode.
+
+use crate::utils::{http_provider, http_provider_with_signer};
+use alloy_network::{EthereumWallet, TransactionBuilder};
+use alloy_primitives::{keccak256, Bytes, U256, U64};
+use alloy_provider::Provider;
+use alloy_rpc_types::{BlockNumberOrTag, TransactionRequest};
+use alloy_serde::WithOtherFields;
+use anvil::{spawn, NodeConfig};
+use foundry_zksync_core::{convert::ConvertH256, hash_bytecode, vm::ZkEnv};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_zks_methods_not_supported_if_zksync_disabled() {
+    let (api, _handle) = spawn(NodeConfig::test()).await;
+
+    let err = api.zks_l1_chain_id().await.unwrap_err();
+    let s = err.to_string();
+    assert!(s.contains("zkSync mode is not enabled"), "{s:?}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_zks_l1_chain_id() {
+    let (api, _handle) = spawn(NodeConfig::test().with_zksync(true)).await;
+
+    assert_eq!(api.zks_l1_chain_id().await.unwrap(), U64::from(9));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_send_value_zksync() {
+    let (api, handle) = spawn(NodeConfig::test().with_zksync(true)).await;
+
+    let accounts: Vec<_> = handle.dev_wallets().collect();
+    let signer: EthereumWallet = accounts[0].clone().into();
+    let from = accounts[0].address();
+    let to = accounts[1].address();
+
+    let provider = http_provider_with_signer(&handle.http_endpoint(), signer);
+
+    let send_value = U256::from(1234);
+    let before_balance_to = provider.get_balance(to).await.unwrap();
+    let before_nonce_from = provider.get_transaction_count(from).await.unwrap();
+
+    let tx = TransactionRequest::default().with_from(from).with_to(to).with_value(send_value);
+    let tx = WithOtherFields::new(tx);
+
+    let pending = provider.send_transaction(tx).await.unwrap().register().await.unwrap();
+
+    api.evm_mine(None).await.unwrap();
+
+    let receipt =
+        provider.get_transaction_receipt(pending.tx_hash().to_owned()).await.unwrap().unwrap();
+    assert!(receipt.status());
+
+    // balances and nonces are tracked by the system contracts
+    let after_balance_to = provider.get_balance(to).await.unwrap();
+    assert_eq!(after_balance_to, before_balance_to + send_value);
+    let after_nonce_from = provider.get_transaction_count(from).await.unwrap();
+    assert_eq!(after_nonce_from, before_nonce_from + 1);
+
+    // the calls made in the zkVM are traced
+    let traces = api.trace_transaction(pending.tx_hash().to_owned()).await.unwrap();
+    assert!(!traces.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_zks_estimate_fee() {
+    let (api, handle) = spawn(NodeConfig::test().with_zksync(true)).await;
+
+    let accounts: Vec<_> = handle.dev_wallets().collect();
+    let tx = TransactionRequest::default()
+        .with_from(accounts[0].address())
+        .with_to(accounts[1].address())
+        .with_value(U256::from(1234));
+
+    let fee = api.zks_estimate_fee(WithOtherFields::new(tx)).await.unwrap();
+    assert!(fee.gas_limit > U256::ZERO);
+    assert_eq!(fee.max_fee_per_gas, U256::from(api.gas_price()));
+    assert_eq!(fee.max_priority_fee_per_gas, api.max_priority_fee_per_gas().unwrap());
+
+    // the gas per pubdata byte follows the base fee of the node
+    let base_fee = api.backend.base_fee() as u64;
+    let (_, gas_per_pubdata) = ZkEnv::local(base_fee).fee_params();
+    assert_eq!(fee.gas_per_pubdata_limit, U256::from(gas_per_pubdata));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_send_raw_eip712_transaction_with_factory_deps() {
+    let (api, handle) = spawn(NodeConfig::test().with_zksync(true)).await;
+
+    let accounts: Vec<_> = handle.dev_wallets().collect();
+    let signer = accounts[0].clone();
+    let from = signer.address();
+    let to = accounts[1].address();
+    let provider = http_provider(&handle.http_endpoint());
+
+    // any bytecode with an odd number of words can be published as a factory dependency
+    let factory_dep = vec![1u8; 32];
+    let tx = TransactionRequest::default()
+        .with_from(from)
+        .with_to(to)
+        .with_chain_id(provider.get_chain_id().await.unwrap())
+        .with_nonce(provider.get_transaction_count(from).await.unwrap())
+        .with_gas_price(api.gas_price());
+    let raw_tx = foundry_zksync_core::new_eip712_transaction(
+        WithOtherFields::new(tx),
+        vec![factory_dep.clone()],
+        None,
+        None,
+        &provider,
+        signer,
+    )
+    .await
+    .unwrap();
+
+    let pending = provider.send_raw_transaction(&raw_tx).await.unwrap().register().await.unwrap();
+    api.evm_mine(None).await.unwrap();
+
+    let receipt =
+        provider.get_transaction_receipt(pending.tx_hash().to_owned()).await.unwrap().unwrap();
+    assert!(receipt.status());
+    assert_eq!(receipt.inner.inner.r#type, 0x71);
+    assert_eq!(receipt.from, from);
+
+    // the factory dependency is published along with the transaction
+    let bytecode_hash = hash_bytecode(&factory_dep).to_b256();
+    let bytecode = api.zks_get_bytecode_by_hash(bytecode_hash).await.unwrap();
+    assert_eq!(bytecode, Some(Bytes::from(factory_dep)));
+
+    let raw_txs = api.zks_get_raw_block_transactions(BlockNumberOrTag::Latest).await.unwrap();
+    assert_eq!(raw_txs, vec![raw_tx]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_zks_get_bytecode_by_hash_unknown() {
+    let (api, _handle) = spawn(NodeConfig::test().with_zksync(true)).await;
+
+    let bytecode_hash = hash_bytecode(&[2u8; 32]).to_b256();
+    assert_eq!(api.zks_get_bytecode_by_hash(bytecode_hash).await.unwrap(), None);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_zks_get_raw_block_transactions() {
+    let (api, handle) = spawn(NodeConfig::test().with_zksync(true)).await;
+
+    let accounts: Vec<_> = handle.dev_wallets().collect();
+    let signer: EthereumWallet = accounts[0].clone().into();
+    let provider = http_provider_with_signer(&handle.http_endpoint(), signer);
+
+    let tx = TransactionRequest::default()
+        .with_from(accounts[0].address())
+        .with_to(accounts[1].address())
+        .with_value(U256::from(1234));
+    let pending = provider
+        .send_transaction(WithOtherFields::new(tx))
+        .await
+        .unwrap()
+        .register()
+        .await
+        .unwrap();
+    api.evm_mine(None).await.unwrap();
+
+    let raw_txs = api.zks_get_raw_block_transactions(BlockNumberOrTag::Latest).await.unwrap();
+    assert_eq!(raw_txs.len(), 1);
+    assert_eq!(keccak256(&raw_txs[0]), *pending.tx_hash());
+
+    // the genesis block has no transactions
+    let raw_txs = api.zks_get_raw_block_transactions(BlockNumberOrTag::Earliest).await.unwrap();
+    assert!(raw_txs.is_empty());
+}
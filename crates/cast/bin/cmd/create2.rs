@@ -1,6 +1,9 @@
 use alloy_primitives::{hex, keccak256, Address, B256, U256};
 use clap::Parser;
 use eyre::{Result, WrapErr};
+use foundry_zksync_core::{
+    compute_create2_address, convert::ConvertH256, hash_bytecode, CREATE2_FACTORY_ADDRESS,
+};
 use rand::{rngs::StdRng, RngCore, SeedableRng};
 use regex::RegexSetBuilder;
 use std::{
@@ -40,22 +43,33 @@ pub struct Create2Args {
     case_sensitive: bool,
 
     /// Address of the contract deployer.
-    #[arg(
-        short,
-        long,
-        default_value = DEPLOYER,
-        value_name = "ADDRESS"
-    )]
-    deployer: Address,
+    ///
+    /// Defaults to the deterministic deployment proxy, or to the `Create2Factory` contract with
+    /// `--zksync`.
+    #[arg(short, long, value_name = "ADDRESS")]
+    deployer: Option<Address>,
 
     /// Init code of the contract to be deployed.
+    ///
+    /// This is the EraVM bytecode of the contract with `--zksync`.
     #[arg(short, long, value_name = "HEX")]
     init_code: Option<String>,
 
     /// Init code hash of the contract to be deployed.
+    ///
+    /// This is the bytecode hash of the contract with `--zksync`.
     #[arg(alias = "ch", long, value_name = "HASH", required_unless_present = "init_code")]
     init_code_hash: Option<String>,
 
+    /// Mine an address for a zkSync deployment, which is derived from the bytecode hash and the
+    /// constructor input of the contract.
+    #[arg(long)]
+    zksync: bool,
+
+    /// ABI-encoded constructor arguments of the contract, for `--zksync`.
+    #[arg(long, value_name = "HEX", requires = "zksync")]
+    constructor_input: Option<String>,
+
     /// Number of threads to use. Defaults to and caps at the number of logical cores.
     #[arg(short, long)]
     jobs: Option<NonZeroUsize>,
@@ -89,6 +103,8 @@ impl Create2Args {
             deployer,
             init_code,
             init_code_hash,
+            zksync,
+            constructor_input,
             jobs,
             caller,
             seed,
@@ -134,13 +150,26 @@ impl Create2Args {
 
         let regex = RegexSetBuilder::new(regexs).case_insensitive(!case_sensitive).build()?;
 
-        let init_code_hash = if let Some(init_code_hash) = init_code_hash {
-            hex::FromHex::from_hex(init_code_hash)
+        let deployer = match deployer {
+            Some(deployer) => deployer,
+            None if zksync => CREATE2_FACTORY_ADDRESS,
+            None => DEPLOYER.parse()?,
+        };
+
+        let init_code_hash: B256 = if let Some(init_code_hash) = init_code_hash {
+            hex::FromHex::from_hex(init_code_hash)?
         } else if let Some(init_code) = init_code {
-            hex::decode(init_code).map(keccak256)
+            let init_code = hex::decode(init_code)?;
+            if zksync {
+                zk_bytecode_hash(&init_code)?
+            } else {
+                keccak256(init_code)
+            }
         } else {
             unreachable!();
-        }?;
+        };
+
+        let constructor_input_hash = keccak256(hex::decode(constructor_input.unwrap_or_default())?);
 
         let mut n_threads = std::thread::available_parallelism().map_or(1, |n| n.get());
         if let Some(jobs) = jobs {
@@ -167,7 +196,12 @@ impl Create2Args {
         }
 
         println!("Configuration:");
-        println!("Init code hash: {init_code_hash}");
+        if zksync {
+            println!("Bytecode hash: {init_code_hash}");
+            println!("Constructor input hash: {constructor_input_hash}");
+        } else {
+            println!("Init code hash: {init_code_hash}");
+        }
         println!("Regex patterns: {:?}", regex.patterns());
         println!();
         println!("Starting to generate deterministic contract address with {n_threads} threads...");
@@ -203,7 +237,16 @@ impl Create2Args {
 
                     // Calculate the `CREATE2` address.
                     #[allow(clippy::needless_borrows_for_generic_args)]
-                    let addr = deployer.create2(&salt.0, &init_code_hash);
+                    let addr = if zksync {
+                        compute_create2_address(
+                            deployer,
+                            salt.0,
+                            init_code_hash,
+                            constructor_input_hash,
+                        )
+                    } else {
+                        deployer.create2(&salt.0, &init_code_hash)
+                    };
 
                     // Check if the regex matches the calculated address' checksum.
                     let _ = addr.to_checksum_raw(&mut checksum, None);
@@ -232,6 +275,13 @@ impl Create2Args {
     }
 }
 
+/// Returns the bytecode hash of the given EraVM bytecode, which must be a valid EraVM bytecode as
+/// [hash_bytecode] panics otherwise.
+fn zk_bytecode_hash(bytecode: &[u8]) -> Result<B256> {
+    foundry_zksync_compiler::limits::check_bytecode("init code", bytecode)?;
+    Ok(hash_bytecode(bytecode).to_b256())
+}
+
 fn get_regex_hex_string(s: String) -> Result<String> {
     let s = s.strip_prefix("0x").unwrap_or(&s);
     let pad_width = s.len() + s.len() % 2;
@@ -338,6 +388,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn create2_zksync() {
+        let init_code = format!("0x{}", "00".repeat(32));
+        let constructor_input = "0x2a";
+        let args = Create2Args::parse_from([
+            "foundry-cli",
+            "--starts-with=ee",
+            "--zksync",
+            "--init-code",
+            &init_code,
+            "--constructor-input",
+            constructor_input,
+        ]);
+        let create2_out = args.run().unwrap();
+        let address = create2_out.address;
+        assert!(format!("{address:x}").starts_with("ee"));
+
+        let deployer = CREATE2_FACTORY_ADDRESS;
+        let bytecode_hash = hash_bytecode(&hex::decode(init_code).unwrap()).to_b256();
+        let constructor_input_hash = keccak256(hex::decode(constructor_input).unwrap());
+        assert_eq!(
+            address,
+            compute_create2_address(
+                deployer,
+                create2_out.salt,
+                bytecode_hash,
+                constructor_input_hash
+            )
+        );
+        assert_ne!(address, deployer.create2(create2_out.salt, bytecode_hash));
+
+        // the bytecode must be a valid EraVM bytecode
+        let args = Create2Args::parse_from([
+            "foundry-cli",
+            "--starts-with=ee",
+            "--zksync",
+            "--init-code=00",
+        ]);
+        assert!(args.run().is_err());
+    }
+
     #[test]
     fn create2_caller() {
         let init_code_hash = "bc36789e7a1e281436464229828f817d6612f7b477d66591ff96a9e064bcc98a";
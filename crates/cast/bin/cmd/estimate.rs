@@ -1,4 +1,4 @@
-use crate::tx::CastTxBuilder;
+use crate::tx::{self, CastTxBuilder, ZkTransactionOpts};
 use alloy_primitives::{TxKind, U256};
 use alloy_provider::Provider;
 use alloy_rpc_types::BlockId;
@@ -28,7 +28,7 @@ pub struct EstimateArgs {
     /// The block height to query at.
     ///
     /// Can also be the tags earliest, finalized, safe, latest, or pending.
-    #[arg(long, short = 'B')]
+    #[arg(long, short = 'B', conflicts_with = "startup")]
     block: Option<BlockId>,
 
     #[command(subcommand)]
@@ -39,6 +39,9 @@ pub struct EstimateArgs {
 
     #[command(flatten)]
     eth: EthereumOpts,
+
+    #[command(flatten)]
+    zk: ZkTransactionOpts,
 }
 
 #[derive(Debug, Parser)]
@@ -67,7 +70,9 @@ pub enum EstimateSubcommands {
 
 impl EstimateArgs {
     pub async fn run(self) -> Result<()> {
-        let Self { to, mut sig, mut args, mut tx, block, eth, command } = self;
+        let Self { to, mut sig, mut args, mut tx, block, eth, command, zk } = self;
+
+        zk.validate(&tx)?;
 
         let config = Config::from(&eth);
         let provider = utils::get_provider(&config)?;
@@ -96,14 +101,28 @@ impl EstimateArgs {
             None
         };
 
-        let (tx, _) = CastTxBuilder::new(&provider, tx, &config)
+        let (mut tx, _) = CastTxBuilder::new(&provider, tx, &config)
             .await?
             .with_tx_kind(tx_kind)
-            .with_code_sig_and_args(code, sig, args)
+            .with_code_sig_and_args(code.clone(), sig, args)
             .await?
             .build_raw(sender)
             .await?;
 
+        if zk.enabled() {
+            let factory_deps = tx::prepare_zk_tx(&provider, &mut tx, code.as_deref(), &zk).await?;
+            let gas = foundry_zksync_core::estimate_gas(
+                &tx,
+                factory_deps,
+                zk.paymaster_params(),
+                zk.gas_per_pubdata,
+                &provider,
+            )
+            .await?;
+            println!("{}", gas.limit);
+            return Ok(())
+        }
+
         let gas = provider.estimate_gas(&tx).block(block.unwrap_or_default()).await?;
         println!("{gas}");
         Ok(())
@@ -119,4 +138,24 @@ mod tests {
         let args: EstimateArgs = EstimateArgs::parse_from(["foundry-cli", "--value", "100"]);
         assert!(args.tx.value.is_some());
     }
+
+    #[test]
+    fn parse_estimate_zksync() {
+        let args: EstimateArgs = EstimateArgs::parse_from([
+            "foundry-cli",
+            "--zksync",
+            "--zk-paymaster-address",
+            "0x0000000000000000000000000000000000000001",
+            "--zk-paymaster-input",
+            "0x1234",
+        ]);
+        assert!(args.zk.enabled());
+        assert!(args.zk.paymaster_params().is_some());
+    }
+
+    #[test]
+    fn reject_blob_with_zksync() {
+        let args: EstimateArgs = EstimateArgs::parse_from(["foundry-cli", "--zksync", "--blob"]);
+        assert!(args.zk.validate(&args.tx).is_err());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::tx::{self, CastTxBuilder};
+use crate::tx::{self, CastTxBuilder, ZkTransactionOpts};
 use alloy_network::{eip2718::Encodable2718, EthereumWallet, TransactionBuilder};
 use alloy_primitives::hex;
 use alloy_signer::Signer;
@@ -45,6 +45,9 @@ pub struct MakeTxArgs {
 
     #[command(flatten)]
     eth: EthereumOpts,
+
+    #[command(flatten)]
+    zk: ZkTransactionOpts,
 }
 
 #[derive(Debug, Parser)]
@@ -65,7 +68,9 @@ pub enum MakeTxSubcommands {
 
 impl MakeTxArgs {
     pub async fn run(self) -> Result<()> {
-        let Self { to, mut sig, mut args, command, tx, path, eth } = self;
+        let Self { to, mut sig, mut args, command, tx, path, eth, zk } = self;
+
+        zk.validate(&tx)?;
 
         let blob_data = if let Some(path) = path { Some(std::fs::read(path)?) } else { None };
 
@@ -95,15 +100,32 @@ impl MakeTxArgs {
 
         let provider = get_provider(&config)?;
 
-        let (tx, _) = CastTxBuilder::new(provider, tx, &config)
+        let builder = CastTxBuilder::new(&provider, tx, &config)
             .await?
             .with_tx_kind(tx_kind)
-            .with_code_sig_and_args(code, sig, args)
+            .with_code_sig_and_args(code.clone(), sig, args)
             .await?
-            .with_blob_data(blob_data)?
-            .build(from)
+            .with_blob_data(blob_data)?;
+
+        if zk.enabled() {
+            let (mut tx, _) = builder.build_raw(from).await?;
+            let factory_deps = tx::prepare_zk_tx(&provider, &mut tx, code.as_deref(), &zk).await?;
+            let signed_tx = foundry_zksync_core::new_eip712_transaction(
+                tx,
+                factory_deps,
+                zk.paymaster_params(),
+                zk.gas_per_pubdata,
+                &provider,
+                signer,
+            )
             .await?;
 
+            println!("{signed_tx}");
+            return Ok(())
+        }
+
+        let (tx, _) = builder.build(from).await?;
+
         let tx = tx.build(&EthereumWallet::new(signer)).await?;
 
         let signed_tx = hex::encode(tx.encoded_2718());
@@ -19,3 +19,4 @@ pub mod run;
 pub mod send;
 pub mod storage;
 pub mod wallet;
+pub mod zks;
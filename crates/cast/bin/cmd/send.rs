@@ -1,5 +1,6 @@
-use crate::tx::{self, CastTxBuilder};
+use crate::tx::{self, CastTxBuilder, ZkTransactionOpts};
 use alloy_network::{AnyNetwork, EthereumWallet};
+use alloy_primitives::{hex, Bytes};
 use alloy_provider::{Provider, ProviderBuilder};
 use alloy_rpc_types::TransactionRequest;
 use alloy_serde::WithOtherFields;
@@ -47,7 +48,7 @@ pub struct SendTxArgs {
     command: Option<SendTxSubcommands>,
 
     /// Send via `eth_sendTransaction using the `--from` argument or $ETH_FROM as sender
-    #[arg(long, requires = "from")]
+    #[arg(long, requires = "from", conflicts_with = "startup")]
     unlocked: bool,
 
     #[command(flatten)]
@@ -56,6 +57,9 @@ pub struct SendTxArgs {
     #[command(flatten)]
     eth: EthereumOpts,
 
+    #[command(flatten)]
+    zk: ZkTransactionOpts,
+
     /// The path of blob data to be sent.
     #[arg(
         long,
@@ -98,8 +102,11 @@ impl SendTxArgs {
             command,
             unlocked,
             path,
+            zk,
         } = self;
 
+        zk.validate(&tx)?;
+
         let blob_data = if let Some(path) = path { Some(std::fs::read(path)?) } else { None };
 
         let code = if let Some(SendTxSubcommands::Create {
@@ -122,7 +129,7 @@ impl SendTxArgs {
         let builder = CastTxBuilder::new(&provider, tx, &config)
             .await?
             .with_tx_kind(tx_kind)
-            .with_code_sig_and_args(code, sig, args)
+            .with_code_sig_and_args(code.clone(), sig, args)
             .await?
             .with_blob_data(blob_data)?;
 
@@ -164,6 +171,23 @@ impl SendTxArgs {
 
             tx::validate_from_address(eth.wallet.from, from)?;
 
+            if zk.enabled() {
+                let (mut tx, _) = builder.build_raw(from).await?;
+                let factory_deps =
+                    tx::prepare_zk_tx(&provider, &mut tx, code.as_deref(), &zk).await?;
+                let raw_tx = foundry_zksync_core::new_eip712_transaction(
+                    tx,
+                    factory_deps,
+                    zk.paymaster_params(),
+                    zk.gas_per_pubdata,
+                    &provider,
+                    signer,
+                )
+                .await?;
+
+                return cast_send_zk(provider, raw_tx, cast_async, confirmations, to_json).await
+            }
+
             let wallet = EthereumWallet::from(signer);
             let provider = ProviderBuilder::<_, _, AnyNetwork>::default()
                 .wallet(wallet)
@@ -197,3 +221,25 @@ async fn cast_send<P: Provider<T, AnyNetwork>, T: Transport + Clone>(
 
     Ok(())
 }
+
+async fn cast_send_zk<P: Provider<T, AnyNetwork>, T: Transport + Clone>(
+    provider: P,
+    raw_tx: Bytes,
+    cast_async: bool,
+    confs: u64,
+    to_json: bool,
+) -> Result<()> {
+    let cast = Cast::new(provider);
+    let pending_tx = cast.publish(hex::encode_prefixed(raw_tx)).await?;
+
+    let tx_hash = pending_tx.inner().tx_hash();
+
+    if cast_async {
+        println!("{tx_hash:#x}");
+    } else {
+        let receipt = cast.receipt(format!("{tx_hash:#x}"), None, confs, false, to_json).await?;
+        println!("{receipt}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,245 @@
+use alloy_primitives::B256;
+use cast::zks::ZksCast;
+use clap::Parser;
+use eyre::{OptionExt, Result};
+use foundry_cli::{opts::RpcOpts, utils};
+use foundry_config::Config;
+use serde::Serialize;
+
+/// CLI arguments for `cast zks`.
+#[derive(Debug, Parser)]
+pub enum ZksSubcommands {
+    /// Get the addresses of the default bridge contracts.
+    #[command(visible_alias = "bridges")]
+    BridgeContracts {
+        #[command(flatten)]
+        output: OutputArgs,
+
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+
+    /// Get the address of the main zkSync contract on L1.
+    #[command(visible_alias = "main")]
+    MainContract {
+        #[command(flatten)]
+        output: OutputArgs,
+
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+
+    /// Get the proof of an L2 to L1 log emitted by a transaction.
+    #[command(visible_alias = "log-proof")]
+    L2ToL1LogProof {
+        /// The transaction hash.
+        tx_hash: B256,
+
+        /// The index of the L2 to L1 log in the transaction, the first one if not set.
+        #[arg(long)]
+        index: Option<usize>,
+
+        #[command(flatten)]
+        output: OutputArgs,
+
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+
+    /// Get the zkSync specific details of a transaction.
+    #[command(visible_alias = "tx")]
+    TxDetails {
+        /// The transaction hash.
+        tx_hash: B256,
+
+        #[command(flatten)]
+        output: OutputArgs,
+
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+
+    /// Get the zkSync specific details of an L2 block.
+    #[command(visible_alias = "block")]
+    BlockDetails {
+        /// The block number.
+        number: u32,
+
+        #[command(flatten)]
+        output: OutputArgs,
+
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+
+    /// Get the details of an L1 batch.
+    #[command(visible_alias = "batch")]
+    BatchDetails {
+        /// The batch number.
+        number: u32,
+
+        #[command(flatten)]
+        output: OutputArgs,
+
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+
+    /// Get a bytecode by its bytecode hash.
+    #[command(visible_alias = "bytecode")]
+    BytecodeByHash {
+        /// The bytecode hash.
+        hash: B256,
+
+        #[command(flatten)]
+        output: OutputArgs,
+
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+
+    /// Get the current fee parameters.
+    #[command(visible_alias = "fees")]
+    FeeParams {
+        #[command(flatten)]
+        output: OutputArgs,
+
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+}
+
+/// Output options of the `cast zks` subcommands.
+#[derive(Debug, Parser)]
+pub struct OutputArgs {
+    /// Print the output as JSON.
+    #[arg(long, short, help_heading = "Display options")]
+    json: bool,
+}
+
+impl OutputArgs {
+    /// Prints the value, either as JSON or as a list of its fields.
+    fn print<T: Serialize>(&self, value: &T) -> Result<()> {
+        let value = serde_json::to_value(value)?;
+        if self.json {
+            println!("{}", serde_json::to_string(&value)?);
+        } else {
+            println!("{}", pretty(&value));
+        }
+        Ok(())
+    }
+}
+
+impl ZksSubcommands {
+    pub async fn run(self) -> Result<()> {
+        let config = Config::from(self.rpc());
+        let provider = utils::get_provider(&config)?;
+        let cast = ZksCast::new(&provider);
+
+        match self {
+            Self::BridgeContracts { output, .. } => {
+                output.print(&cast.bridge_contracts().await?)?;
+            }
+            Self::MainContract { output, .. } => {
+                output.print(&cast.main_contract().await?.to_checksum(None))?;
+            }
+            Self::L2ToL1LogProof { tx_hash, index, output, .. } => {
+                let proof = cast
+                    .l2_to_l1_log_proof(tx_hash, index)
+                    .await?
+                    .ok_or_eyre("no L2 to L1 log found")?;
+                output.print(&proof)?;
+            }
+            Self::TxDetails { tx_hash, output, .. } => {
+                let details =
+                    cast.transaction_details(tx_hash).await?.ok_or_eyre("transaction not found")?;
+                output.print(&details)?;
+            }
+            Self::BlockDetails { number, output, .. } => {
+                let details = cast.block_details(number).await?.ok_or_eyre("block not found")?;
+                output.print(&details)?;
+            }
+            Self::BatchDetails { number, output, .. } => {
+                let details = cast.batch_details(number).await?.ok_or_eyre("batch not found")?;
+                output.print(&details)?;
+            }
+            Self::BytecodeByHash { hash, output, .. } => {
+                let bytecode =
+                    cast.bytecode_by_hash(hash).await?.ok_or_eyre("bytecode not found")?;
+                output.print(&bytecode)?;
+            }
+            Self::FeeParams { output, .. } => {
+                output.print(&cast.fee_params().await?)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn rpc(&self) -> &RpcOpts {
+        match self {
+            Self::BridgeContracts { rpc, .. } |
+            Self::MainContract { rpc, .. } |
+            Self::L2ToL1LogProof { rpc, .. } |
+            Self::TxDetails { rpc, .. } |
+            Self::BlockDetails { rpc, .. } |
+            Self::BatchDetails { rpc, .. } |
+            Self::BytecodeByHash { rpc, .. } |
+            Self::FeeParams { rpc, .. } => rpc,
+        }
+    }
+}
+
+/// Formats the fields of a JSON object as aligned `key value` lines, nested values are formatted
+/// as compact JSON.
+fn pretty(value: &serde_json::Value) -> String {
+    let Some(object) = value.as_object() else { return fmt_value(value) };
+    object
+        .iter()
+        .map(|(key, value)| format!("{key:<28}{}", fmt_value(value)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn fmt_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::b256;
+
+    #[test]
+    fn parse_json_output() {
+        let ZksSubcommands::MainContract { output, .. } =
+            ZksSubcommands::parse_from(["foundry-cli", "main-contract", "--json"])
+        else {
+            panic!("expected main-contract")
+        };
+        assert!(output.json);
+
+        let ZksSubcommands::BytecodeByHash { hash, output, .. } = ZksSubcommands::parse_from([
+            "foundry-cli",
+            "bytecode",
+            "0x0100000000000000000000000000000000000000000000000000000000000001",
+            "-j",
+        ]) else {
+            panic!("expected bytecode-by-hash")
+        };
+        assert!(output.json);
+        assert_eq!(hash, b256!("0100000000000000000000000000000000000000000000000000000000000001"));
+    }
+
+    #[test]
+    fn pretty_fields() {
+        assert_eq!(pretty(&serde_json::json!({ "hash": "0x01" })), format!("{:<28}0x01", "hash"));
+        assert_eq!(
+            pretty(&serde_json::json!({ "nested": { "a": 1 } })),
+            format!("{:<28}{{\"a\":1}}", "nested")
+        );
+        assert_eq!(pretty(&serde_json::json!("0x01")), "0x01");
+    }
+}
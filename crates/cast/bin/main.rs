@@ -4,7 +4,7 @@ extern crate tracing;
 use alloy_primitives::{hex, keccak256, Address, B256};
 use alloy_provider::Provider;
 use alloy_rpc_types::{BlockId, BlockNumberOrTag::Latest};
-use cast::{Cast, SimpleCast};
+use cast::{zks::ZksCast, Cast, SimpleCast};
 use clap::{CommandFactory, Parser};
 use clap_complete::generate;
 use eyre::Result;
@@ -295,12 +295,20 @@ async fn main() -> Result<()> {
             let who = who.resolve(&provider).await?;
             println!("{}", Cast::new(provider).codesize(who, block).await?);
         }
-        CastSubcommand::ComputeAddress { address, nonce, rpc } => {
+        CastSubcommand::ComputeAddress { address, nonce, zksync, rpc } => {
             let config = Config::from(&rpc);
             let provider = utils::get_provider(&config)?;
 
             let address: Address = stdin::unwrap_line(address)?.parse()?;
-            let computed = Cast::new(provider).compute_address(address, nonce).await?;
+            let computed = if zksync {
+                let deploy_nonce = match nonce {
+                    Some(nonce) => nonce,
+                    None => ZksCast::new(provider).deploy_nonce(address, None).await?,
+                };
+                foundry_zksync_core::compute_create_address(address, deploy_nonce)
+            } else {
+                Cast::new(provider).compute_address(address, nonce).await?
+            };
             println!("Computed Address: {}", computed.to_checksum(None));
         }
         CastSubcommand::Disassemble { bytecode } => {
@@ -551,6 +559,7 @@ async fn main() -> Result<()> {
             cmd.run()?;
         }
         CastSubcommand::Wallet { command } => command.run().await?,
+        CastSubcommand::Zks { command } => command.run().await?,
         CastSubcommand::Completions { shell } => {
             generate(shell, &mut Opts::command(), "cast", &mut std::io::stdout())
         }
@@ -2,7 +2,7 @@ use crate::cmd::{
     access_list::AccessListArgs, bind::BindArgs, call::CallArgs, create2::Create2Args,
     estimate::EstimateArgs, find_block::FindBlockArgs, interface::InterfaceArgs, logs::LogsArgs,
     mktx::MakeTxArgs, rpc::RpcArgs, run::RunArgs, send::SendTxArgs, storage::StorageArgs,
-    wallet::WalletSubcommands,
+    wallet::WalletSubcommands, zks::ZksSubcommands,
 };
 use alloy_primitives::{Address, B256, U256};
 use alloy_rpc_types::BlockId;
@@ -388,9 +388,16 @@ pub enum CastSubcommand {
         address: Option<String>,
 
         /// The nonce of the deployer address.
+        ///
+        /// This is the deployment nonce of the deployer with `--zksync`.
         #[arg(long)]
         nonce: Option<u64>,
 
+        /// Compute the address the contract would have on zkSync, from the deployment nonce of
+        /// the deployer.
+        #[arg(long)]
+        zksync: bool,
+
         #[command(flatten)]
         rpc: RpcOpts,
     },
@@ -837,6 +844,12 @@ pub enum CastSubcommand {
         command: WalletSubcommands,
     },
 
+    /// zkSync specific RPC utilities.
+    Zks {
+        #[command(subcommand)]
+        command: ZksSubcommands,
+    },
+
     /// Generate a Solidity interface from a given ABI.
     ///
     /// Currently does not support ABI encoder v2.
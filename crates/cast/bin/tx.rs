@@ -1,18 +1,25 @@
 use alloy_consensus::{SidecarBuilder, SimpleCoder};
 use alloy_json_abi::Function;
 use alloy_network::{AnyNetwork, TransactionBuilder};
-use alloy_primitives::{hex, Address, Bytes, TxKind};
+use alloy_primitives::{hex, Address, Bytes, TxKind, U256};
 use alloy_provider::Provider;
 use alloy_rpc_types::{TransactionInput, TransactionRequest};
 use alloy_serde::WithOtherFields;
 use alloy_transport::Transport;
-use eyre::Result;
+use clap::Parser;
+use eyre::{OptionExt, Result, WrapErr};
 use foundry_cli::{
-    opts::TransactionOpts,
+    opts::{TransactionOpts, ZkSyncArgs},
     utils::{self, parse_function_args},
 };
 use foundry_common::ens::NameOrAddress;
 use foundry_config::{Chain, Config};
+use foundry_evm::revm::primitives::CreateScheme;
+use foundry_zksync_core::{
+    convert::ConvertH160, encode_create_params, hash_bytecode, PaymasterParams,
+    CONTRACT_DEPLOYER_ADDRESS,
+};
+use std::path::PathBuf;
 
 /// Prevents a misconfigured hwlib from sending a transaction that defies user-specified --from
 pub fn validate_from_address(
@@ -299,3 +306,103 @@ where
         Ok(self)
     }
 }
+
+/// zkSync options of `cast send`, `cast mktx` and `cast estimate`.
+#[derive(Clone, Debug, Default, Parser)]
+#[command(next_help_heading = "zkSync options")]
+pub struct ZkTransactionOpts {
+    // `--zksync` sends a zkSync EIP-712 transaction, with the paymaster set via
+    // `--zk-paymaster-address` and `--zk-paymaster-input`, if any
+    #[command(flatten)]
+    pub args: ZkSyncArgs,
+
+    /// Paths of the bytecodes to pass as factory dependencies, either hex encoded or raw.
+    #[arg(long = "zk-factory-deps", value_name = "PATHS", num_args(1..), requires = "startup")]
+    pub factory_deps: Vec<PathBuf>,
+
+    /// Gas per pubdata byte limit, estimated via `zks_estimateFee` if not set.
+    #[arg(long = "zk-gas-per-pubdata", value_name = "GAS_PER_PUBDATA", requires = "startup")]
+    pub gas_per_pubdata: Option<U256>,
+}
+
+impl ZkTransactionOpts {
+    /// Returns true if the transaction should be sent as a zkSync EIP-712 transaction.
+    pub fn enabled(&self) -> bool {
+        self.args.enabled()
+    }
+
+    /// Errors if zkSync is enabled along with transaction options it does not support.
+    pub fn validate(&self, tx: &TransactionOpts) -> Result<()> {
+        if self.enabled() {
+            eyre::ensure!(
+                !tx.blob && tx.blob_gas_price.is_none(),
+                "blob transactions are not supported by zkSync"
+            );
+        }
+        Ok(())
+    }
+
+    /// Reads the factory dependencies from the configured paths.
+    pub fn factory_deps(&self) -> Result<Vec<Vec<u8>>> {
+        self.factory_deps
+            .iter()
+            .map(|path| {
+                let content = std::fs::read(path)
+                    .wrap_err_with(|| format!("failed to read factory dep {}", path.display()))?;
+                Ok(match std::str::from_utf8(&content).map(|s| hex::decode(s.trim())) {
+                    Ok(Ok(bytecode)) => bytecode,
+                    _ => content,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the paymaster params, if a paymaster was set.
+    pub fn paymaster_params(&self) -> Option<PaymasterParams> {
+        self.args.paymaster_params()
+    }
+}
+
+/// Prepares a transaction built with [CastTxBuilder::build_raw] to be sent as a zkSync EIP-712
+/// transaction, returning its factory dependencies.
+///
+/// Contract creations are turned into calls to the `ContractDeployer` system contract, which
+/// require the bytecode of the contract to be passed as a factory dependency.
+pub async fn prepare_zk_tx<P: Provider<T, AnyNetwork>, T: Transport + Clone>(
+    provider: &P,
+    tx: &mut WithOtherFields<TransactionRequest>,
+    code: Option<&str>,
+    zk: &ZkTransactionOpts,
+) -> Result<Vec<Vec<u8>>> {
+    let mut factory_deps = zk.factory_deps()?;
+
+    if let Some(code) = code {
+        let bytecode = hex::decode(code)?;
+        eyre::ensure!(bytecode.len() % 32 == 0, "zkSync bytecode length must be a multiple of 32");
+        let input = tx.input.input().cloned().unwrap_or_default();
+        let constructor_args = input.get(bytecode.len()..).unwrap_or_default().to_vec();
+        let data = Bytes::from(encode_create_params(
+            &CreateScheme::Create,
+            hash_bytecode(&bytecode),
+            constructor_args,
+        ));
+        tx.input = TransactionInput { input: Some(data.clone()), data: Some(data) };
+        tx.set_kind(TxKind::Call(CONTRACT_DEPLOYER_ADDRESS.to_address()));
+        factory_deps.push(bytecode);
+    }
+
+    // EIP-712 transactions use the gas price as max fee per gas
+    if tx.gas_price.is_none() {
+        tx.gas_price = match tx.max_fee_per_gas {
+            Some(max_fee_per_gas) => Some(max_fee_per_gas),
+            None => Some(provider.get_gas_price().await?),
+        };
+    }
+
+    if tx.nonce.is_none() {
+        let from = tx.from.ok_or_eyre("`from` cannot be empty")?;
+        tx.nonce = Some(provider.get_transaction_count(from).await?);
+    }
+
+    Ok(factory_deps)
+}
@@ -50,6 +50,7 @@ pub use foundry_evm::*;
 
 pub mod base;
 pub mod errors;
+pub mod zks;
 mod rlp_converter;
 
 use rlp_converter::Item;
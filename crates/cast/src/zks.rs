@@ -0,0 +1,194 @@
+//! Typed access to the zkSync specific `zks_*` RPC namespace.
+
+use alloy_network::AnyNetwork;
+use alloy_primitives::{Address, Bytes, B256};
+use alloy_provider::Provider;
+use alloy_rpc_types::BlockId;
+use alloy_transport::Transport;
+use eyre::Result;
+use foundry_zksync_core::{
+    convert::{ConvertB256, ConvertH160},
+    state::{get_nonce_storage, parse_full_nonce},
+};
+use std::marker::PhantomData;
+use zksync_types::{
+    api::{BlockDetails, BridgeAddresses, L1BatchDetails, L2ToL1LogProof, TransactionDetails},
+    fee_model::FeeParams,
+    L1BatchNumber, L2BlockNumber, H160,
+};
+
+pub struct ZksCast<P, T> {
+    provider: P,
+    transport: PhantomData<T>,
+}
+
+impl<T, P> ZksCast<P, T>
+where
+    T: Transport + Clone,
+    P: Provider<T, AnyNetwork>,
+{
+    /// Creates a new ZksCast instance from the provided client
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use alloy_provider::{network::AnyNetwork, ProviderBuilder, RootProvider};
+    /// use cast::zks::ZksCast;
+    ///
+    /// # async fn foo() -> eyre::Result<()> {
+    /// let provider =
+    ///     ProviderBuilder::<_, _, AnyNetwork>::default().on_builtin("http://localhost:8011").await?;
+    /// let cast = ZksCast::new(provider);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(provider: P) -> Self {
+        Self { provider, transport: PhantomData }
+    }
+
+    /// Returns the addresses of the default bridge contracts.
+    pub async fn bridge_contracts(&self) -> Result<BridgeAddresses> {
+        Ok(self.provider.raw_request("zks_getBridgeContracts".into(), ()).await?)
+    }
+
+    /// Returns the address of the main zkSync contract on L1.
+    pub async fn main_contract(&self) -> Result<Address> {
+        let address: H160 = self.provider.raw_request("zks_getMainContract".into(), ()).await?;
+        Ok(address.to_address())
+    }
+
+    /// Returns the proof of the L2 to L1 log with the given index emitted by the given
+    /// transaction, or of its first log if no index is given.
+    pub async fn l2_to_l1_log_proof(
+        &self,
+        tx_hash: B256,
+        index: Option<usize>,
+    ) -> Result<Option<L2ToL1LogProof>> {
+        Ok(self
+            .provider
+            .raw_request("zks_getL2ToL1LogProof".into(), (tx_hash.to_h256(), index))
+            .await?)
+    }
+
+    /// Returns the zkSync specific details of the given transaction.
+    pub async fn transaction_details(&self, tx_hash: B256) -> Result<Option<TransactionDetails>> {
+        Ok(self
+            .provider
+            .raw_request("zks_getTransactionDetails".into(), (tx_hash.to_h256(),))
+            .await?)
+    }
+
+    /// Returns the zkSync specific details of the given L2 block.
+    pub async fn block_details(&self, number: u32) -> Result<Option<BlockDetails>> {
+        Ok(self
+            .provider
+            .raw_request("zks_getBlockDetails".into(), (L2BlockNumber(number),))
+            .await?)
+    }
+
+    /// Returns the details of the given L1 batch.
+    pub async fn batch_details(&self, number: u32) -> Result<Option<L1BatchDetails>> {
+        Ok(self
+            .provider
+            .raw_request("zks_getL1BatchDetails".into(), (L1BatchNumber(number),))
+            .await?)
+    }
+
+    /// Returns the bytecode with the given bytecode hash, if it is known to the node.
+    pub async fn bytecode_by_hash(&self, hash: B256) -> Result<Option<Bytes>> {
+        let bytecode: Option<Vec<u8>> =
+            self.provider.raw_request("zks_getBytecodeByHash".into(), (hash.to_h256(),)).await?;
+        Ok(bytecode.map(Into::into))
+    }
+
+    /// Returns the current fee parameters of the node.
+    pub async fn fee_params(&self) -> Result<FeeParams> {
+        Ok(self.provider.raw_request("zks_getFeeParams".into(), ()).await?)
+    }
+
+    /// Returns the deployment nonce of the given account, which is used to derive the addresses of
+    /// the contracts it deploys with `CREATE`.
+    pub async fn deploy_nonce(&self, who: Address, block: Option<BlockId>) -> Result<u64> {
+        let (nonce_holder, slot) = get_nonce_storage(who);
+        let full_nonce = self
+            .provider
+            .get_storage_at(nonce_holder, slot)
+            .block_id(block.unwrap_or_default())
+            .await?;
+        Ok(parse_full_nonce(full_nonce).deploy_nonce)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_network::{EthereumWallet, TransactionBuilder};
+    use alloy_primitives::U256;
+    use alloy_rpc_types::TransactionRequest;
+    use alloy_serde::WithOtherFields;
+    use anvil::{spawn, NodeConfig};
+    use foundry_common::provider::{get_http_provider, ProviderBuilder};
+    use foundry_zksync_core::{convert::ConvertH256, hash_bytecode};
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn bytecode_by_hash() {
+        let (api, handle) = spawn(NodeConfig::test().with_zksync(true)).await;
+        let signer = handle.dev_wallets().next().unwrap();
+        let from = signer.address();
+        let provider = get_http_provider(handle.http_endpoint());
+        let cast = ZksCast::new(&provider);
+
+        let factory_dep = vec![1u8; 32];
+        let bytecode_hash = hash_bytecode(&factory_dep).to_b256();
+        assert_eq!(cast.bytecode_by_hash(bytecode_hash).await.unwrap(), None);
+
+        let tx = TransactionRequest::default()
+            .with_from(from)
+            .with_to(Address::with_last_byte(1))
+            .with_chain_id(provider.get_chain_id().await.unwrap())
+            .with_nonce(0)
+            .with_gas_price(api.gas_price());
+        let raw_tx = foundry_zksync_core::new_eip712_transaction(
+            WithOtherFields::new(tx),
+            vec![factory_dep.clone()],
+            None,
+            None,
+            &provider,
+            signer,
+        )
+        .await
+        .unwrap();
+        let receipt =
+            provider.send_raw_transaction(&raw_tx).await.unwrap().get_receipt().await.unwrap();
+        assert!(receipt.status());
+
+        assert_eq!(cast.bytecode_by_hash(bytecode_hash).await.unwrap(), Some(factory_dep.into()));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn deploy_nonce_is_separate_from_tx_nonce() {
+        let (_api, handle) = spawn(NodeConfig::test().with_zksync(true)).await;
+        let wallet = handle.dev_wallets().next().unwrap();
+        let from = wallet.address();
+        let provider = ProviderBuilder::new(&handle.http_endpoint())
+            .build_with_wallet(EthereumWallet::from(wallet))
+            .unwrap();
+
+        let tx = TransactionRequest::default()
+            .with_from(from)
+            .with_to(Address::with_last_byte(1))
+            .with_value(U256::from(1));
+        let receipt = provider
+            .send_transaction(WithOtherFields::new(tx))
+            .await
+            .unwrap()
+            .get_receipt()
+            .await
+            .unwrap();
+        assert!(receipt.status());
+
+        let cast = ZksCast::new(&provider);
+        assert_eq!(provider.get_transaction_count(from).await.unwrap(), 1);
+        assert_eq!(cast.deploy_nonce(from, None).await.unwrap(), 0);
+    }
+}
@@ -0,0 +1,137 @@
+//! Contains tests for the zkSync options of cast commands, run against `anvil --zksync`
+
+use alloy_primitives::{address, hex, U256};
+use anvil::{spawn, NodeConfig};
+use foundry_test_utils::casttest_async;
+use foundry_zksync_core::hash_bytecode;
+
+// tests that `cast send --zksync` sends a value transfer as an EIP-712 transaction
+casttest_async!(send_zksync_value, |_prj, cmd| {
+    let (api, handle) = spawn(NodeConfig::test().with_zksync(true)).await;
+    let rpc = handle.http_endpoint();
+    let wallet = handle.dev_wallets().next().unwrap();
+    let pk = hex::encode(wallet.credential().to_bytes());
+    let to = address!("0000000000000000000000000000000000c0ffee");
+
+    cmd.args([
+        "send",
+        "--zksync",
+        "--json",
+        "--rpc-url",
+        rpc.as_str(),
+        "--private-key",
+        pk.as_str(),
+        "--value",
+        "100",
+        &to.to_string(),
+    ]);
+    let receipt: serde_json::Value = serde_json::from_str(&cmd.stdout_lossy()).unwrap();
+
+    assert_eq!(receipt["type"], "0x71");
+    assert_eq!(receipt["status"], "0x1");
+    assert_eq!(api.balance(to, None).await.unwrap(), U256::from(100));
+});
+
+// tests that `cast mktx --zksync` signs an EIP-712 transaction without sending it
+casttest_async!(mktx_zksync, |_prj, cmd| {
+    let (api, handle) = spawn(NodeConfig::test().with_zksync(true)).await;
+    let rpc = handle.http_endpoint();
+    let wallet = handle.dev_wallets().next().unwrap();
+    let pk = hex::encode(wallet.credential().to_bytes());
+
+    cmd.args([
+        "mktx",
+        "--zksync",
+        "--rpc-url",
+        rpc.as_str(),
+        "--private-key",
+        pk.as_str(),
+        "--value",
+        "100",
+        "0x0000000000000000000000000000000000c0ffee",
+    ]);
+    let output = cmd.stdout_lossy();
+
+    assert!(output.trim().starts_with("0x71"), "not an EIP-712 transaction: {output}");
+    assert_eq!(api.transaction_count(wallet.address(), None).await.unwrap(), U256::ZERO);
+});
+
+// tests that `cast estimate --zksync` uses `zks_estimateFee`
+casttest_async!(estimate_zksync, |_prj, cmd| {
+    let (_api, handle) = spawn(NodeConfig::test().with_zksync(true)).await;
+    let rpc = handle.http_endpoint();
+    let from = handle.dev_accounts().next().unwrap();
+
+    cmd.args([
+        "estimate",
+        "--zksync",
+        "--rpc-url",
+        rpc.as_str(),
+        "--from",
+        &from.to_string(),
+        "--value",
+        "100",
+        "0x0000000000000000000000000000000000c0ffee",
+    ]);
+    let gas: u64 = cmd.stdout_lossy().trim().parse().unwrap();
+
+    // transactions in the zkVM cost more than the EVM intrinsic gas
+    assert!(gas > 21000, "unexpected estimate: {gas}");
+});
+
+// tests that blob transactions are rejected in zkSync mode
+casttest_async!(send_zksync_rejects_blob, |_prj, cmd| {
+    let (_api, handle) = spawn(NodeConfig::test().with_zksync(true)).await;
+    let rpc = handle.http_endpoint();
+    let wallet = handle.dev_wallets().next().unwrap();
+    let pk = hex::encode(wallet.credential().to_bytes());
+
+    cmd.args([
+        "send",
+        "--zksync",
+        "--blob",
+        "--rpc-url",
+        rpc.as_str(),
+        "--private-key",
+        pk.as_str(),
+        "0x0000000000000000000000000000000000c0ffee",
+    ]);
+
+    assert!(cmd.stderr_lossy().contains("blob transactions are not supported by zkSync"));
+});
+
+// tests that `cast zks bytecode` returns the factory dependencies published by `cast send`
+casttest_async!(zks_bytecode_by_hash, |prj, cmd| {
+    let (_api, handle) = spawn(NodeConfig::test().with_zksync(true)).await;
+    let rpc = handle.http_endpoint();
+    let wallet = handle.dev_wallets().next().unwrap();
+    let pk = hex::encode(wallet.credential().to_bytes());
+
+    // any bytecode with an odd number of words can be published as a factory dependency
+    let factory_dep = [1u8; 32];
+    let factory_dep_path = prj.root().join("factory_dep.hex");
+    std::fs::write(&factory_dep_path, hex::encode_prefixed(factory_dep)).unwrap();
+    let bytecode_hash = hex::encode_prefixed(hash_bytecode(&factory_dep));
+
+    cmd.args(["zks", "bytecode", &bytecode_hash, "--rpc-url", rpc.as_str()]);
+    assert!(cmd.stderr_lossy().contains("bytecode not found"));
+
+    cmd.cast_fuse().args([
+        "send",
+        "--zksync",
+        "--rpc-url",
+        rpc.as_str(),
+        "--private-key",
+        pk.as_str(),
+        "--zk-factory-deps",
+        factory_dep_path.to_str().unwrap(),
+        "0x0000000000000000000000000000000000c0ffee",
+    ]);
+    cmd.assert_success();
+
+    cmd.cast_fuse().args(["zks", "bytecode", &bytecode_hash, "--rpc-url", rpc.as_str()]);
+    assert_eq!(cmd.stdout_lossy().trim(), hex::encode_prefixed(factory_dep));
+
+    cmd.cast_fuse().args(["zks", "bytecode", &bytecode_hash, "--json", "--rpc-url", rpc.as_str()]);
+    assert_eq!(cmd.stdout_lossy().trim(), format!("\"{}\"", hex::encode_prefixed(factory_dep)));
+});
@@ -1,6 +1,6 @@
 use alloy_primitives::{Bytes, U256};
 use revm::interpreter::InstructionResult;
-use std::cmp::Ordering;
+use std::{cmp::Ordering, collections::BTreeMap};
 
 /// Mocked call data.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
@@ -36,3 +36,25 @@ impl Ord for MockCallDataContext {
         self.calldata.cmp(&other.calldata).reverse().then(self.value.cmp(&other.value).reverse())
     }
 }
+
+/// Returns the mocked return data of a call to a mocked address, given its calldata and value.
+///
+/// An exact match of the calldata and value takes precedence, otherwise the mock with the most
+/// specific calldata matching the start of the call's calldata is returned, as long as its value
+/// matches if one was given.
+pub fn find_mock<'a>(
+    mocks: &'a BTreeMap<MockCallDataContext, MockCallReturnData>,
+    calldata: &Bytes,
+    value: Option<U256>,
+) -> Option<&'a MockCallReturnData> {
+    let ctx = MockCallDataContext { calldata: calldata.clone(), value };
+    mocks.get(&ctx).or_else(|| {
+        mocks
+            .iter()
+            .find(|(mock, _)| {
+                calldata.get(..mock.calldata.len()) == Some(&mock.calldata[..]) &&
+                    mock.value.map_or(true, |mock_value| Some(mock_value) == value)
+            })
+            .map(|(_, v)| v)
+    })
+}
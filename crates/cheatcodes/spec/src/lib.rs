@@ -85,6 +85,9 @@ impl Cheatcodes<'static> {
                 Vm::AccountAccess::STRUCT.clone(),
                 Vm::StorageAccess::STRUCT.clone(),
                 Vm::Gas::STRUCT.clone(),
+                Vm::L2ToL1Log::STRUCT.clone(),
+                Vm::L2ToL1Message::STRUCT.clone(),
+                Vm::L1TxResult::STRUCT.clone(),
             ]),
             enums: Cow::Owned(vec![
                 Vm::CallerMode::ENUM.clone(),
@@ -110,6 +110,39 @@ interface Vm {
         uint64 gasRemaining;
     }
 
+    /// An L2 to L1 log emitted by the ZK-VM. Returned by `zkGetRecordedL2ToL1Logs`.
+    struct L2ToL1Log {
+        /// Whether the log is a service log, emitted by the system contracts.
+        bool isService;
+        /// The address that emitted the log.
+        address sender;
+        /// The key of the log.
+        bytes32 key;
+        /// The value of the log.
+        bytes32 value;
+    }
+
+    /// A message sent from the ZK-VM to L1 with `L1Messenger.sendToL1`. Returned by
+    /// `zkGetRecordedL2ToL1Messages`.
+    struct L2ToL1Message {
+        /// The address that sent the message.
+        address sender;
+        /// The message.
+        bytes data;
+    }
+
+    /// The result of a priority (L1 to L2) transaction. Returned by `zkExecuteL1Tx`.
+    struct L1TxResult {
+        /// Whether the transaction succeeded.
+        bool success;
+        /// The data returned by the transaction, or its revert data if it failed.
+        bytes returnData;
+        /// The gas spent by the transaction, out of its gas limit.
+        uint256 gasUsed;
+        /// The amount of ETH refunded to the refund recipient, in wei.
+        uint256 refund;
+    }
+
     /// An RPC URL and its alias. Returned by `rpcUrlStructs`.
     struct Rpc {
         /// The alias of the RPC URL.
@@ -457,6 +490,28 @@ interface Vm {
     #[cheatcode(group = Evm, safety = Unsafe)]
     function setNonceUnsafe(address account, uint64 newNonce) external;
 
+    /// Gets the deployment nonce of an account in the ZK-VM, which is used to derive the addresses
+    /// of the contracts it deploys with `CREATE`.
+    #[cheatcode(group = Evm, safety = Safe)]
+    function zkGetDeployNonce(address account) external view returns (uint64 nonce);
+
+    /// Sets the deployment nonce of an account in the ZK-VM, keeping its transaction nonce.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function zkSetDeployNonce(address account, uint64 newNonce) external;
+
+    /// Gets the bytecode hash of an account in the ZK-VM, as stored by the `AccountCodeStorage`
+    /// system contract.
+    #[cheatcode(group = Evm, safety = Safe)]
+    function zkGetAccountCodeHash(address account) external view returns (bytes32 codeHash);
+
+    /// Gets all the L2 to L1 logs emitted by the ZK-VM since the last call to this cheatcode.
+    #[cheatcode(group = Evm, safety = Safe)]
+    function zkGetRecordedL2ToL1Logs() external returns (L2ToL1Log[] memory logs);
+
+    /// Gets all the L2 to L1 messages sent from the ZK-VM since the last call to this cheatcode.
+    #[cheatcode(group = Evm, safety = Safe)]
+    function zkGetRecordedL2ToL1Messages() external returns (L2ToL1Message[] memory messages);
+
     /// Stores a value to an address' storage slot.
     #[cheatcode(group = Evm, safety = Unsafe)]
     function store(address target, bytes32 slot, bytes32 value) external;
@@ -698,6 +753,27 @@ interface Vm {
     #[cheatcode(group = Testing, safety = Safe)]
     function zkRegisterContract(string calldata name, bytes32 evmBytecodeHash, bytes calldata evmDeployedBytecode, bytes calldata evmBytecode, bytes32 zkBytecodeHash, bytes calldata zkDeployedBytecode) external pure;
 
+    /// Use a paymaster for the next ZK-VM CALL or CREATE.
+    #[cheatcode(group = Testing, safety = Safe)]
+    function zkUsePaymaster(address paymaster_address, bytes calldata paymaster_input) external pure;
+
+    /// Sends the next ZK-VM CALL or CREATE as a transaction from the given smart account, running
+    /// its validation with the provided signature.
+    #[cheatcode(group = Testing, safety = Safe)]
+    function zkUseAccount(address account, bytes calldata signature) external pure;
+
+    /// Executes a priority (L1 to L2) transaction requested by `from` on L1 in the ZK-VM, sending it
+    /// from the aliased `from` address. The unspent fees are refunded to `from`, which is aliased if
+    /// it is a contract like the `Mailbox` does.
+    #[cheatcode(group = Testing, safety = Unsafe)]
+    function zkExecuteL1Tx(address from, address to, uint256 value, bytes calldata data, uint256 gasLimit, bytes[] calldata factoryDeps) external returns (L1TxResult memory result);
+
+    /// Executes a priority (L1 to L2) transaction requested by `from` on L1 in the ZK-VM, sending it
+    /// from the aliased `from` address. The unspent fees are refunded to `refundRecipient`, or `from`
+    /// if it is zero, which is aliased if it is a contract like the `Mailbox` does.
+    #[cheatcode(group = Testing, safety = Unsafe)]
+    function zkExecuteL1Tx(address from, address to, uint256 value, bytes calldata data, uint256 gasLimit, bytes[] calldata factoryDeps, address refundRecipient) external returns (L1TxResult memory result);
+
     /// If the condition is false, discard this run's fuzz inputs and generate new ones.
     #[cheatcode(group = Testing, safety = Safe)]
     function assume(bool condition) external pure;
@@ -726,6 +802,11 @@ interface Vm {
     #[cheatcode(group = Testing, safety = Safe)]
     function sleep(uint256 duration) external;
 
+    /// Expects an L2 to L1 message with the given data to be sent from the ZK-VM by `sender` during
+    /// the test.
+    #[cheatcode(group = Testing, safety = Unsafe)]
+    function expectL2ToL1Message(address sender, bytes calldata data) external;
+
     /// Expects a call to an address with the specified calldata.
     /// Calldata can either be a strict or a partial match.
     #[cheatcode(group = Testing, safety = Unsafe)]
@@ -1511,6 +1592,9 @@ interface Vm {
 
     /// Gets the creation bytecode from an artifact file. Takes in the relative path to the json file or the path to the
     /// artifact in the form of <path>:<contract>:<version> where <contract> and <version> parts are optional.
+    ///
+    /// When running in the ZK-VM, the zksolc bytecode of the contract is returned instead of the solc one,
+    /// which is returned again once the ZK-VM is disabled with `zkVm(false)`.
     #[cheatcode(group = Filesystem)]
     function getCode(string calldata artifactPath) external view returns (bytes memory creationBytecode);
 
@@ -1528,6 +1612,9 @@ interface Vm {
 
     /// Gets the deployed bytecode from an artifact file. Takes in the relative path to the json file or the path to the
     /// artifact in the form of <path>:<contract>:<version> where <contract> and <version> parts are optional.
+    ///
+    /// When running in the ZK-VM, the zksolc bytecode of the contract is returned instead of the solc one,
+    /// which is returned again once the ZK-VM is disabled with `zkVm(false)`.
     #[cheatcode(group = Filesystem)]
     function getDeployedCode(string calldata artifactPath) external view returns (bytes memory runtimeBytecode);
 
@@ -2193,10 +2280,16 @@ interface Vm {
     function computeCreateAddress(address deployer, uint256 nonce) external pure returns (address);
 
     /// Compute the address of a contract created with CREATE2 using the given CREATE2 deployer.
+    ///
+    /// When running in the ZK-VM, the zkSync derivation is used, which only supports the init code hash of a contract
+    /// without constructor arguments, as the constructor input can't be recovered from it.
     #[cheatcode(group = Utilities)]
     function computeCreate2Address(bytes32 salt, bytes32 initCodeHash, address deployer) external pure returns (address);
 
     /// Compute the address of a contract created with CREATE2 using the default CREATE2 deployer.
+    ///
+    /// When running in the ZK-VM, the zkSync derivation is used with the `Create2Factory` as the default deployer, which
+    /// only supports the init code hash of a contract without constructor arguments.
     #[cheatcode(group = Utilities)]
     function computeCreate2Address(bytes32 salt, bytes32 initCodeHash) external pure returns (address);
 
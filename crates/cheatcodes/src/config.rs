@@ -9,6 +9,10 @@ use foundry_config::{
 };
 use foundry_evm_core::opts::EvmOpts;
 use foundry_zksync_compiler::DualCompiledContracts;
+use foundry_zksync_core::{
+    vm::{ZkSystemContracts, ZkSystemEnv},
+    ZkAccountData,
+};
 use semver::Version;
 use std::{
     collections::HashMap,
@@ -57,6 +61,12 @@ pub struct CheatsConfig {
     pub dual_compiled_contracts: DualCompiledContracts,
     /// Use ZK-VM on startup
     pub use_zk: bool,
+    /// Whether to record the gas usage breakdown of ZK-VM executions
+    pub zk_gas_breakdown: bool,
+    /// Protocol version and system contracts of the ZK-VM
+    pub zk_system_env: ZkSystemEnv,
+    /// Smart account to send the ZK-VM calls and deployments from, once it is deployed
+    pub zk_custom_account: Option<ZkAccountData>,
     /// Whether to enable legacy (non-reverting) assertions.
     pub assertions_revert: bool,
 }
@@ -101,6 +111,14 @@ impl CheatsConfig {
             running_version,
             dual_compiled_contracts,
             use_zk,
+            zk_gas_breakdown: config.zksync.gas_breakdown,
+            zk_system_env: zk_system_env(config),
+            zk_custom_account: config.zksync.custom_account.map(|address| {
+                ZkAccountData::new(
+                    address,
+                    config.zksync.custom_account_signature.clone().unwrap_or_default(),
+                )
+            }),
             assertions_revert: config.assertions_revert,
         }
     }
@@ -231,11 +249,24 @@ impl Default for CheatsConfig {
             running_version: Default::default(),
             dual_compiled_contracts: Default::default(),
             use_zk: false,
+            zk_gas_breakdown: false,
+            zk_system_env: Default::default(),
+            zk_custom_account: None,
             assertions_revert: true,
         }
     }
 }
 
+/// Returns the system environment of the ZK-VM configured in the `[zksync]` section.
+fn zk_system_env(config: &Config) -> ZkSystemEnv {
+    let system_contracts = match &config.zksync.system_contracts_path {
+        Some(path) => ZkSystemContracts::Local(config.root.0.join(path)),
+        None if config.zksync.system_contracts_security => ZkSystemContracts::BuiltIn,
+        None => ZkSystemContracts::BuiltInWithoutSecurity,
+    };
+    ZkSystemEnv::new(config.zksync.protocol_version, system_contracts)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -203,6 +203,20 @@ impl Cheatcode for getRecordedLogsCall {
     }
 }
 
+impl Cheatcode for zkGetRecordedL2ToL1LogsCall {
+    fn apply(&self, state: &mut Cheatcodes) -> Result {
+        let Self {} = self;
+        Ok(std::mem::take(&mut state.recorded_l2_to_l1_logs).abi_encode())
+    }
+}
+
+impl Cheatcode for zkGetRecordedL2ToL1MessagesCall {
+    fn apply(&self, state: &mut Cheatcodes) -> Result {
+        let Self {} = self;
+        Ok(std::mem::take(&mut state.recorded_l2_to_l1_messages).abi_encode())
+    }
+}
+
 impl Cheatcode for pauseGasMeteringCall {
     fn apply(&self, state: &mut Cheatcodes) -> Result {
         let Self {} = self;
@@ -480,6 +494,30 @@ impl Cheatcode for setNonceUnsafeCall {
     }
 }
 
+impl Cheatcode for zkGetDeployNonceCall {
+    fn apply_stateful<DB: DatabaseExt>(&self, ccx: &mut CheatsCtxt<DB>) -> Result {
+        let Self { account } = self;
+        let nonce = foundry_zksync_core::cheatcodes::get_deploy_nonce(*account, ccx.ecx);
+        Ok(nonce.abi_encode())
+    }
+}
+
+impl Cheatcode for zkSetDeployNonceCall {
+    fn apply_stateful<DB: DatabaseExt>(&self, ccx: &mut CheatsCtxt<DB>) -> Result {
+        let Self { account, newNonce } = *self;
+        foundry_zksync_core::cheatcodes::set_deploy_nonce(account, newNonce, ccx.ecx);
+        Ok(Default::default())
+    }
+}
+
+impl Cheatcode for zkGetAccountCodeHashCall {
+    fn apply_stateful<DB: DatabaseExt>(&self, ccx: &mut CheatsCtxt<DB>) -> Result {
+        let Self { account } = self;
+        let code_hash = foundry_zksync_core::cheatcodes::get_account_code_hash(*account, ccx.ecx);
+        Ok(code_hash.abi_encode())
+    }
+}
+
 impl Cheatcode for storeCall {
     fn apply_stateful<DB: DatabaseExt>(&self, ccx: &mut CheatsCtxt<DB>) -> Result {
         let Self { target, slot, value } = *self;
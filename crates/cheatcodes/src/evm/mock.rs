@@ -24,10 +24,7 @@ impl Cheatcode for mockCall_0Call {
             ccx.ecx.journaled_state.set_code(*callee, code);
         }
 
-        if ccx.state.use_zk_vm {
-            foundry_zksync_core::cheatcodes::set_mocked_account(*callee, ccx.ecx, ccx.caller);
-        }
-
+        set_zk_mocked_account(ccx, callee);
         mock_call(ccx.state, callee, data, None, returnData, InstructionResult::Return);
         Ok(Default::default())
     }
@@ -37,27 +34,38 @@ impl Cheatcode for mockCall_1Call {
     fn apply_stateful<DB: DatabaseExt>(&self, ccx: &mut CheatsCtxt<DB>) -> Result {
         let Self { callee, msgValue, data, returnData } = self;
         ccx.ecx.load_account(*callee)?;
+        set_zk_mocked_account(ccx, callee);
         mock_call(ccx.state, callee, data, Some(msgValue), returnData, InstructionResult::Return);
         Ok(Default::default())
     }
 }
 
 impl Cheatcode for mockCallRevert_0Call {
-    fn apply(&self, state: &mut Cheatcodes) -> Result {
+    fn apply_stateful<DB: DatabaseExt>(&self, ccx: &mut CheatsCtxt<DB>) -> Result {
         let Self { callee, data, revertData } = self;
-        mock_call(state, callee, data, None, revertData, InstructionResult::Revert);
+        set_zk_mocked_account(ccx, callee);
+        mock_call(ccx.state, callee, data, None, revertData, InstructionResult::Revert);
         Ok(Default::default())
     }
 }
 
 impl Cheatcode for mockCallRevert_1Call {
-    fn apply(&self, state: &mut Cheatcodes) -> Result {
+    fn apply_stateful<DB: DatabaseExt>(&self, ccx: &mut CheatsCtxt<DB>) -> Result {
         let Self { callee, msgValue, data, revertData } = self;
-        mock_call(state, callee, data, Some(msgValue), revertData, InstructionResult::Revert);
+        set_zk_mocked_account(ccx, callee);
+        mock_call(ccx.state, callee, data, Some(msgValue), revertData, InstructionResult::Revert);
         Ok(Default::default())
     }
 }
 
+/// Marks the mocked account as having code in the zkVM, so calls to it are not rejected when it is
+/// empty.
+fn set_zk_mocked_account<DB: DatabaseExt>(ccx: &mut CheatsCtxt<DB>, callee: &Address) {
+    if ccx.state.use_zk_vm {
+        foundry_zksync_core::cheatcodes::set_mocked_account(*callee, ccx.ecx, ccx.caller);
+    }
+}
+
 #[allow(clippy::ptr_arg)] // Not public API, doesn't matter
 fn mock_call(
     state: &mut Cheatcodes,
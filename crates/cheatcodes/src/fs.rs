@@ -272,20 +272,7 @@ impl Cheatcode for deployCode_0Call {
     ) -> Result {
         let Self { artifactPath: path } = self;
         let bytecode = get_artifact_code(ccx.state, path, false)?;
-        let output = executor
-            .exec_create(
-                CreateInputs {
-                    caller: ccx.caller,
-                    scheme: revm::primitives::CreateScheme::Create,
-                    value: U256::ZERO,
-                    init_code: bytecode,
-                    gas_limit: ccx.gas_limit,
-                },
-                ccx,
-            )
-            .unwrap();
-
-        Ok(output.address.unwrap().abi_encode())
+        deploy_code(ccx, executor, bytecode)
     }
 }
 
@@ -298,23 +285,32 @@ impl Cheatcode for deployCode_1Call {
         let Self { artifactPath: path, constructorArgs } = self;
         let mut bytecode = get_artifact_code(ccx.state, path, false)?.to_vec();
         bytecode.extend_from_slice(constructorArgs);
-        let output = executor
-            .exec_create(
-                CreateInputs {
-                    caller: ccx.caller,
-                    scheme: revm::primitives::CreateScheme::Create,
-                    value: U256::ZERO,
-                    init_code: bytecode.into(),
-                    gas_limit: ccx.gas_limit,
-                },
-                ccx,
-            )
-            .unwrap();
-
-        Ok(output.address.unwrap().abi_encode())
+        deploy_code(ccx, executor, bytecode.into())
     }
 }
 
+/// Deploys the given init code from the caller, bubbling up the revert data if the deployment
+/// fails.
+fn deploy_code<DB: DatabaseExt, E: CheatcodesExecutor>(
+    ccx: &mut CheatsCtxt<DB>,
+    executor: &mut E,
+    init_code: Bytes,
+) -> Result {
+    let output = executor.exec_create(
+        CreateInputs {
+            caller: ccx.caller,
+            scheme: revm::primitives::CreateScheme::Create,
+            value: U256::ZERO,
+            init_code,
+            gas_limit: ccx.gas_limit,
+        },
+        ccx,
+    )?;
+
+    let address = output.address.ok_or_else(|| crate::Error::from(output.result.output))?;
+    Ok(address.abi_encode())
+}
+
 /// Returns the path to the json artifact depending on the input
 ///
 /// Can parse following input formats:
@@ -325,7 +321,27 @@ impl Cheatcode for deployCode_1Call {
 /// - `path/to/contract.sol:0.8.23`
 /// - `ContractName`
 /// - `ContractName:0.8.23`
+///
+/// When running in the ZK-VM, the zksolc bytecode of the matching contract is returned instead.
 fn get_artifact_code(state: &Cheatcodes, path: &str, deployed: bool) -> Result<Bytes> {
+    let bytecode = get_solc_artifact_code(state, path, deployed)?;
+    if !state.use_zk_vm {
+        return Ok(bytecode)
+    }
+
+    let contract = if deployed {
+        state
+            .dual_compiled_contracts
+            .iter()
+            .find(|contract| contract.evm_deployed_bytecode == bytecode.as_ref())
+    } else {
+        state.dual_compiled_contracts.find_by_evm_bytecode(&bytecode)
+    };
+    // artifacts read from the zksolc output already contain the zksolc bytecode
+    Ok(contract.map_or(bytecode, |contract| contract.zk_deployed_bytecode.clone().into()))
+}
+
+fn get_solc_artifact_code(state: &Cheatcodes, path: &str, deployed: bool) -> Result<Bytes> {
     let path = if path.ends_with(".json") {
         PathBuf::from(path)
     } else {
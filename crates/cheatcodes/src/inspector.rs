@@ -9,7 +9,7 @@ use crate::{
     },
     inspector::utils::CommonCreateInput,
     script::{Broadcast, ScriptWallets},
-    test::expect::{self, ExpectedEmit, ExpectedRevert, ExpectedRevertKind},
+    test::expect::{self, ExpectedEmit, ExpectedL2ToL1Message, ExpectedRevert, ExpectedRevertKind},
     CheatsConfig, CheatsCtxt, DynCheatcode, Error, Result, Vm,
     Vm::AccountAccess,
 };
@@ -18,7 +18,7 @@ use alloy_rpc_types::request::{TransactionInput, TransactionRequest};
 use alloy_sol_types::{SolCall, SolInterface, SolValue};
 use foundry_cheatcodes_common::{
     expect::{ExpectedCallData, ExpectedCallTracker, ExpectedCallType},
-    mock::{MockCallDataContext, MockCallReturnData},
+    mock::{find_mock, MockCallDataContext, MockCallReturnData},
     record::RecordAccess,
 };
 use foundry_common::{evm::Breakpoints, SELECTOR_LEN};
@@ -34,7 +34,9 @@ use foundry_evm_core::{
 use foundry_zksync_compiler::{DualCompiledContract, DualCompiledContracts};
 use foundry_zksync_core::{
     convert::{ConvertH160, ConvertH256, ConvertRU256, ConvertU256},
-    get_account_code_key, get_balance_key, get_nonce_key, ZkTransactionMetadata,
+    get_account_code_key, get_balance_key, get_nonce_key,
+    vm::{Call, EraVmStep, ZkEnv, ZkGasUsage, ZkHitMaps, ZkL2ToL1Log, ZkL2ToL1Message},
+    PaymasterParams, ZkAccountData, ZkTransactionMetadata,
 };
 use itertools::Itertools;
 use revm::{
@@ -51,6 +53,7 @@ use revm::{
 use rustc_hash::FxHashMap;
 use serde_json::Value;
 use std::{
+    borrow::Cow,
     collections::{BTreeMap, HashMap, VecDeque},
     fs::File,
     io::BufReader,
@@ -158,6 +161,26 @@ pub trait CheatcodesExecutor {
     fn console_log<DB: DatabaseExt>(&mut self, ccx: &mut CheatsCtxt<DB>, message: String) {
         self.get_inspector::<DB>(ccx.state).console_log(message);
     }
+
+    /// Records the zkVM call traces and debug steps under the currently active call, which was
+    /// made to `address`.
+    fn trace_zksync<DB: DatabaseExt>(
+        &mut self,
+        ccx_state: &mut Cheatcodes,
+        ecx: &mut EvmContext<DB>,
+        address: Address,
+        call_traces: Vec<Call>,
+        logs: &[Log],
+        debug_steps: Vec<EraVmStep>,
+    ) {
+        self.get_inspector::<DB>(ccx_state).trace_zksync(
+            ecx,
+            address,
+            call_traces,
+            logs,
+            debug_steps,
+        );
+    }
 }
 
 /// Basic implementation of [CheatcodesExecutor] that simply returns the [Cheatcodes] instance as an
@@ -219,6 +242,19 @@ pub struct BroadcastableTransaction {
 /// List of transactions that can be broadcasted.
 pub type BroadcastableTransactions = VecDeque<BroadcastableTransaction>;
 
+/// The gas usage breakdown of a call or create executed in the ZK-VM.
+#[derive(Clone, Debug)]
+pub struct ZkCallGasUsage {
+    /// The called address, or the created address for creates.
+    pub address: Address,
+    /// The calldata, or the init code for creates.
+    pub data: Bytes,
+    /// Whether this was a create.
+    pub is_create: bool,
+    /// The gas usage breakdown.
+    pub usage: ZkGasUsage,
+}
+
 /// An EVM inspector that handles calls to various cheatcodes, each with their own behavior.
 ///
 /// Cheatcodes can be called by contracts during execution to modify the VM environment, such as
@@ -287,6 +323,8 @@ pub struct Cheatcodes {
     pub expected_calls: ExpectedCallTracker,
     /// Expected emits
     pub expected_emits: VecDeque<ExpectedEmit>,
+    /// Expected L2 to L1 messages, checked once the test finishes
+    pub expected_l2_to_l1_messages: Vec<ExpectedL2ToL1Message>,
 
     /// Map of context depths to memory offset ranges that may be written to within the call depth.
     pub allowed_mem_writes: FxHashMap<u64, Vec<Range<u64>>>,
@@ -352,6 +390,37 @@ pub struct Cheatcodes {
     /// This can be done as each test runs with its own [Cheatcodes] instance, thereby
     /// providing the necessary level of isolation.
     pub persisted_factory_deps: HashMap<H256, Vec<u8>>,
+
+    /// Paymaster params to use for the next ZK-VM CALL or CREATE, set via `zkUsePaymaster`.
+    pub paymaster_params: Option<PaymasterParams>,
+
+    /// Smart account to send the next ZK-VM CALL or CREATE from, set via `zkUseAccount`.
+    pub account_data: Option<ZkAccountData>,
+
+    /// Instruction coverage of the ZK-VM executions, recorded only if coverage is enabled.
+    pub zk_coverage: Option<ZkHitMaps>,
+
+    /// Gas usage breakdown of each ZK-VM call and create, in execution order, recorded only if
+    /// the gas breakdown is enabled.
+    pub zk_gas_usage: Option<Vec<ZkCallGasUsage>>,
+
+    /// Debug steps of the ongoing ZK-VM execution, recorded only if debugging is enabled. They
+    /// are handed over to the tracer once the execution finishes.
+    pub zk_debug_steps: Option<Vec<EraVmStep>>,
+
+    /// L2 to L1 logs emitted by the ZK-VM since the last call to `zkGetRecordedL2ToL1Logs`.
+    pub recorded_l2_to_l1_logs: Vec<Vm::L2ToL1Log>,
+
+    /// L2 to L1 messages sent from the ZK-VM since the last call to
+    /// `zkGetRecordedL2ToL1Messages`.
+    pub recorded_l2_to_l1_messages: Vec<Vm::L2ToL1Message>,
+
+    /// Batch fee parameters of the selected zkSync fork, used by the ZK-VM executions instead of
+    /// the ones derived from the block's base fee.
+    pub zk_env: Option<ZkEnv>,
+
+    /// The serial id of the next priority transaction executed with `zkExecuteL1Tx`.
+    pub zk_l1_tx_serial_id: u64,
 }
 
 // This is not derived because calling this in `fn new` with `..Default::default()` creates a second
@@ -405,6 +474,7 @@ impl Cheatcodes {
         persisted_factory_deps.insert(zk_bytecode_hash, zk_deployed_bytecode);
 
         let startup_zk = config.use_zk;
+        let zk_gas_usage = config.zk_gas_breakdown.then(Default::default);
         Self {
             fs_commit: true,
             labels: config.labels.clone(),
@@ -423,6 +493,7 @@ impl Cheatcodes {
             mocked_calls: Default::default(),
             expected_calls: Default::default(),
             expected_emits: Default::default(),
+            expected_l2_to_l1_messages: Default::default(),
             allowed_mem_writes: Default::default(),
             broadcast: Default::default(),
             broadcastable_transactions: Default::default(),
@@ -436,6 +507,15 @@ impl Cheatcodes {
             breakpoints: Default::default(),
             use_zk_vm: Default::default(),
             persisted_factory_deps: Default::default(),
+            paymaster_params: Default::default(),
+            account_data: Default::default(),
+            zk_coverage: Default::default(),
+            zk_gas_usage,
+            zk_debug_steps: Default::default(),
+            recorded_l2_to_l1_logs: Default::default(),
+            recorded_l2_to_l1_messages: Default::default(),
+            zk_env: Default::default(),
+            zk_l1_tx_serial_id: Default::default(),
         }
     }
 
@@ -536,6 +616,7 @@ impl Cheatcodes {
         fork_id: LocalForkId,
     ) {
         let fork_info = data.db.get_fork_info(fork_id).expect("failed getting fork info");
+        self.zk_env = fork_info.zk_env;
         if fork_info.fork_type.is_evm() {
             self.select_evm(data)
         } else {
@@ -724,11 +805,21 @@ impl Cheatcodes {
         }
     }
 
+    pub fn create_with_executor<DB: DatabaseExt>(
+        &mut self,
+        ecx: &mut EvmContext<DB>,
+        call: &mut CreateInputs,
+        executor: &mut impl CheatcodesExecutor,
+    ) -> Option<CreateOutcome> {
+        self.create_common(ecx, call, executor)
+    }
+
     // common create functionality for both legacy and EOF.
     fn create_common<DB, Input>(
         &mut self,
         ecx: &mut EvmContext<DB>,
         mut input: Input,
+        executor: &mut impl CheatcodesExecutor,
     ) -> Option<CreateOutcome>
     where
         DB: DatabaseExt,
@@ -742,14 +833,25 @@ impl Cheatcodes {
             if ecx_inner.journaled_state.depth() >= prank.depth &&
                 input.caller() == prank.prank_caller
             {
+                let mut prank_applied = false;
+
                 // At the target depth we set `msg.sender`
                 if ecx_inner.journaled_state.depth() == prank.depth {
                     input.set_caller(prank.new_caller);
+                    prank_applied = true;
                 }
 
                 // At the target depth, or deeper, we set `tx.origin`
                 if let Some(new_origin) = prank.new_origin {
                     ecx_inner.env.tx.caller = new_origin;
+                    prank_applied = true;
+                }
+
+                // If prank applied for first time, then update
+                if prank_applied {
+                    if let Some(applied_prank) = prank.first_time_applied() {
+                        self.prank = Some(applied_prank);
+                    }
                 }
             }
         }
@@ -786,17 +888,24 @@ impl Cheatcodes {
                     let mut zk_tx = if self.use_zk_vm {
                         to = Some(TxKind::Call(CONTRACT_DEPLOYER_ADDRESS.to_address()));
                         nonce = foundry_zksync_core::nonce(broadcast.new_origin, ecx_inner) as u64;
-                        let contract = self
-                            .dual_compiled_contracts
-                            .find_by_evm_bytecode(&input.init_code().0)
-                            .unwrap_or_else(|| {
-                                panic!("failed finding contract for {:?}", input.init_code())
-                            });
+                        let (contract, constructor_input) = match find_zk_contract(
+                            &self.dual_compiled_contracts,
+                            &call_init_code,
+                        ) {
+                            Ok(found) => found,
+                            Err(err) => {
+                                return Some(CreateOutcome {
+                                    result: InterpreterResult {
+                                        result: InstructionResult::Revert,
+                                        output: Error::encode(err),
+                                        gas,
+                                    },
+                                    address: None,
+                                })
+                            }
+                        };
                         let factory_deps =
-                            self.dual_compiled_contracts.fetch_all_factory_deps(contract);
-
-                        let constructor_input =
-                            call_init_code[contract.evm_bytecode.len()..].to_vec();
+                            self.dual_compiled_contracts.fetch_all_factory_deps(&contract);
 
                         let create_input = foundry_zksync_core::encode_create_params(
                             &input.scheme().unwrap_or(CreateScheme::Create),
@@ -827,7 +936,10 @@ impl Cheatcodes {
                                     nonce: Some(nonce),
                                     ..Default::default()
                                 },
-                                zk_tx: Some(ZkTransactionMetadata { factory_deps }),
+                                zk_tx: Some(ZkTransactionMetadata::new(
+                                    factory_deps,
+                                    self.paymaster_params.clone(),
+                                )),
                             });
 
                             //update nonce for each tx
@@ -849,7 +961,9 @@ impl Cheatcodes {
                             },
                             ..Default::default()
                         },
-                        zk_tx: zk_tx.map(ZkTransactionMetadata::new),
+                        zk_tx: zk_tx.map(|factory_deps| {
+                            ZkTransactionMetadata::new(factory_deps, self.paymaster_params.clone())
+                        }),
                     });
 
                     input.log_debug(self, &input.scheme().unwrap_or(CreateScheme::Create));
@@ -889,19 +1003,40 @@ impl Cheatcodes {
                 return None
             }
 
-            let zk_contract = self
-                .dual_compiled_contracts
-                .find_by_evm_bytecode(&input.init_code().0)
-                .unwrap_or_else(|| panic!("failed finding contract for {:?}", input.init_code()));
+            let (zk_contract, constructor_input) =
+                match find_zk_contract(&self.dual_compiled_contracts, &input.init_code()) {
+                    Ok(found) => found,
+                    Err(err) => {
+                        return Some(CreateOutcome {
+                            result: InterpreterResult {
+                                result: InstructionResult::Revert,
+                                output: Error::encode(err),
+                                gas,
+                            },
+                            address: None,
+                        })
+                    }
+                };
 
-            let factory_deps = self.dual_compiled_contracts.fetch_all_factory_deps(zk_contract);
+            let factory_deps = self.dual_compiled_contracts.fetch_all_factory_deps(&zk_contract);
             tracing::debug!(contract = zk_contract.name, "using dual compiled contract");
 
+            let account_data = self.zk_account_data(&mut ecx.inner);
+            let pranked_sender =
+                self.zk_pranked_sender(ecx.journaled_state.depth(), input.caller());
             let ccx = foundry_zksync_core::vm::CheatcodeTracerContext {
                 mocked_calls: self.mocked_calls.clone(),
                 expected_calls: Some(&mut self.expected_calls),
                 accesses: self.accesses.as_mut(),
                 persisted_factory_deps: Some(&mut self.persisted_factory_deps),
+                paymaster_data: self.paymaster_params.take(),
+                account_data,
+                pranked_sender,
+                coverage: self.zk_coverage.as_mut(),
+                debug_steps: self.zk_debug_steps.as_mut(),
+                zk_env: self.zk_env,
+                system_env: self.config.zk_system_env.clone(),
+                ..Default::default()
             };
             let create_inputs = CreateInputs {
                 scheme: input.scheme().unwrap_or(CreateScheme::Create),
@@ -912,21 +1047,18 @@ impl Cheatcodes {
             };
             if let Ok(result) = foundry_zksync_core::vm::create::<_, DatabaseError>(
                 &create_inputs,
-                zk_contract,
+                &zk_contract,
+                constructor_input,
                 factory_deps,
                 ecx,
                 ccx,
             ) {
-                if let Some(recorded_logs) = &mut self.recorded_logs {
-                    recorded_logs.extend(result.logs.clone().into_iter().map(|log| Vm::Log {
-                        topics: log.data.topics().to_vec(),
-                        data: log.data.data.clone(),
-                        emitter: log.address,
-                    }));
+                self.handle_zk_logs(&result.logs);
+                if result.execution_result.is_success() {
+                    self.handle_zk_l2_to_l1(&result.l2_to_l1_logs, &result.l2_to_l1_messages);
                 }
 
                 // append console logs from zkEVM to the current executor's LogTracer
-                let executor = &mut TransparentCheatcodesExecutor;
                 result.logs.iter().filter_map(decode_console_log).for_each(|decoded_log| {
                     executor.console_log(
                         &mut CheatsCtxt {
@@ -940,12 +1072,29 @@ impl Cheatcodes {
                     );
                 });
 
-                // for each log in cloned logs call handle_expect_emit
-                if !self.expected_emits.is_empty() {
-                    for log in result.logs {
-                        expect::handle_expect_emit(self, &log);
-                    }
+                // append traces from zkEVM to the current executor's TracingInspector
+                let created_address = match &result.execution_result {
+                    ExecutionResult::Success { output: Output::Create(_, address), .. } => *address,
+                    _ => None,
+                };
+                if let Some(zk_gas_usage) = &mut self.zk_gas_usage {
+                    zk_gas_usage.push(ZkCallGasUsage {
+                        address: created_address.unwrap_or_default(),
+                        data: create_inputs.init_code.clone(),
+                        is_create: true,
+                        usage: result.gas_usage,
+                    });
                 }
+                let debug_steps =
+                    self.zk_debug_steps.as_mut().map(std::mem::take).unwrap_or_default();
+                executor.trace_zksync(
+                    self,
+                    ecx,
+                    created_address.unwrap_or_default(),
+                    result.call_traces,
+                    &result.logs,
+                    debug_steps,
+                );
 
                 return match result.execution_result {
                     ExecutionResult::Success { output, .. } => match output {
@@ -1190,17 +1339,7 @@ impl Cheatcodes {
 
         // Handle mocked calls
         if let Some(mocks) = self.mocked_calls.get(&call.bytecode_address) {
-            let ctx =
-                MockCallDataContext { calldata: call.input.clone(), value: call.transfer_value() };
-            if let Some(return_data) = mocks.get(&ctx).or_else(|| {
-                mocks
-                    .iter()
-                    .find(|(mock, _)| {
-                        call.input.get(..mock.calldata.len()) == Some(&mock.calldata[..]) &&
-                            mock.value.map_or(true, |value| Some(value) == call.transfer_value())
-                    })
-                    .map(|(_, v)| v)
-            }) {
+            if let Some(return_data) = find_mock(mocks, &call.input, call.transfer_value()) {
                 return Some(CallOutcome {
                     result: InterpreterResult {
                         result: return_data.ret_type,
@@ -1286,7 +1425,10 @@ impl Cheatcodes {
 
                     let zk_tx = if self.use_zk_vm {
                         // We shouldn't need factory_deps for CALLs
-                        Some(ZkTransactionMetadata { factory_deps: Default::default() })
+                        Some(ZkTransactionMetadata::new(
+                            Default::default(),
+                            self.paymaster_params.clone(),
+                        ))
                     } else {
                         None
                     };
@@ -1388,11 +1530,20 @@ impl Cheatcodes {
 
             info!("running call in zk vm {:#?}", call);
 
+            let account_data = self.zk_account_data(&mut ecx.inner);
+            let pranked_sender = self.zk_pranked_sender(ecx.journaled_state.depth(), call.caller);
             let ccx = foundry_zksync_core::vm::CheatcodeTracerContext {
                 mocked_calls: self.mocked_calls.clone(),
                 expected_calls: Some(&mut self.expected_calls),
                 accesses: self.accesses.as_mut(),
                 persisted_factory_deps: Some(&mut self.persisted_factory_deps),
+                paymaster_data: self.paymaster_params.take(),
+                account_data,
+                pranked_sender,
+                coverage: self.zk_coverage.as_mut(),
+                debug_steps: self.zk_debug_steps.as_mut(),
+                zk_env: self.zk_env,
+                system_env: self.config.zk_system_env.clone(),
             };
             if let Ok(result) = foundry_zksync_core::vm::call::<_, DatabaseError>(call, ecx, ccx) {
                 // append console logs from zkEVM to the current executor's LogTracer
@@ -1409,21 +1560,32 @@ impl Cheatcodes {
                     );
                 });
 
+                if let Some(zk_gas_usage) = &mut self.zk_gas_usage {
+                    zk_gas_usage.push(ZkCallGasUsage {
+                        address: call.target_address,
+                        data: call.input.clone(),
+                        is_create: false,
+                        usage: result.gas_usage,
+                    });
+                }
+
+                // append traces from zkEVM to the current executor's TracingInspector
+                let debug_steps =
+                    self.zk_debug_steps.as_mut().map(std::mem::take).unwrap_or_default();
+                executor.trace_zksync(
+                    self,
+                    ecx,
+                    call.target_address,
+                    result.call_traces,
+                    &result.logs,
+                    debug_steps,
+                );
+
                 // skip log processing for static calls
                 if !call.is_static {
-                    if let Some(recorded_logs) = &mut self.recorded_logs {
-                        recorded_logs.extend(result.logs.clone().into_iter().map(|log| Vm::Log {
-                            topics: log.data.topics().to_vec(),
-                            data: log.data.data.clone(),
-                            emitter: log.address,
-                        }));
-                    }
-
-                    // for each log in cloned logs call handle_expect_emit
-                    if !self.expected_emits.is_empty() {
-                        for log in result.logs {
-                            expect::handle_expect_emit(self, &log);
-                        }
+                    self.handle_zk_logs(&result.logs);
+                    if result.execution_result.is_success() {
+                        self.handle_zk_l2_to_l1(&result.l2_to_l1_logs, &result.l2_to_l1_messages);
                     }
                 }
 
@@ -1483,6 +1645,10 @@ impl<DB: DatabaseExt> Inspector<DB> for Cheatcodes {
         }
         if self.startup_zk && !self.use_zk_vm {
             self.startup_zk = false; // We only do this once.
+            if let Some(fork_id) = ecx.db.active_fork_id() {
+                self.zk_env =
+                    ecx.db.get_fork_info(fork_id).ok().and_then(|fork_info| fork_info.zk_env);
+            }
             self.select_zk_vm(ecx, None);
         }
     }
@@ -1550,18 +1716,7 @@ impl<DB: DatabaseExt> Inspector<DB> for Cheatcodes {
     }
 
     fn log(&mut self, _interpreter: &mut Interpreter, _context: &mut EvmContext<DB>, log: &Log) {
-        if !self.expected_emits.is_empty() {
-            expect::handle_expect_emit(self, log);
-        }
-
-        // `recordLogs`
-        if let Some(storage_recorded_logs) = &mut self.recorded_logs {
-            storage_recorded_logs.push(Vm::Log {
-                topics: log.data.topics().to_vec(),
-                data: log.data.data.clone(),
-                emitter: log.address,
-            });
-        }
+        self.handle_log(log);
     }
 
     fn call(
@@ -1846,6 +2001,20 @@ impl<DB: DatabaseExt> Inspector<DB> for Cheatcodes {
                 outcome.result.output = Error::encode(msg);
                 return outcome;
             }
+
+            // Check if all the expected L2 to L1 messages were sent
+            if let Some(expected) =
+                self.expected_l2_to_l1_messages.iter().find(|expected| !expected.found)
+            {
+                let msg = format!(
+                    "expected an L2 to L1 message from {} with data {}, but it was not sent",
+                    expected.sender,
+                    hex::encode_prefixed(&expected.data)
+                );
+                outcome.result.result = InstructionResult::Revert;
+                outcome.result.output = Error::encode(msg);
+                return outcome;
+            }
         }
 
         outcome
@@ -1856,7 +2025,7 @@ impl<DB: DatabaseExt> Inspector<DB> for Cheatcodes {
         ecx: &mut EvmContext<DB>,
         call: &mut CreateInputs,
     ) -> Option<CreateOutcome> {
-        self.create_common(ecx, call)
+        self.create_with_executor(ecx, call, &mut TransparentCheatcodesExecutor)
     }
 
     fn create_end(
@@ -1873,7 +2042,7 @@ impl<DB: DatabaseExt> Inspector<DB> for Cheatcodes {
         ecx: &mut EvmContext<DB>,
         call: &mut EOFCreateInputs,
     ) -> Option<CreateOutcome> {
-        self.create_common(ecx, call)
+        self.create_common(ecx, call, &mut TransparentCheatcodesExecutor)
     }
 
     fn eofcreate_end(
@@ -1910,6 +2079,78 @@ impl<DB: DatabaseExt> InspectorExt<DB> for Cheatcodes {
 }
 
 impl Cheatcodes {
+    /// Handles a log emitted during execution, for `expectEmit` and `recordLogs`.
+    fn handle_log(&mut self, log: &Log) {
+        if !self.expected_emits.is_empty() {
+            expect::handle_expect_emit(self, log);
+        }
+
+        // `recordLogs`
+        if let Some(storage_recorded_logs) = &mut self.recorded_logs {
+            storage_recorded_logs.push(Vm::Log {
+                topics: log.data.topics().to_vec(),
+                data: log.data.data.clone(),
+                emitter: log.address,
+            });
+        }
+    }
+
+    /// Handles the logs emitted during a zkEVM execution, in the order they were emitted.
+    ///
+    /// The zkEVM logs also contain the decoded `console.log` calls, which are emitted by the zero
+    /// address, and the events of the system contracts (e.g. the fee transfers), which would not
+    /// be emitted in the EVM, so these are skipped.
+    pub(crate) fn handle_zk_logs(&mut self, logs: &[Log]) {
+        for log in logs.iter().filter(|log| !foundry_zksync_core::is_system_address(log.address)) {
+            self.handle_log(log);
+        }
+    }
+
+    pub(crate) fn handle_zk_l2_to_l1(
+        &mut self,
+        logs: &[ZkL2ToL1Log],
+        messages: &[ZkL2ToL1Message],
+    ) {
+        self.recorded_l2_to_l1_logs.extend(logs.iter().map(|log| Vm::L2ToL1Log {
+            isService: log.is_service,
+            sender: log.sender,
+            key: log.key,
+            value: log.value,
+        }));
+        for message in messages {
+            expect::handle_expect_l2_to_l1_message(self, message);
+            self.recorded_l2_to_l1_messages
+                .push(Vm::L2ToL1Message { sender: message.sender, data: message.data.clone() });
+        }
+    }
+
+    /// Returns the smart account to send the next ZK-VM CALL or CREATE from, either set via
+    /// `zkUseAccount` or the configured custom account, once it has been deployed.
+    fn zk_account_data<DB: DatabaseExt>(
+        &mut self,
+        ecx: &mut InnerEvmContext<DB>,
+    ) -> Option<ZkAccountData> {
+        if let Some(account_data) = self.account_data.take() {
+            return Some(account_data)
+        }
+
+        let account_data = self.config.zk_custom_account.clone()?;
+        let account_code_addr = ACCOUNT_CODE_STORAGE_ADDRESS.to_address();
+        ecx.load_account(account_code_addr).ok()?;
+        let (code_hash, _) =
+            ecx.sload(account_code_addr, get_account_code_key(account_data.address)).ok()?;
+        (!code_hash.is_zero()).then_some(account_data)
+    }
+
+    /// Returns the `msg.sender` of the next ZK-VM CALL or CREATE if it was set by a prank, which is
+    /// kept when the transaction is sent from a smart account.
+    fn zk_pranked_sender(&self, depth: u64, caller: Address) -> Option<Address> {
+        self.prank
+            .as_ref()
+            .filter(|prank| depth == prank.depth && caller == prank.new_caller)
+            .map(|prank| prank.new_caller)
+    }
+
     #[cold]
     fn meter_gas(&mut self, interpreter: &mut Interpreter) {
         match &self.gas_metering {
@@ -2338,6 +2579,43 @@ fn disallowed_mem_write(
     };
 }
 
+/// Finds the zksolc contract deployed with the given init code, along with its constructor
+/// arguments.
+///
+/// Init code that doesn't belong to any compiled contract is deployed as raw EraVM bytecode,
+/// without constructor arguments and published as its own factory dependency, as long as it is a
+/// valid EraVM bytecode.
+fn find_zk_contract<'a>(
+    dual_compiled_contracts: &'a DualCompiledContracts,
+    init_code: &[u8],
+) -> Result<(Cow<'a, DualCompiledContract>, Vec<u8>), String> {
+    if let Some((contract, constructor_input)) =
+        dual_compiled_contracts.find_by_init_code(init_code)
+    {
+        return Ok((Cow::Borrowed(contract), constructor_input.to_vec()))
+    }
+
+    foundry_zksync_compiler::limits::check_bytecode("<unknown>", init_code).map_err(|err| {
+        format!(
+            "no zksolc artifact found for the deployed init code with hash {}, which is not a \
+             valid EraVM bytecode either: {err}",
+            keccak256(init_code)
+        )
+    })?;
+    debug!("deploying unknown init code as raw EraVM bytecode");
+
+    Ok((
+        Cow::Owned(DualCompiledContract {
+            name: "<unknown>".to_string(),
+            zk_bytecode_hash: foundry_zksync_core::hash_bytecode(init_code),
+            zk_deployed_bytecode: init_code.to_vec(),
+            zk_factory_deps: vec![init_code.to_vec()],
+            ..Default::default()
+        }),
+        vec![],
+    ))
+}
+
 // Determines if the gas limit on a given call was manually set in the script and should therefore
 // not be overwritten by later estimations
 fn check_if_fixed_gas_limit<DB: DatabaseExt>(
@@ -19,6 +19,7 @@ pub use config::CheatsConfig;
 pub use error::{Error, ErrorKind, Result};
 pub use inspector::{
     BroadcastableTransaction, BroadcastableTransactions, Cheatcodes, CheatcodesExecutor, Context,
+    ZkCallGasUsage,
 };
 pub use spec::{CheatcodeDef, Vm};
 pub use Vm::ForgeContext;
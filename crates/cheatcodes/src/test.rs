@@ -1,10 +1,22 @@
 //! Implementations of [`Testing`](spec::Group::Testing) cheatcodes.
 
-use crate::{Cheatcode, Cheatcodes, CheatsCtxt, DatabaseExt, Error, Result, Vm::*};
-use alloy_primitives::Address;
+use crate::{
+    evm::journaled_account, Cheatcode, Cheatcodes, CheatsCtxt, DatabaseExt, Error, Result, Vm::*,
+};
+use alloy_primitives::{Address, Bytes, U256};
 use alloy_sol_types::SolValue;
-use foundry_evm_core::constants::{MAGIC_ASSUME, MAGIC_SKIP};
+use foundry_evm_core::{
+    backend::DatabaseError,
+    constants::{MAGIC_ASSUME, MAGIC_SKIP},
+};
 use foundry_zksync_compiler::DualCompiledContract;
+use foundry_zksync_core::{
+    apply_l1_to_l2_alias,
+    convert::ConvertAddress,
+    vm::{CheatcodeTracerContext, ZkL1Tx},
+    PaymasterParams, ZkAccountData,
+};
+use revm::primitives::ExecutionResult;
 
 pub(crate) mod assert;
 pub(crate) mod expect;
@@ -59,6 +71,39 @@ impl Cheatcode for zkRegisterContractCall {
     }
 }
 
+impl Cheatcode for zkUsePaymasterCall {
+    fn apply_stateful<DB: DatabaseExt>(&self, ccx: &mut CheatsCtxt<DB>) -> Result {
+        let Self { paymaster_address, paymaster_input } = self;
+        ccx.state.paymaster_params = Some(PaymasterParams {
+            paymaster: paymaster_address.to_h160(),
+            paymaster_input: paymaster_input.to_vec(),
+        });
+        Ok(Default::default())
+    }
+}
+
+impl Cheatcode for zkUseAccountCall {
+    fn apply_stateful<DB: DatabaseExt>(&self, ccx: &mut CheatsCtxt<DB>) -> Result {
+        let Self { account, signature } = self;
+        ccx.state.account_data = Some(ZkAccountData::new(*account, signature.clone()));
+        Ok(Default::default())
+    }
+}
+
+impl Cheatcode for zkExecuteL1Tx_0Call {
+    fn apply_stateful<DB: DatabaseExt>(&self, ccx: &mut CheatsCtxt<DB>) -> Result {
+        let Self { from, to, value, data, gasLimit, factoryDeps } = self;
+        execute_l1_tx(ccx, *from, *to, *value, data, *gasLimit, factoryDeps, Address::ZERO)
+    }
+}
+
+impl Cheatcode for zkExecuteL1Tx_1Call {
+    fn apply_stateful<DB: DatabaseExt>(&self, ccx: &mut CheatsCtxt<DB>) -> Result {
+        let Self { from, to, value, data, gasLimit, factoryDeps, refundRecipient } = self;
+        execute_l1_tx(ccx, *from, *to, *value, data, *gasLimit, factoryDeps, *refundRecipient)
+    }
+}
+
 impl Cheatcode for assumeCall {
     fn apply(&self, _state: &mut Cheatcodes) -> Result {
         let Self { condition } = self;
@@ -144,3 +189,76 @@ fn breakpoint(state: &mut Cheatcodes, caller: &Address, s: &str, add: bool) -> R
 
     Ok(Default::default())
 }
+
+#[allow(clippy::too_many_arguments)]
+fn execute_l1_tx<DB: DatabaseExt>(
+    ccx: &mut CheatsCtxt<DB>,
+    from: Address,
+    to: Address,
+    value: U256,
+    data: &Bytes,
+    gas_limit: U256,
+    factory_deps: &[Bytes],
+    refund_recipient: Address,
+) -> Result {
+    ensure!(ccx.state.use_zk_vm, "L1 transactions can only be executed in the ZK-VM");
+    ensure!(gas_limit <= U256::from(u64::MAX), "gas limit must be less than 2^64 - 1");
+
+    // like the `Mailbox`, default to refunding the sender and alias contract recipients, since they
+    // can't control the same address on L2
+    let refund_recipient = if refund_recipient.is_zero() { from } else { refund_recipient };
+    let refund_recipient =
+        if journaled_account(ccx.ecx, refund_recipient)?.info.is_empty_code_hash() {
+            refund_recipient
+        } else {
+            apply_l1_to_l2_alias(refund_recipient)
+        };
+    let serial_id = ccx.state.zk_l1_tx_serial_id;
+    ccx.state.zk_l1_tx_serial_id += 1;
+
+    let l1_tx = ZkL1Tx {
+        from,
+        to,
+        value,
+        calldata: data.clone(),
+        gas_limit: gas_limit.to(),
+        factory_deps: factory_deps.iter().map(|dep| dep.to_vec()).collect(),
+        refund_recipient,
+        serial_id,
+    };
+    let state = &mut *ccx.state;
+    let tracer_ccx = CheatcodeTracerContext {
+        mocked_calls: state.mocked_calls.clone(),
+        expected_calls: Some(&mut state.expected_calls),
+        accesses: state.accesses.as_mut(),
+        persisted_factory_deps: Some(&mut state.persisted_factory_deps),
+        zk_env: state.zk_env,
+        system_env: state.config.zk_system_env.clone(),
+        ..Default::default()
+    };
+    let result = foundry_zksync_core::vm::execute_l1_tx::<_, DatabaseError>(
+        l1_tx.clone(),
+        ccx.ecx,
+        tracer_ccx,
+    )
+    .map_err(|err| fmt_err!("failed executing L1 transaction: {err:?}"))?;
+
+    let (success, return_data) = match result.result.execution_result {
+        ExecutionResult::Success { output, .. } => {
+            state.handle_zk_logs(&result.result.logs);
+            state
+                .handle_zk_l2_to_l1(&result.result.l2_to_l1_logs, &result.result.l2_to_l1_messages);
+            (true, output.into_data())
+        }
+        ExecutionResult::Revert { output, .. } => (false, output),
+        ExecutionResult::Halt { .. } => (false, Bytes::new()),
+    };
+
+    Ok(L1TxResult {
+        success,
+        returnData: return_data,
+        gasUsed: U256::from(l1_tx.gas_limit.saturating_sub(result.gas_refunded)),
+        refund: result.refund,
+    }
+    .abi_encode())
+}
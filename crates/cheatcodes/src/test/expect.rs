@@ -2,6 +2,7 @@ use crate::{Cheatcode, Cheatcodes, CheatsCtxt, DatabaseExt, Result, Vm::*};
 use alloy_primitives::{address, hex, Address, Bytes, LogData as RawLog, U256};
 use alloy_sol_types::{SolError, SolValue};
 use foundry_cheatcodes_common::expect::{ExpectedCallData, ExpectedCallType};
+use foundry_zksync_core::vm::ZkL2ToL1Message;
 use revm::interpreter::{return_ok, InstructionResult};
 use spec::Vm;
 use std::collections::hash_map::Entry;
@@ -40,6 +41,16 @@ pub struct ExpectedRevert {
     pub kind: ExpectedRevertKind,
 }
 
+#[derive(Clone, Debug)]
+pub struct ExpectedL2ToL1Message {
+    /// The address expected to send the message
+    pub sender: Address,
+    /// The message we expect
+    pub data: Bytes,
+    /// Whether the message was actually sent
+    pub found: bool,
+}
+
 #[derive(Clone, Debug)]
 pub struct ExpectedEmit {
     /// The depth at which we expect this emit to have occurred
@@ -163,6 +174,18 @@ impl Cheatcode for expectCallMinGas_1Call {
     }
 }
 
+impl Cheatcode for expectL2ToL1MessageCall {
+    fn apply(&self, state: &mut Cheatcodes) -> Result {
+        let Self { sender, data } = self;
+        state.expected_l2_to_l1_messages.push(ExpectedL2ToL1Message {
+            sender: *sender,
+            data: data.clone(),
+            found: false,
+        });
+        Ok(Default::default())
+    }
+}
+
 impl Cheatcode for expectEmit_0Call {
     fn apply_stateful<DB: DatabaseExt>(&self, ccx: &mut CheatsCtxt<DB>) -> Result {
         let Self { checkTopic1, checkTopic2, checkTopic3, checkData } = *self;
@@ -408,6 +431,15 @@ fn expect_emit(
     Ok(Default::default())
 }
 
+pub(crate) fn handle_expect_l2_to_l1_message(state: &mut Cheatcodes, message: &ZkL2ToL1Message) {
+    // Each sent message fulfills the oldest matching expectation
+    if let Some(expected) = state.expected_l2_to_l1_messages.iter_mut().find(|expected| {
+        !expected.found && expected.sender == message.sender && expected.data == message.data
+    }) {
+        expected.found = true;
+    }
+}
+
 pub(crate) fn handle_expect_emit(state: &mut Cheatcodes, log: &alloy_primitives::Log) {
     // Fill or check the expected emits.
     // We expect for emit checks to be filled as they're declared (from oldest to newest),
@@ -13,6 +13,7 @@ use alloy_signer_local::{
 use alloy_sol_types::SolValue;
 use foundry_common::ens::namehash;
 use foundry_evm_core::constants::DEFAULT_CREATE2_DEPLOYER;
+use foundry_zksync_core::convert::ConvertH256;
 use k256::{
     ecdsa::SigningKey,
     elliptic_curve::{sec1::ToEncodedPoint, Curve},
@@ -118,25 +119,69 @@ impl Cheatcode for getLabelCall {
 }
 
 impl Cheatcode for computeCreateAddressCall {
-    fn apply(&self, _state: &mut Cheatcodes) -> Result {
+    fn apply(&self, state: &mut Cheatcodes) -> Result {
         let Self { nonce, deployer } = self;
         ensure!(*nonce <= U256::from(u64::MAX), "nonce must be less than 2^64 - 1");
+        if state.use_zk_vm {
+            // the nonce is the deployment nonce of the deployer in the zkVM
+            return Ok(
+                foundry_zksync_core::compute_create_address(*deployer, nonce.to()).abi_encode()
+            )
+        }
         Ok(deployer.create(nonce.to()).abi_encode())
     }
 }
 
 impl Cheatcode for computeCreate2Address_0Call {
-    fn apply(&self, _state: &mut Cheatcodes) -> Result {
+    fn apply(&self, state: &mut Cheatcodes) -> Result {
         let Self { salt, initCodeHash, deployer } = self;
-        Ok(deployer.create2(salt, initCodeHash).abi_encode())
+        Ok(compute_create2_address(state, *deployer, *salt, *initCodeHash)?.abi_encode())
     }
 }
 
 impl Cheatcode for computeCreate2Address_1Call {
-    fn apply(&self, _state: &mut Cheatcodes) -> Result {
+    fn apply(&self, state: &mut Cheatcodes) -> Result {
         let Self { salt, initCodeHash } = self;
-        Ok(DEFAULT_CREATE2_DEPLOYER.create2(salt, initCodeHash).abi_encode())
+        // the default deployer of the zkVM is the `Create2Factory`, like in `cast create2 --zksync`
+        let deployer = if state.use_zk_vm {
+            foundry_zksync_core::CREATE2_FACTORY_ADDRESS
+        } else {
+            DEFAULT_CREATE2_DEPLOYER
+        };
+        Ok(compute_create2_address(state, deployer, *salt, *initCodeHash)?.abi_encode())
+    }
+}
+
+/// Computes the `CREATE2` address of a contract, using the zkSync derivation in the zkVM.
+///
+/// In the zkVM the address depends on the bytecode hash of the contract and the hash of its
+/// constructor input rather than on the hash of its init code, so the init code hash must be the
+/// one of a compiled contract without constructor arguments, as they can't be recovered from it.
+fn compute_create2_address(
+    state: &Cheatcodes,
+    deployer: Address,
+    salt: B256,
+    init_code_hash: B256,
+) -> Result<Address> {
+    if !state.use_zk_vm {
+        return Ok(deployer.create2(salt, init_code_hash))
     }
+
+    let contract = state
+        .dual_compiled_contracts
+        .find_by_evm_init_code_hash(init_code_hash)
+        .ok_or_else(|| {
+            fmt_err!(
+                "no zksolc contract found for the init code hash {init_code_hash}; \
+                 contracts with constructor arguments are not supported in the zkVM"
+            )
+        })?;
+    Ok(foundry_zksync_core::compute_create2_address(
+        deployer,
+        salt,
+        contract.zk_bytecode_hash.to_b256(),
+        keccak256([]),
+    ))
 }
 
 impl Cheatcode for ensNamehashCall {
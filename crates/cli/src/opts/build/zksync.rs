@@ -1,7 +1,9 @@
 use std::path::PathBuf;
 
+use alloy_primitives::{Address, Bytes};
 use clap::Parser;
 use foundry_config::ZkSyncConfig;
+use foundry_zksync_core::{convert::ConvertAddress, PaymasterParams};
 use serde::Serialize;
 
 #[derive(Clone, Debug, Default, Serialize, Parser)]
@@ -104,6 +106,76 @@ pub struct ZkSyncArgs {
     /// Contracts to avoid compiling on zkSync
     #[clap(long = "zk-avoid-contracts", visible_alias = "avoid-contracts", value_delimiter = ',')]
     pub avoid_contracts: Option<Vec<String>>,
+
+    /// Split the gas used in the zkVM into computation, pubdata and storage writes in gas reports
+    /// and snapshots.
+    #[clap(
+        long = "zk-gas-breakdown",
+        value_name = "GAS_BREAKDOWN",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_breakdown: Option<bool>,
+
+    /// The protocol version of the zkVM, defaults to the one of the forked zkSync chain or to the
+    /// latest one.
+    #[clap(long = "zk-protocol-version", value_name = "VERSION")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol_version: Option<u16>,
+
+    /// Use the system contracts with the security checks of the default account, which require
+    /// transactions to be signed.
+    #[clap(
+        long = "zk-system-contracts-security",
+        value_name = "SYSTEM_CONTRACTS_SECURITY",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_contracts_security: Option<bool>,
+
+    /// Load the compiled system contracts and bootloader from a `zksync-era` checkout instead of
+    /// using the built-in ones.
+    #[clap(long = "zk-system-contracts-path", value_name = "PATH")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_contracts_path: Option<PathBuf>,
+
+    /// Smart account to send the zkVM calls and deployments of tests from, using account
+    /// abstraction, once it is deployed.
+    #[clap(long = "zk-custom-account", value_name = "ADDRESS")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_account: Option<Address>,
+
+    /// Signature passed to the `validateTransaction` of the custom account.
+    #[clap(
+        long = "zk-custom-account-signature",
+        value_name = "SIGNATURE",
+        requires = "custom_account"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_account_signature: Option<Bytes>,
+
+    /// Paymaster address
+    #[clap(
+        long = "zk-paymaster-address",
+        value_name = "PAYMASTER_ADDRESS",
+        visible_alias = "paymaster-address"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paymaster_address: Option<Address>,
+
+    /// Paymaster input
+    #[clap(
+        long = "zk-paymaster-input",
+        value_name = "PAYMASTER_INPUT",
+        visible_alias = "paymaster-input",
+        requires = "paymaster_address"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paymaster_input: Option<Bytes>,
 }
 
 impl ZkSyncArgs {
@@ -112,6 +184,15 @@ impl ZkSyncArgs {
         self.compile.unwrap_or_default()
     }
 
+    /// Returns the paymaster params provided via `--zk-paymaster-address` and
+    /// `--zk-paymaster-input`, if any.
+    pub fn paymaster_params(&self) -> Option<PaymasterParams> {
+        self.paymaster_address.map(|paymaster| PaymasterParams {
+            paymaster: paymaster.to_h160(),
+            paymaster_input: self.paymaster_input.clone().unwrap_or_default().to_vec(),
+        })
+    }
+
     /// Merge the current cli arguments into the specified zksync configuration
     pub(crate) fn apply_overrides(&self, mut zksync: ZkSyncConfig) -> ZkSyncConfig {
         macro_rules! set_if_some {
@@ -134,6 +215,12 @@ impl ZkSyncArgs {
             zksync.detect_missing_libraries
         );
         set_if_some!(self.avoid_contracts.clone(), zksync.avoid_contracts);
+        set_if_some!(self.gas_breakdown, zksync.gas_breakdown);
+        set_if_some!(self.protocol_version, zksync.protocol_version);
+        set_if_some!(self.system_contracts_security, zksync.system_contracts_security);
+        set_if_some!(self.system_contracts_path.clone(), zksync.system_contracts_path);
+        set_if_some!(self.custom_account, zksync.custom_account);
+        set_if_some!(self.custom_account_signature.clone(), zksync.custom_account_signature);
 
         set_if_some!(self.optimizer.then_some(true), zksync.optimizer);
         set_if_some!(
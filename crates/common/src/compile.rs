@@ -21,11 +21,11 @@ use foundry_compilers::{
     Artifact, Project, ProjectBuilder, ProjectCompileOutput, ProjectPathsConfig, SolcConfig,
 };
 use foundry_linking::Linker;
-use foundry_zksync_compiler::libraries::{self, ZkMissingLibrary};
+use foundry_zksync_compiler::libraries;
 use num_format::{Locale, ToFormattedString};
 use rustc_hash::FxHashMap;
 use std::{
-    collections::{BTreeMap, HashMap, HashSet},
+    collections::{BTreeMap, HashMap},
     fmt::Display,
     io::IsTerminal,
     path::{Path, PathBuf},
@@ -59,6 +59,9 @@ pub struct ProjectCompiler {
 
     /// Set zksync specific settings based on context
     zksync: bool,
+
+    /// Whether to allow missing libraries in the zksolc output, instead of failing compilation.
+    zksync_allow_missing_libraries: bool,
 }
 
 impl Default for ProjectCompiler {
@@ -80,6 +83,7 @@ impl ProjectCompiler {
             bail: None,
             files: Vec::new(),
             zksync: false,
+            zksync_allow_missing_libraries: false,
         }
     }
 
@@ -142,6 +146,14 @@ impl ProjectCompiler {
         self
     }
 
+    /// Sets whether to allow missing libraries in the zksolc output, so that they can be deployed
+    /// and linked by the caller instead of failing compilation.
+    #[inline]
+    pub fn zksync_allow_missing_libraries(mut self, yes: bool) -> Self {
+        self.zksync_allow_missing_libraries = yes;
+        self
+    }
+
     /// Compiles the project.
     pub fn compile<C: Compiler>(mut self, project: &Project<C>) -> Result<ProjectCompileOutput<C>> {
         // TODO: Avoid process::exit
@@ -384,50 +396,10 @@ impl ProjectCompiler {
 
         // Process missing libraries
         // TODO: skip this if project was not compiled using --detect-missing-libraries
-        let mut missing_libs_unique: HashSet<String> = HashSet::new();
-        for (artifact_id, artifact) in output.artifact_ids() {
-            // TODO: when compiling specific files, the output might still add cached artifacts
-            // that are not part of the file list to the output, which may cause missing libraries
-            // error to trigger for files that were not intended to be compiled.
-            // This behaviour needs to be investigated better on the foundry-compilers side.
-            // For now we filter, checking only the files passed to compile.
-            let is_target_file =
-                self.files.is_empty() || self.files.iter().any(|f| artifact_id.path == *f);
-            if is_target_file {
-                if let Some(mls) = &artifact.missing_libraries {
-                    missing_libs_unique.extend(mls.clone());
-                }
-            }
-        }
-
-        let missing_libs: Vec<ZkMissingLibrary> = missing_libs_unique
-            .into_iter()
-            .map(|ml| {
-                let mut split = ml.split(':');
-                let contract_path =
-                    split.next().expect("Failed to extract contract path for missing library");
-                let contract_name =
-                    split.next().expect("Failed to extract contract name for missing library");
-
-                let mut abs_path_buf = PathBuf::new();
-                abs_path_buf.push(root_path.as_ref());
-                abs_path_buf.push(contract_path);
-
-                let art = output.find(abs_path_buf.as_path(), contract_name).unwrap_or_else(|| {
-                    panic!(
-                        "Could not find contract {contract_name} at path {contract_path} for compilation output"
-                    )
-                });
+        let missing_libs =
+            libraries::get_missing_libraries(root_path.as_ref(), output, &self.files);
 
-                ZkMissingLibrary {
-                    contract_path: contract_path.to_string(),
-                    contract_name: contract_name.to_string(),
-                    missing_libraries: art.missing_libraries.clone().unwrap_or_default(),
-                }
-            })
-            .collect();
-
-        if !missing_libs.is_empty() {
+        if !missing_libs.is_empty() && !self.zksync_allow_missing_libraries {
             libraries::add_dependencies_to_missing_libraries_cache(
                 root_path,
                 missing_libs.as_slice(),
@@ -475,6 +447,7 @@ impl ProjectCompiler {
                 .map(|(id, artifact)| (id.name, artifact))
                 .collect();
 
+            let mut limit_errors = vec![];
             for (name, artifact) in artifacts {
                 let bytecode = artifact.get_bytecode_object().unwrap_or_default();
                 let size = match bytecode.as_ref() {
@@ -495,15 +468,26 @@ impl ProjectCompiler {
                         })
                     })
                     .unwrap_or(false);
+                // interfaces and abstract contracts have no bytecode to deploy
+                if let (BytecodeObject::Bytecode(bytes), false) =
+                    (bytecode.as_ref(), is_dev_contract)
+                {
+                    if !bytes.is_empty() {
+                        if let Err(err) =
+                            foundry_zksync_compiler::limits::check_bytecode(&name, bytes)
+                        {
+                            limit_errors.push(err.to_string());
+                        }
+                    }
+                }
                 size_report.contracts.insert(name, ContractInfo { size, is_dev_contract });
             }
 
             println!("{size_report}");
 
-            // TODO: avoid process::exit
-            // exit with error if any contract exceeds the size limit, excluding test contracts.
-            if size_report.exceeds_size_limit() {
-                std::process::exit(1);
+            // error if any contract can't be deployed on EraVM, excluding test contracts.
+            if !limit_errors.is_empty() {
+                eyre::bail!("{}", limit_errors.join("\n"));
             }
         }
         Ok(())
@@ -640,7 +624,8 @@ impl ContractSources {
 const CONTRACT_SIZE_LIMIT: usize = 24576;
 
 // https://docs.zksync.io/build/developer-reference/ethereum-differences/contract-deployment#contract-size-limit-and-format-of-bytecode-hash
-const ZKSYNC_CONTRACT_SIZE_LIMIT: usize = 450999;
+const ZKSYNC_CONTRACT_SIZE_LIMIT: usize =
+    foundry_zksync_compiler::limits::MAX_PUBLISHED_BYTECODES_SIZE;
 
 /// Contracts with info about their size
 pub struct SizeReport {
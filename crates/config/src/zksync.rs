@@ -10,6 +10,7 @@ use foundry_compilers::{
     },
 };
 
+use alloy_primitives::{Address, Bytes};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -59,6 +60,29 @@ pub struct ZkSyncConfig {
 
     /// zkSolc optimizer details
     pub optimizer_details: Option<OptimizerDetails>,
+
+    /// Split the gas used in the zkVM into computation, pubdata and storage writes in gas
+    /// reports and snapshots
+    pub gas_breakdown: bool,
+
+    /// The protocol version of the zkVM, defaults to the one of the forked zkSync chain or to the
+    /// latest one
+    pub protocol_version: Option<u16>,
+
+    /// Whether to use the system contracts with the security checks of the default account, which
+    /// require transactions to be signed
+    pub system_contracts_security: bool,
+
+    /// Directory to load the compiled system contracts and bootloader from, laid out as a
+    /// `zksync-era` checkout, instead of the built-in ones
+    pub system_contracts_path: Option<PathBuf>,
+
+    /// Smart account to send the zkVM calls and deployments of tests from, using account
+    /// abstraction, once it is deployed. Can be overridden for a single call with `zkUseAccount`
+    pub custom_account: Option<Address>,
+
+    /// Signature passed to the `validateTransaction` of the custom account
+    pub custom_account_signature: Option<Bytes>,
 }
 
 impl Default for ZkSyncConfig {
@@ -78,6 +102,12 @@ impl Default for ZkSyncConfig {
             optimizer: true,
             optimizer_mode: '3',
             optimizer_details: Default::default(),
+            gas_breakdown: Default::default(),
+            protocol_version: Default::default(),
+            system_contracts_security: Default::default(),
+            system_contracts_path: Default::default(),
+            custom_account: Default::default(),
+            custom_account_signature: Default::default(),
         }
     }
 }
@@ -1,7 +1,9 @@
 use alloy_primitives::{Address, Bytes};
 use foundry_evm_traces::{CallKind, CallTraceArena};
+use foundry_zksync_core::vm::EraVmStep;
 use revm_inspectors::tracing::types::{CallTraceStep, TraceMemberOrder};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Represents a part of the execution frame before the next call or end of the execution.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -16,6 +18,8 @@ pub struct DebugNode {
     pub calldata: Bytes,
     /// The debug steps.
     pub steps: Vec<CallTraceStep>,
+    /// The EraVM debug steps, if this part of the execution ran in the zkVM.
+    pub zk_steps: Vec<EraVmStep>,
 }
 
 impl DebugNode {
@@ -26,7 +30,31 @@ impl DebugNode {
         steps: Vec<CallTraceStep>,
         calldata: Bytes,
     ) -> Self {
-        Self { address, kind, steps, calldata }
+        Self { address, kind, steps, calldata, zk_steps: Vec::new() }
+    }
+
+    /// Creates a new debug node for a part of the execution that ran in the zkVM.
+    pub fn new_zk(
+        address: Address,
+        kind: CallKind,
+        zk_steps: Vec<EraVmStep>,
+        calldata: Bytes,
+    ) -> Self {
+        Self { address, kind, steps: Vec::new(), calldata, zk_steps }
+    }
+
+    /// Returns true if this part of the execution ran in the zkVM.
+    pub fn is_zk(&self) -> bool {
+        !self.zk_steps.is_empty()
+    }
+
+    /// Returns the number of debug steps.
+    pub fn num_steps(&self) -> usize {
+        if self.is_zk() {
+            self.zk_steps.len()
+        } else {
+            self.steps.len()
+        }
     }
 }
 
@@ -34,7 +62,15 @@ impl DebugNode {
 ///
 /// This is done by recursively traversing the call tree and collecting the steps in-between the
 /// calls.
-pub fn flatten_call_trace(arena: CallTraceArena, out: &mut Vec<DebugNode>) {
+///
+/// The calls executed in the zkVM are not stepped through by revm, their EraVM steps are instead
+/// given in `zk_steps`, keyed by the index of the node of the call. They are split into one node
+/// per contract, as the zkVM calls are only recorded as trace nodes without steps.
+pub fn flatten_call_trace(
+    arena: CallTraceArena,
+    mut zk_steps: HashMap<usize, Vec<EraVmStep>>,
+    out: &mut Vec<DebugNode>,
+) {
     #[derive(Debug, Clone, Copy)]
     struct PendingNode {
         node_idx: usize,
@@ -65,6 +101,20 @@ pub fn flatten_call_trace(arena: CallTraceArena, out: &mut Vec<DebugNode>) {
     let mut arena_nodes = arena.into_nodes();
 
     for pending in nodes {
+        if let Some(zk_steps) = zk_steps.remove(&pending.node_idx) {
+            let call = &arena_nodes[pending.node_idx].trace;
+            for steps in split_zk_steps(zk_steps) {
+                let node = if steps[0].address == call.address {
+                    let calldata =
+                        if call.kind.is_any_create() { Bytes::new() } else { call.data.clone() };
+                    DebugNode::new_zk(call.address, call.kind, steps, calldata)
+                } else {
+                    DebugNode::new_zk(steps[0].address, CallKind::Call, steps, Bytes::new())
+                };
+                out.push(node);
+            }
+        }
+
         let steps = {
             let other_steps =
                 arena_nodes[pending.node_idx].trace.steps.split_off(pending.steps_count);
@@ -83,3 +133,15 @@ pub fn flatten_call_trace(arena: CallTraceArena, out: &mut Vec<DebugNode>) {
         out.push(node);
     }
 }
+
+/// Splits the EraVM steps of a zkVM execution into the consecutive steps of each contract.
+fn split_zk_steps(zk_steps: Vec<EraVmStep>) -> Vec<Vec<EraVmStep>> {
+    let mut frames: Vec<Vec<EraVmStep>> = Vec::new();
+    for step in zk_steps {
+        match frames.last_mut() {
+            Some(frame) if frame[0].address == step.address => frame.push(step),
+            _ => frames.push(vec![step]),
+        }
+    }
+    frames
+}
@@ -4,6 +4,7 @@ use crate::{node::flatten_call_trace, DebugNode, Debugger};
 use alloy_primitives::Address;
 use foundry_common::{evm::Breakpoints, get_contract_name};
 use foundry_evm_traces::{debug::ContractSources, CallTraceArena, CallTraceDecoder, Traces};
+use foundry_zksync_core::vm::EraVmStep;
 use std::collections::HashMap;
 
 /// Debugger builder.
@@ -39,7 +40,19 @@ impl DebuggerBuilder {
     /// Extends the debug arena.
     #[inline]
     pub fn trace_arena(mut self, arena: CallTraceArena) -> Self {
-        flatten_call_trace(arena, &mut self.debug_arena);
+        flatten_call_trace(arena, HashMap::new(), &mut self.debug_arena);
+        self
+    }
+
+    /// Extends the debug arena with the trace of an execution that made calls in the zkVM, along
+    /// with their EraVM steps keyed by the index of their trace node.
+    #[inline]
+    pub fn zk_trace_arena(
+        mut self,
+        arena: CallTraceArena,
+        zk_steps: HashMap<usize, Vec<EraVmStep>>,
+    ) -> Self {
+        flatten_call_trace(arena, zk_steps, &mut self.debug_arena);
         self
     }
 
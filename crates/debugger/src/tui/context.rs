@@ -3,6 +3,7 @@
 use crate::{DebugNode, Debugger, ExitReason};
 use alloy_primitives::{hex, Address};
 use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
+use foundry_zksync_core::vm::EraVmStep;
 use revm::interpreter::OpCode;
 use revm_inspectors::tracing::types::{CallKind, CallTraceStep};
 use std::ops::ControlFlow;
@@ -112,12 +113,25 @@ impl<'a> DebuggerContext<'a> {
         &self.debug_steps()[self.current_step]
     }
 
+    /// Returns the current EraVM debug step, if the current call ran in the zkVM.
+    pub(crate) fn current_zk_step(&self) -> Option<&EraVmStep> {
+        self.debug_call().zk_steps.get(self.current_step)
+    }
+
+    /// Returns the heap of the current EraVM debug step, or `None` if it was not recorded.
+    pub(crate) fn current_zk_heap(&self) -> Option<&[u8]> {
+        zk_heap(&self.debug_call().zk_steps[..=self.current_step])
+    }
+
     fn gen_opcode_list(&mut self) {
         self.opcode_list.clear();
-        let debug_steps = &self.debugger.debug_arena[self.draw_memory.inner_call_index].steps;
-        for step in debug_steps {
+        let debug_call = &self.debugger.debug_arena[self.draw_memory.inner_call_index];
+        for step in &debug_call.steps {
             self.opcode_list.push(pretty_opcode(step));
         }
+        for step in &debug_call.zk_steps {
+            self.opcode_list.push(step.opcode.clone());
+        }
     }
 
     fn gen_opcode_list_if_necessary(&mut self) {
@@ -127,7 +141,15 @@ impl<'a> DebuggerContext<'a> {
         }
     }
 
-    fn active_buffer(&self) -> &[u8] {
+    pub(crate) fn active_buffer(&self) -> &[u8] {
+        if self.current_zk_step().is_some() {
+            return match self.active_buffer {
+                BufferKind::Memory => self.current_zk_heap().unwrap_or_default(),
+                BufferKind::Calldata => &self.debug_call().calldata,
+                BufferKind::Returndata => &[],
+            }
+        }
+
         match self.active_buffer {
             BufferKind::Memory => self.current_step().memory.as_ref().unwrap().as_bytes(),
             BufferKind::Calldata => &self.debug_call().calldata,
@@ -188,8 +210,12 @@ impl DebuggerContext<'_> {
             }),
             // Scroll down the stack
             KeyCode::Char('J') => self.repeat(|this| {
-                let max_stack =
-                    this.current_step().stack.as_ref().map_or(0, |s| s.len()).saturating_sub(1);
+                let max_stack = if let Some(step) = this.current_zk_step() {
+                    step.registers.len() + step.stack.len()
+                } else {
+                    this.current_step().stack.as_ref().map_or(0, |s| s.len())
+                }
+                .saturating_sub(1);
                 if this.draw_memory.current_stack_startline < max_stack {
                     this.draw_memory.current_stack_startline += 1;
                 }
@@ -230,30 +256,18 @@ impl DebuggerContext<'_> {
 
             // Step forward
             KeyCode::Char('s') => self.repeat(|this| {
-                let remaining_steps = &this.debug_steps()[this.current_step..];
-                if let Some((i, _)) =
-                    remaining_steps.iter().enumerate().skip(1).find(|(i, step)| {
-                        let prev = &remaining_steps[*i - 1];
-                        is_jump(step, prev)
-                    })
+                if let Some(i) =
+                    (this.current_step + 1..this.n_steps()).find(|&i| this.is_jump_target(i))
                 {
-                    this.current_step += i
+                    this.current_step = i
                 }
             }),
 
             // Step backwards
             KeyCode::Char('a') => self.repeat(|this| {
-                let ops = &this.debug_steps()[..this.current_step];
-                this.current_step = ops
-                    .iter()
-                    .enumerate()
-                    .skip(1)
+                this.current_step = (1..this.current_step)
                     .rev()
-                    .find(|&(i, op)| {
-                        let prev = &ops[i - 1];
-                        is_jump(op, prev)
-                    })
-                    .map(|(i, _)| i)
+                    .find(|&i| this.is_jump_target(i))
                     .unwrap_or_default();
             }),
 
@@ -336,7 +350,17 @@ impl DebuggerContext<'_> {
     }
 
     fn n_steps(&self) -> usize {
-        self.debug_steps().len()
+        self.debug_call().num_steps()
+    }
+
+    /// Returns true if the step at index `i` of the current call was reached through a jump.
+    fn is_jump_target(&self, i: usize) -> bool {
+        let debug_call = self.debug_call();
+        if debug_call.is_zk() {
+            is_zk_jump(&debug_call.zk_steps[i], &debug_call.zk_steps[i - 1])
+        } else {
+            is_jump(&debug_call.steps[i], &debug_call.steps[i - 1])
+        }
     }
 }
 
@@ -378,3 +402,34 @@ fn is_jump(step: &CallTraceStep, prev: &CallTraceStep) -> bool {
         step.code_section_idx != prev.code_section_idx
     }
 }
+
+/// Returns the heap of the last of the given EraVM debug steps, from the latest step that recorded
+/// it, or `None` if it was not recorded because of the heap recording limit.
+fn zk_heap(steps: &[EraVmStep]) -> Option<&[u8]> {
+    if steps.last()?.heap_truncated {
+        return None
+    }
+    steps.iter().rev().find_map(|step| step.heap.as_deref())
+}
+
+fn is_zk_jump(step: &EraVmStep, prev: &EraVmStep) -> bool {
+    prev.is_jump && step.pc != prev.pc + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zk_heap() {
+        let recorded = EraVmStep { heap: Some(vec![1u8].into()), ..Default::default() };
+        let unchanged = EraVmStep::default();
+        let truncated = EraVmStep { heap_truncated: true, ..Default::default() };
+
+        assert_eq!(super::zk_heap(&[]), None);
+        assert_eq!(super::zk_heap(&[unchanged.clone()]), None);
+        assert_eq!(super::zk_heap(&[recorded.clone(), unchanged.clone()]), Some(&[1u8][..]));
+        // the latest recorded heap is stale once the heap is no longer recorded
+        assert_eq!(super::zk_heap(&[recorded, unchanged, truncated]), None);
+    }
+}
@@ -3,8 +3,8 @@
 use super::context::{BufferKind, DebuggerContext};
 use crate::op::OpcodeParam;
 use alloy_primitives::U256;
-use foundry_compilers::artifacts::sourcemap::SourceElement;
 use foundry_evm_traces::debug::SourceData;
+use foundry_zksync_core::vm::EraVmStep;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -14,7 +14,7 @@ use ratatui::{
 };
 use revm::interpreter::opcode;
 use revm_inspectors::tracing::types::CallKind;
-use std::{collections::VecDeque, fmt::Write, io};
+use std::{collections::VecDeque, fmt::Write, io, ops::Range};
 
 impl DebuggerContext<'_> {
     /// Draws the TUI layout and subcomponents to the given terminal.
@@ -213,22 +213,16 @@ impl DebuggerContext<'_> {
     }
 
     fn src_text(&self, area: Rect) -> (Text<'_>, Option<&str>) {
-        let (source_element, source) = match self.src_map() {
+        let (range, source) = match self.src_map() {
             Ok(r) => r,
             Err(e) => return (Text::from(e), None),
         };
 
-        // We are handed a vector of SourceElements that give us a span of sourcecode that is
-        // currently being executed. This includes an offset and length.
-        // This vector is in instruction pointer order, meaning the location of the instruction
-        // minus `sum(push_bytes[..pc])`.
-        let offset = source_element.offset() as usize;
-        let len = source_element.length() as usize;
         let max = source.source.len();
 
         // Split source into before, relevant, and after chunks, split by line, for formatting.
-        let actual_start = offset.min(max);
-        let actual_end = (offset + len).min(max);
+        let actual_start = range.start.min(max);
+        let actual_end = range.end.min(max);
 
         let mut before: Vec<_> = source.source[..actual_start].split_inclusive('\n').collect();
         let actual: Vec<_> =
@@ -341,13 +335,21 @@ impl DebuggerContext<'_> {
         (Text::from(lines.lines), source.path.to_str())
     }
 
-    /// Returns source map, source code and source name of the current line.
-    fn src_map(&self) -> Result<(SourceElement, &SourceData), String> {
+    /// Returns the source range and source data of the current step.
+    fn src_map(&self) -> Result<(Range<usize>, &SourceData), String> {
+        if let Some(step) = self.current_zk_step() {
+            return self.zk_src_map(step)
+        }
+
         let address = self.address();
         let Some(contract_name) = self.debugger.identified_contracts.get(address) else {
             return Err(format!("Unknown contract at address {address}"));
         };
 
+        // We are handed a vector of SourceElements that give us a span of sourcecode that is
+        // currently being executed. This includes an offset and length.
+        // This vector is in instruction pointer order, meaning the location of the instruction
+        // minus `sum(push_bytes[..pc])`.
         self.debugger
             .contracts_sources
             .find_source_mapping(
@@ -355,20 +357,50 @@ impl DebuggerContext<'_> {
                 self.current_step().pc,
                 self.debug_call().kind.is_any_create(),
             )
+            .map(|(source_element, source)| {
+                let offset = source_element.offset() as usize;
+                let len = source_element.length() as usize;
+                (offset..offset + len, source)
+            })
             .ok_or_else(|| format!("No source map for contract {contract_name}"))
     }
 
+    /// Returns the source range and source data of the given EraVM step.
+    ///
+    /// zksolc only maps instructions to source lines, so the whole line is highlighted.
+    fn zk_src_map(&self, step: &EraVmStep) -> Result<(Range<usize>, &SourceData), String> {
+        let sources = &self.debugger.contracts_sources;
+        let Some(contract_name) = sources.find_zk_contract_name(step.code_hash) else {
+            return Err(format!(
+                "Unknown zkVM contract at address {} with bytecode hash {}",
+                step.address, step.code_hash
+            ));
+        };
+
+        sources.find_zk_source_mapping(step.code_hash, step.pc as usize).ok_or_else(|| {
+            format!(
+                "No source map for zkVM contract {contract_name}, \
+                 make sure the EraVM assembly is part of the zksolc output"
+            )
+        })
+    }
+
     fn draw_op_list(&self, f: &mut Frame<'_>, area: Rect) {
-        let debug_steps = self.debug_steps();
-        let max_pc = debug_steps.iter().map(|step| step.pc).max().unwrap_or(0);
+        let debug_call = self.debug_call();
+        let pcs: Vec<usize> = if debug_call.is_zk() {
+            debug_call.zk_steps.iter().map(|step| step.pc as usize).collect()
+        } else {
+            debug_call.steps.iter().map(|step| step.pc).collect()
+        };
+        let max_pc = pcs.iter().copied().max().unwrap_or(0);
         let max_pc_len = hex_digits(max_pc);
 
-        let items = debug_steps
+        let items = pcs
             .iter()
             .enumerate()
-            .map(|(i, step)| {
+            .map(|(i, pc)| {
                 let mut content = String::with_capacity(64);
-                write!(content, "{:0>max_pc_len$x}|", step.pc).unwrap();
+                write!(content, "{pc:0>max_pc_len$x}|").unwrap();
                 if let Some(op) = self.opcode_list.get(i) {
                     content.push_str(op);
                 }
@@ -376,13 +408,20 @@ impl DebuggerContext<'_> {
             })
             .collect::<Vec<_>>();
 
-        let title = format!(
-            "Address: {} | PC: {} | Gas used in call: {} | Code section: {}",
-            self.address(),
-            self.current_step().pc,
-            self.current_step().gas_used,
-            self.current_step().code_section_idx,
-        );
+        let title = if let Some(step) = self.current_zk_step() {
+            format!(
+                "Address: {} | PC: {} | Ergs remaining: {} | Code page: {}",
+                step.address, step.pc, step.ergs_remaining, step.code_page,
+            )
+        } else {
+            format!(
+                "Address: {} | PC: {} | Gas used in call: {} | Code section: {}",
+                self.address(),
+                self.current_step().pc,
+                self.current_step().gas_used,
+                self.current_step().code_section_idx,
+            )
+        };
         let block = Block::default().title(title).borders(Borders::ALL);
         let list = List::new(items)
             .block(block)
@@ -394,6 +433,11 @@ impl DebuggerContext<'_> {
     }
 
     fn draw_stack(&self, f: &mut Frame<'_>, area: Rect) {
+        if let Some(step) = self.current_zk_step() {
+            self.draw_zk_registers_and_stack(f, area, step);
+            return
+        }
+
         let step = self.current_step();
         let stack = step.stack.as_ref();
         let stack_len = stack.map_or(0, |s| s.len());
@@ -452,14 +496,45 @@ impl DebuggerContext<'_> {
         f.render_widget(paragraph, area);
     }
 
+    /// Draws the registers of an EraVM step, followed by its stack from the top.
+    fn draw_zk_registers_and_stack(&self, f: &mut Frame<'_>, area: Rect, step: &EraVmStep) {
+        let min_len = decimal_digits(step.stack.len()).max(3);
+
+        let registers =
+            step.registers.iter().enumerate().map(|(i, value)| (format!("r{}", i + 1), value));
+        let stack = step.stack.iter().rev().enumerate().map(|(i, value)| (i.to_string(), value));
+
+        let text: Vec<Line<'_>> = registers
+            .chain(stack)
+            .skip(self.draw_memory.current_stack_startline)
+            .map(|(label, value)| {
+                let mut spans = Vec::with_capacity(1 + 32 * 2 + 1);
+
+                // Register name or stack index.
+                spans.push(Span::styled(
+                    format!("{label:>min_len$}| "),
+                    Style::new().fg(Color::White),
+                ));
+
+                // Item hex bytes.
+                hex_bytes_spans(&value.to_be_bytes::<32>(), &mut spans, |_, _| {
+                    Style::new().fg(Color::White)
+                });
+
+                spans.push(Span::raw("\n"));
+
+                Line::from(spans)
+            })
+            .collect();
+
+        let title = format!("Registers: {} | Stack: {}", step.registers.len(), step.stack.len());
+        let block = Block::default().title(title).borders(Borders::ALL);
+        let paragraph = Paragraph::new(text).block(block).wrap(Wrap { trim: true });
+        f.render_widget(paragraph, area);
+    }
+
     fn draw_buffer(&self, f: &mut Frame<'_>, area: Rect) {
-        let call = self.debug_call();
-        let step = self.current_step();
-        let buf = match self.active_buffer {
-            BufferKind::Memory => step.memory.as_ref().unwrap().as_ref(),
-            BufferKind::Calldata => call.calldata.as_ref(),
-            BufferKind::Returndata => step.returndata.as_ref(),
-        };
+        let buf = self.active_buffer();
 
         let min_len = hex_digits(buf.len());
 
@@ -469,8 +544,10 @@ impl DebuggerContext<'_> {
         let mut write_offset = None;
         let mut write_size = None;
         let mut color = None;
-        let stack_len = step.stack.as_ref().map_or(0, |s| s.len());
-        if stack_len > 0 {
+        // The EraVM instructions are not decoded, so their accesses are not colored.
+        let step = (!self.debug_call().is_zk()).then(|| self.current_step());
+        let stack_len = step.and_then(|step| step.stack.as_ref()).map_or(0, |s| s.len());
+        if let Some(step) = step.filter(|_| stack_len > 0) {
             if let Some(stack) = step.stack.as_ref() {
                 if let Some(accesses) = get_buffer_accesses(step.op.get(), stack) {
                     if let Some(read_access) = accesses.read {
@@ -492,7 +569,7 @@ impl DebuggerContext<'_> {
         // TODO: technically it's possible for this to conflict with the current op, ie, with
         // subsequent MCOPYs, but solc can't seem to generate that code even with high optimizer
         // settings
-        if self.current_step > 0 {
+        if step.is_some() && self.current_step > 0 {
             let prev_step = self.current_step - 1;
             let prev_step = &self.debug_steps()[prev_step];
             if let Some(stack) = prev_step.stack.as_ref() {
@@ -582,7 +659,15 @@ impl DebuggerContext<'_> {
             })
             .collect();
 
-        let title = self.active_buffer.title(buf.len());
+        let title = if self.debug_call().is_zk() && self.active_buffer == BufferKind::Memory {
+            if self.current_zk_heap().is_some() {
+                format!("Heap (size: {} bytes)", buf.len())
+            } else {
+                "Heap (not recorded)".to_string()
+            }
+        } else {
+            self.active_buffer.title(buf.len())
+        };
         let block = Block::default().title(title).borders(Borders::ALL);
         let paragraph = Paragraph::new(text).block(block).wrap(Wrap { trim: true });
         f.render_widget(paragraph, area);
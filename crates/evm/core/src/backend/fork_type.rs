@@ -1,6 +1,11 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
+use alloy_primitives::{Bytes, B256};
 use alloy_provider::Provider;
+use foundry_common::provider::RetryProvider;
+use foundry_zksync_core::vm::ZkEnv;
+use parking_lot::RwLock;
+use zksync_types::api::BlockDetails;
 
 /// Defines a fork of the type EVM or ZK.
 #[derive(Debug, Clone)]
@@ -22,8 +27,16 @@ impl ForkType {
 }
 
 /// A cached implementation for retrieving the [ForkType] of a given url.
+///
+/// The providers of the zkSync forks are kept to fetch their batch fee parameters and the EraVM
+/// bytecodes of their contracts, which are cached as well.
 #[derive(Default, Debug, Clone)]
-pub struct CachedForkType(HashMap<String, ForkType>);
+pub struct CachedForkType {
+    fork_types: HashMap<String, ForkType>,
+    zk_providers: HashMap<String, RetryProvider>,
+    /// The EraVM bytecodes fetched from the zkSync forks, by bytecode hash
+    zk_bytecodes: Arc<RwLock<HashMap<B256, Bytes>>>,
+}
 
 impl CachedForkType {
     /// Retrieve the [ForkType] of a url.
@@ -31,24 +44,85 @@ impl CachedForkType {
     /// then the chain is [ForkType::Zk], else it's [ForkType::Evm].
     /// The result is then cached
     pub fn get(&mut self, fork_url: &str) -> ForkType {
-        if let Some(fork_url_type) = self.0.get(fork_url) {
+        if let Some(fork_url_type) = self.fork_types.get(fork_url) {
             return fork_url_type.clone()
         }
 
-        let is_zk_url = foundry_common::provider::try_get_http_provider(fork_url)
-            .map(|provider| {
-                tokio::task::block_in_place(move || {
-                    tokio::runtime::Handle::current()
-                        .block_on(provider.raw_request::<_, String>("zks_L1ChainId".into(), ()))
-                        .map(|_| true)
-                })
-                .unwrap_or_default()
-            })
-            .unwrap_or_default();
+        let zk_provider =
+            foundry_common::provider::try_get_http_provider(fork_url).ok().filter(|provider| {
+                block_on(provider.raw_request::<_, String>("zks_L1ChainId".into(), ())).is_ok()
+            });
 
-        let fork_type = if is_zk_url { ForkType::Zk } else { ForkType::Evm };
-        self.0.insert(fork_url.to_string(), fork_type.clone());
+        let fork_type = if let Some(provider) = zk_provider {
+            self.zk_providers.insert(fork_url.to_string(), provider);
+            ForkType::Zk
+        } else {
+            ForkType::Evm
+        };
+        self.fork_types.insert(fork_url.to_string(), fork_type.clone());
 
         fork_type
     }
+
+    /// Retrieve the [ForkType] of a url, if it was already retrieved.
+    pub fn get_cached(&self, fork_url: &str) -> Option<ForkType> {
+        self.fork_types.get(fork_url).cloned()
+    }
+
+    /// Retrieves the batch fee parameters and protocol version of the given block of a zkSync
+    /// fork, using the `zks_getBlockDetails` method.
+    ///
+    /// Returns `None` if the url is not a known zkSync fork.
+    pub fn zk_env(&self, fork_url: &str, block_number: u64) -> Option<ZkEnv> {
+        let provider = self.zk_providers.get(fork_url)?;
+        let details = block_on(
+            provider.raw_request::<_, Option<BlockDetails>>(
+                "zks_getBlockDetails".into(),
+                (block_number,),
+            ),
+        )
+        .inspect_err(
+            |err| warn!(target: "backend", ?err, block_number, "failed fetching zk block details"),
+        )
+        .ok()??;
+
+        if details.protocol_version.is_none() {
+            warn!(target: "backend", block_number, "unknown zk protocol version of the fork");
+        }
+
+        Some(ZkEnv {
+            l1_gas_price: details.base.l1_gas_price,
+            fair_l2_gas_price: details.base.l2_fair_gas_price,
+            protocol_version: details.protocol_version,
+        })
+    }
+
+    /// Retrieves the bytecode with the given bytecode hash from a zkSync fork, using the
+    /// `zks_getBytecodeByHash` method.
+    ///
+    /// EraVM contracts are only referenced by their bytecode hash in the state, so this is required
+    /// to execute the contracts deployed on the forked chain. Bytecodes are content addressed, so
+    /// they are cached across forks.
+    ///
+    /// Returns `None` if the url is not a known zkSync fork.
+    pub fn zk_bytecode(&self, fork_url: &str, hash: B256) -> Option<Bytes> {
+        if let Some(bytecode) = self.zk_bytecodes.read().get(&hash) {
+            return Some(bytecode.clone())
+        }
+
+        let provider = self.zk_providers.get(fork_url)?;
+        let bytecode: Bytes = block_on(
+            provider.raw_request::<_, Option<Vec<u8>>>("zks_getBytecodeByHash".into(), (hash,)),
+        )
+        .inspect_err(|err| warn!(target: "backend", ?err, ?hash, "failed fetching zk bytecode"))
+        .ok()??
+        .into();
+
+        self.zk_bytecodes.write().insert(hash, bytecode.clone());
+        Some(bytecode)
+    }
+}
+
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    tokio::task::block_in_place(move || tokio::runtime::Handle::current().block_on(future))
 }
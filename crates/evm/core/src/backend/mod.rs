@@ -15,7 +15,8 @@ use eyre::Context;
 use foundry_common::{is_known_system_sender, SYSTEM_TRANSACTION_TYPE};
 pub use foundry_fork_db::{cache::BlockchainDbMeta, BlockchainDb, SharedBackend};
 use foundry_zksync_core::{
-    convert::ConvertH160, ACCOUNT_CODE_STORAGE_ADDRESS, L2_BASE_TOKEN_ADDRESS, NONCE_HOLDER_ADDRESS,
+    convert::ConvertH160, vm::ZkEnv, ACCOUNT_CODE_STORAGE_ADDRESS, L2_BASE_TOKEN_ADDRESS,
+    NONCE_HOLDER_ADDRESS,
 };
 use itertools::Itertools;
 use revm::{
@@ -80,6 +81,8 @@ pub struct ForkInfo {
     pub fork_type: ForkType,
     /// The fork's environment
     pub fork_env: Env,
+    /// The batch fee parameters of the fork, if it's a zkSync fork
+    pub zk_env: Option<ZkEnv>,
 }
 
 /// An extension trait that allows us to easily extend the `revm::Inspector` capabilities
@@ -463,6 +466,8 @@ pub struct Backend {
     inner: BackendInner,
     /// Keeps track of the fork type
     fork_url_type: CachedForkType,
+    /// Keeps track of the batch fee parameters of the zkSync forks
+    zk_fork_envs: HashMap<ForkId, Option<ZkEnv>>,
     /// TODO: Ensure this parameter is updated on `select_fork`.
     ///
     /// Keeps track if the backend is in ZK mode.
@@ -502,6 +507,7 @@ impl Backend {
             active_fork_ids: None,
             inner,
             fork_url_type: Default::default(),
+            zk_fork_envs: Default::default(),
             is_zk: false,
         };
 
@@ -541,11 +547,22 @@ impl Backend {
             fork_init_journaled_state: self.inner.new_journaled_state(),
             active_fork_ids: None,
             inner: Default::default(),
-            fork_url_type: Default::default(),
+            fork_url_type: self.fork_url_type.clone(),
+            zk_fork_envs: Default::default(),
             is_zk: false,
         }
     }
 
+    /// Fetches the EraVM bytecode with the given hash from the active fork, if it's a zkSync fork.
+    ///
+    /// The EraVM only references contracts by their bytecode hash, so unlike the EVM their code
+    /// is not loaded along with the account.
+    fn zk_fork_code_by_hash(&self, code_hash: B256) -> Option<Bytecode> {
+        let fork_id = self.ensure_fork_id(self.active_fork_id()?).ok()?;
+        let fork_url = self.forks.get_fork_url(fork_id.clone()).ok()??;
+        self.fork_url_type.zk_bytecode(&fork_url, code_hash).map(Bytecode::new_raw)
+    }
+
     pub fn insert_account_info(&mut self, address: Address, account: AccountInfo) {
         if let Some(db) = self.active_fork_db_mut() {
             db.insert_account_info(address, account)
@@ -816,10 +833,17 @@ impl Backend {
         env: &mut EnvWithHandlerCfg,
         persisted_factory_deps: &mut HashMap<foundry_zksync_core::H256, Vec<u8>>,
         factory_deps: Option<Vec<Vec<u8>>>,
+        paymaster_data: Option<foundry_zksync_core::PaymasterParams>,
     ) -> eyre::Result<ResultAndState> {
         self.initialize(env);
 
-        foundry_zksync_core::vm::transact(Some(persisted_factory_deps), factory_deps, env, self)
+        foundry_zksync_core::vm::transact(
+            Some(persisted_factory_deps),
+            factory_deps,
+            paymaster_data,
+            env,
+            self,
+        )
     }
 
     /// Returns true if the address is a precompile
@@ -965,13 +989,20 @@ impl DatabaseExt for Backend {
             .forks
             .get_env(fork_id.clone())?
             .ok_or_else(|| eyre::eyre!("Requested fork `{}` does not exit", id))?;
-        let fork_type = self
-            .forks
-            .get_fork_url(fork_id)?
-            .map(|url| self.fork_url_type.get(&url))
-            .unwrap_or(ForkType::Zk);
+        let fork_url = self.forks.get_fork_url(fork_id.clone())?;
+        let fork_type =
+            fork_url.as_ref().map(|url| self.fork_url_type.get(url)).unwrap_or(ForkType::Zk);
+
+        let zk_env = match fork_url {
+            Some(url) if fork_type.is_zk() => {
+                *self.zk_fork_envs.entry(fork_id).or_insert_with(|| {
+                    self.fork_url_type.zk_env(&url, fork_env.block.number.saturating_to())
+                })
+            }
+            _ => None,
+        };
 
-        Ok(ForkInfo { fork_type, fork_env })
+        Ok(ForkInfo { fork_type, fork_env, zk_env })
     }
 
     fn snapshot(&mut self, journaled_state: &JournaledState, env: &Env) -> U256 {
@@ -1526,6 +1557,7 @@ impl DatabaseRef for Backend {
     fn code_by_hash_ref(&self, code_hash: B256) -> Result<Bytecode, Self::Error> {
         if let Some(db) = self.active_fork_db() {
             db.code_by_hash_ref(code_hash)
+                .or_else(|err| self.zk_fork_code_by_hash(code_hash).ok_or(err))
         } else {
             Ok(self.mem_db.code_by_hash_ref(code_hash)?)
         }
@@ -1570,7 +1602,16 @@ impl Database for Backend {
 
     fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
         if let Some(db) = self.active_fork_db_mut() {
-            Ok(db.code_by_hash(code_hash)?)
+            match db.code_by_hash(code_hash) {
+                Ok(code) => Ok(code),
+                Err(err) => {
+                    let code = self.zk_fork_code_by_hash(code_hash).ok_or(err)?;
+                    if let Some(db) = self.active_fork_db_mut() {
+                        db.contracts.insert(code_hash, code.clone());
+                    }
+                    Ok(code)
+                }
+            }
         } else {
             Ok(self.mem_db.code_by_hash(code_hash)?)
         }
@@ -5,7 +5,9 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
+use alloy_primitives::{Address, Log};
 use auto_impl::auto_impl;
+use foundry_zksync_core::vm::{Call, EraVmStep};
 use revm::{inspectors::NoOpInspector, interpreter::CreateInputs, Database, EvmContext, Inspector};
 use revm_inspectors::access_list::AccessListInspector;
 
@@ -47,6 +49,18 @@ pub trait InspectorExt<DB: Database>: Inspector<DB> {
 
     // Simulates `console.log` invocation.
     fn console_log(&mut self, _input: String) {}
+
+    /// Records the call traces, logs and debug steps of a zkVM execution under the currently
+    /// active call, which was made to `address`.
+    fn trace_zksync(
+        &mut self,
+        _context: &mut EvmContext<DB>,
+        _address: Address,
+        _call_traces: Vec<Call>,
+        _logs: &[Log],
+        _debug_steps: Vec<EraVmStep>,
+    ) {
+    }
 }
 
 impl<DB: Database> InspectorExt<DB> for NoOpInspector {}
@@ -22,6 +22,7 @@ use std::{
 
 pub mod analysis;
 pub mod anchors;
+pub mod zksync;
 
 mod inspector;
 pub use inspector::CoverageCollector;
@@ -40,6 +41,8 @@ pub struct CoverageReport {
     pub items: HashMap<Version, Vec<CoverageItem>>,
     /// All item anchors for the codebase, keyed by their contract ID.
     pub anchors: HashMap<ContractId, (Vec<ItemAnchor>, Vec<ItemAnchor>)>,
+    /// All item anchors for the zksolc compiled codebase, keyed by their contract ID.
+    pub zk_anchors: HashMap<ContractId, Vec<ItemAnchor>>,
     /// All the bytecode hits for the codebase.
     pub bytecode_hits: HashMap<ContractId, HitMap>,
     /// The bytecode -> source mappings.
@@ -79,6 +82,14 @@ impl CoverageReport {
         self.anchors.extend(anchors);
     }
 
+    /// Add zksolc anchors to this report.
+    pub fn add_zk_anchors(
+        &mut self,
+        anchors: impl IntoIterator<Item = (ContractId, Vec<ItemAnchor>)>,
+    ) {
+        self.zk_anchors.extend(anchors);
+    }
+
     /// Get coverage summaries by source file path.
     pub fn summary_by_file(&self) -> impl Iterator<Item = (PathBuf, CoverageSummary)> {
         let mut summaries = BTreeMap::new();
@@ -149,6 +160,23 @@ impl CoverageReport {
         Ok(())
     }
 
+    /// Processes data from a [`HitMap`] recorded in the zkVM and sets hit counts for coverage
+    /// items in this coverage map.
+    ///
+    /// EraVM bytecode has no source map, so bytecode level hits are not recorded.
+    pub fn add_zk_hit_map(&mut self, contract_id: &ContractId, hit_map: &HitMap) {
+        let Some(anchors) = self.zk_anchors.get(contract_id) else { return };
+        for anchor in anchors {
+            if let Some(&hits) = hit_map.hits.get(&anchor.instruction) {
+                self.items
+                    .get_mut(&contract_id.version)
+                    .and_then(|items| items.get_mut(anchor.item_id))
+                    .expect("Anchor refers to non-existent coverage item")
+                    .hits += hits;
+            }
+        }
+    }
+
     /// Removes all the coverage items that should be ignored by the filter.
     ///
     /// This function should only be called after all the sources were used, otherwise, the output
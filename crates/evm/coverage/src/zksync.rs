@@ -0,0 +1,53 @@
+//! Coverage analysis for contracts compiled with zksolc and executed in the zkVM.
+//!
+//! zksolc does not output source maps, instead the EraVM assembly it emits carries the source
+//! location of each instruction as `.loc` directives, which are used here to map the program
+//! counters recorded during execution back to the coverage items.
+
+use super::{CoverageItem, CoverageItemKind, ItemAnchor};
+use foundry_zksync_compiler::EraVmSourceMap;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::HashMap;
+
+/// Attempts to find anchors for the given items using the given EraVM source map.
+///
+/// Items are anchored to the first instruction mapped to the line the item starts at, `source_ids`
+/// maps the file numbers of the source map to source IDs. Branch items are not supported, as the
+/// EraVM source map does not carry enough information to tell the paths apart.
+pub fn find_zk_anchors(
+    source_map: &EraVmSourceMap,
+    source_ids: &HashMap<u32, usize>,
+    items: &[CoverageItem],
+    items_by_source_id: &FxHashMap<usize, Vec<usize>>,
+) -> Vec<ItemAnchor> {
+    let mut seen = FxHashSet::default();
+    let mut first_instruction_by_line = FxHashMap::default();
+    for (pc, element) in source_map.elements.iter().enumerate() {
+        let Some(element) = element else { continue };
+        let Some(&source_id) = source_ids.get(&element.file) else { continue };
+        first_instruction_by_line.entry((source_id, element.line)).or_insert(pc);
+    }
+
+    first_instruction_by_line
+        .keys()
+        .filter_map(|(source_id, _)| items_by_source_id.get(source_id))
+        .flatten()
+        .filter_map(|&item_id| {
+            if !seen.insert(item_id) {
+                return None;
+            }
+
+            let item = &items[item_id];
+            if matches!(item.kind, CoverageItemKind::Branch { .. }) {
+                return None;
+            }
+
+            let instruction =
+                first_instruction_by_line.get(&(item.loc.source_id, item.loc.line)).copied();
+            if instruction.is_none() {
+                trace!("Could not find zk anchor for item {item}");
+            }
+            instruction.map(|instruction| ItemAnchor { instruction, item_id })
+        })
+        .collect()
+}
@@ -7,7 +7,8 @@
 // the concrete `Executor` type.
 
 use crate::inspectors::{
-    cheatcodes::BroadcastableTransactions, Cheatcodes, InspectorData, InspectorStack,
+    cheatcodes::{BroadcastableTransactions, ZkCallGasUsage},
+    Cheatcodes, InspectorData, InspectorStack,
 };
 use alloy_dyn_abi::{DynSolValue, FunctionExt, JsonAbiExt};
 use alloy_json_abi::Function;
@@ -24,7 +25,7 @@ use foundry_evm_core::{
 };
 use foundry_evm_coverage::HitMaps;
 use foundry_evm_traces::{CallTraceArena, TraceMode};
-use foundry_zksync_core::ZkTransactionMetadata;
+use foundry_zksync_core::{vm::EraVmStep, ZkTransactionMetadata};
 use revm::{
     db::{DatabaseCommit, DatabaseRef},
     interpreter::{return_ok, InstructionResult},
@@ -434,6 +435,7 @@ impl Executor {
                     &mut env,
                     &mut self.zk_persisted_factory_deps.clone(),
                     Some(zk_tx.factory_deps.clone()),
+                    zk_tx.paymaster_data.clone(),
                 )?
             }
         };
@@ -458,6 +460,7 @@ impl Executor {
                     // no need to commit them later
                     &mut self.zk_persisted_factory_deps,
                     Some(zk_tx.factory_deps),
+                    zk_tx.paymaster_data,
                 )?
             }
         };
@@ -805,6 +808,10 @@ pub struct RawCallResult {
     pub chisel_state: Option<(Vec<U256>, Vec<u8>, InstructionResult)>,
     /// The deployments generated during the call
     pub deployments: HashMap<Address, Bytes>,
+    /// The gas usage breakdown of the ZK-VM calls and creates made during the call
+    pub zk_gas_usage: Vec<ZkCallGasUsage>,
+    /// The EraVM steps of the ZK-VM calls and creates, keyed by the index of their trace node
+    pub zk_debug_steps: HashMap<usize, Vec<EraVmStep>>,
 }
 
 impl Default for RawCallResult {
@@ -828,6 +835,8 @@ impl Default for RawCallResult {
             cheatcodes: Default::default(),
             out: None,
             chisel_state: None,
+            zk_gas_usage: Vec::new(),
+            zk_debug_steps: HashMap::new(),
         }
     }
 }
@@ -935,14 +944,28 @@ fn convert_executed_result(
         _ => Bytes::new(),
     };
 
-    let InspectorData { logs, labels, traces, coverage, cheatcodes, chisel_state } =
-        inspector.collect();
+    let InspectorData {
+        logs,
+        labels,
+        traces,
+        coverage,
+        zk_debug_steps,
+        mut cheatcodes,
+        chisel_state,
+    } = inspector.collect();
 
     let transactions = cheatcodes
         .as_ref()
         .map(|c| c.broadcastable_transactions.clone())
         .filter(|txs| !txs.is_empty());
 
+    // Taken so each call only reports the ZK-VM gas usage of its own execution.
+    let zk_gas_usage = cheatcodes
+        .as_mut()
+        .and_then(|c| c.zk_gas_usage.as_mut())
+        .map(std::mem::take)
+        .unwrap_or_default();
+
     Ok(RawCallResult {
         deployments: HashMap::new(),
         exit_reason,
@@ -962,5 +985,7 @@ fn convert_executed_result(
         cheatcodes,
         out,
         chisel_state,
+        zk_gas_usage,
+        zk_debug_steps,
     })
 }
@@ -14,4 +14,4 @@ mod logs;
 pub use logs::LogCollector;
 
 mod stack;
-pub use stack::{InspectorData, InspectorStack, InspectorStackBuilder};
+pub use stack::{trace_zksync_transaction, InspectorData, InspectorStack, InspectorStackBuilder};
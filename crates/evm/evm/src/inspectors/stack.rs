@@ -2,27 +2,32 @@ use super::{
     Cheatcodes, CheatsConfig, ChiselState, CoverageCollector, Fuzzer, LogCollector,
     TracingInspector,
 };
-use alloy_primitives::{Address, Bytes, Log, TxKind, U256};
+use alloy_primitives::{Address, Bytes, Log, TxKind, B256, U256};
 use foundry_cheatcodes::CheatcodesExecutor;
 use foundry_evm_core::{
     backend::{update_state, DatabaseExt},
     InspectorExt,
 };
-use foundry_evm_coverage::HitMaps;
+use foundry_evm_coverage::{HitMap, HitMaps};
 use foundry_evm_traces::{CallTraceArena, TraceMode};
+use foundry_zksync_core::{
+    convert::{ConvertH160, ConvertU256},
+    is_system_address,
+    vm::{Call, CallType, EraVmStep, FarCallOpcode},
+};
 use revm::{
     inspectors::CustomPrintTracer,
     interpreter::{
-        CallInputs, CallOutcome, CallScheme, CreateInputs, CreateOutcome, EOFCreateInputs,
-        EOFCreateKind, Gas, InstructionResult, Interpreter, InterpreterResult,
+        CallInputs, CallOutcome, CallScheme, CallValue, CreateInputs, CreateOutcome,
+        EOFCreateInputs, EOFCreateKind, Gas, InstructionResult, Interpreter, InterpreterResult,
     },
     primitives::{
         BlockEnv, CreateScheme, Env, EnvWithHandlerCfg, ExecutionResult, Output, TransactTo,
     },
-    EvmContext, Inspector,
+    Database, EvmContext, Inspector,
 };
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet, VecDeque},
     ops::{Deref, DerefMut},
     sync::Arc,
 };
@@ -234,6 +239,9 @@ pub struct InspectorData {
     pub labels: HashMap<Address, String>,
     pub traces: Option<CallTraceArena>,
     pub coverage: Option<HitMaps>,
+    /// The EraVM steps of the zkVM executions, keyed by the index of the trace node of the call
+    /// that was executed in the zkVM.
+    pub zk_debug_steps: HashMap<usize, Vec<EraVmStep>>,
     pub cheatcodes: Option<Cheatcodes>,
     pub chisel_state: Option<(Vec<U256>, Vec<u8>, InstructionResult)>,
 }
@@ -283,6 +291,7 @@ pub struct InspectorStackInner {
     pub log_collector: Option<LogCollector>,
     pub printer: Option<CustomPrintTracer>,
     pub tracer: Option<TracingInspector>,
+    pub zk_debug_steps: HashMap<usize, Vec<EraVmStep>>,
     pub enable_isolation: bool,
 
     /// Flag marking if we are in the inner EVM context.
@@ -384,6 +393,9 @@ impl InspectorStack {
     #[inline]
     pub fn collect_coverage(&mut self, yes: bool) {
         self.coverage = yes.then(Default::default);
+        if let Some(cheatcodes) = &mut self.cheatcodes {
+            cheatcodes.zk_coverage = yes.then(Default::default);
+        }
     }
 
     /// Set whether to enable call isolation.
@@ -407,6 +419,9 @@ impl InspectorStack {
     /// Set whether to enable the tracer.
     #[inline]
     pub fn tracing(&mut self, mode: TraceMode) {
+        if let Some(cheatcodes) = &mut self.cheatcodes {
+            cheatcodes.zk_debug_steps = mode.is_debug().then(Default::default);
+        }
         if let Some(config) = mode.into_config() {
             *self.tracer.get_or_insert_with(Default::default).config_mut() = config;
         } else {
@@ -418,10 +433,31 @@ impl InspectorStack {
     #[inline]
     pub fn collect(self) -> InspectorData {
         let Self {
-            cheatcodes,
-            inner: InspectorStackInner { chisel_state, coverage, log_collector, tracer, .. },
+            mut cheatcodes,
+            inner:
+                InspectorStackInner {
+                    chisel_state,
+                    coverage,
+                    log_collector,
+                    tracer,
+                    zk_debug_steps,
+                    ..
+                },
         } = self;
 
+        // take the instruction hits recorded in the zkVM, so they are not reported again by the
+        // next call, and key them by the EraVM bytecode hash of the dual compiled contracts.
+        let zk_coverage = cheatcodes.as_mut().and_then(|cheatcodes| {
+            let hits = std::mem::take(cheatcodes.zk_coverage.as_mut()?);
+            let maps = hits.into_iter().filter_map(|(code_hash, hits)| {
+                let contract =
+                    cheatcodes.dual_compiled_contracts.find_by_zk_bytecode_hash(code_hash)?;
+                let bytecode = Bytes::from(contract.zk_deployed_bytecode.clone());
+                Some((B256::from(code_hash.0), HitMap { bytecode, hits }))
+            });
+            Some(HitMaps(maps.collect()))
+        });
+
         InspectorData {
             logs: log_collector.map(|logs| logs.logs).unwrap_or_default(),
             labels: cheatcodes
@@ -429,7 +465,9 @@ impl InspectorStack {
                 .map(|cheatcodes| cheatcodes.labels.clone())
                 .unwrap_or_default(),
             traces: tracer.map(|tracer| tracer.into_traces()),
-            coverage: coverage.map(|coverage| coverage.maps),
+            coverage: coverage
+                .map(|coverage| coverage.maps.merged(zk_coverage.unwrap_or_default())),
+            zk_debug_steps,
             cheatcodes,
             chisel_state: chisel_state.and_then(|state| state.state),
         }
@@ -751,12 +789,21 @@ impl<'a, DB: DatabaseExt> Inspector<DB> for InspectorStackRefMut<'a> {
 
         call_inspectors_adjust_depth!(
             #[ret]
-            [&mut self.tracer, &mut self.coverage, &mut self.cheatcodes],
+            [&mut self.tracer, &mut self.coverage],
             |inspector| inspector.create(ecx, create).map(Some),
             self,
             ecx
         );
 
+        ecx.journaled_state.depth += self.in_inner_context as usize;
+        if let Some(cheatcodes) = self.cheatcodes.as_deref_mut() {
+            if let Some(output) = cheatcodes.create_with_executor(ecx, create, self.inner) {
+                ecx.journaled_state.depth -= self.in_inner_context as usize;
+                return Some(output);
+            }
+        }
+        ecx.journaled_state.depth -= self.in_inner_context as usize;
+
         if !matches!(create.scheme, CreateScheme::Create2 { .. }) &&
             self.enable_isolation &&
             !self.in_inner_context &&
@@ -915,6 +962,179 @@ impl<'a, DB: DatabaseExt> InspectorExt<DB> for InspectorStackRefMut<'a> {
             inspector, input
         ));
     }
+
+    fn trace_zksync(
+        &mut self,
+        ecx: &mut EvmContext<DB>,
+        address: Address,
+        call_traces: Vec<Call>,
+        logs: &[Log],
+        debug_steps: Vec<EraVmStep>,
+    ) {
+        let Some(tracer) = self.tracer.as_mut() else { return };
+        // the currently active call is the last recorded node, as the zkVM calls are only
+        // recorded below
+        let node_idx = tracer.traces().nodes().len() - 1;
+
+        // only keep the logs emitted by contracts that are part of the traces, this excludes
+        // the system contract events and the injected console logs.
+        let mut emitters = HashSet::from([address]);
+        collect_zksync_emitters(&call_traces, &mut emitters);
+        let mut logs =
+            logs.iter().filter(|log| emitters.contains(&log.address)).collect::<VecDeque<_>>();
+
+        trace_zksync_nested_calls(tracer, ecx, address, call_traces, &mut logs);
+
+        if !debug_steps.is_empty() {
+            self.zk_debug_steps.entry(node_idx).or_default().extend(debug_steps);
+        }
+    }
+}
+
+/// Returns the address of the account whose context a zkVM call executes in, which is also the
+/// address of any logs emitted during it.
+fn zksync_call_context_address(call: &Call) -> Address {
+    match call.r#type {
+        CallType::Call(FarCallOpcode::Delegate) => call.from.to_address(),
+        _ => call.to.to_address(),
+    }
+}
+
+/// Collects the log emitters of all the zkVM calls that are recorded in the traces.
+fn collect_zksync_emitters(calls: &[Call], emitters: &mut HashSet<Address>) {
+    for call in calls {
+        if is_traced_zksync_call(call) {
+            emitters.insert(zksync_call_context_address(call));
+        }
+        collect_zksync_emitters(&call.calls, emitters);
+    }
+}
+
+/// Returns true if the zkVM call should be recorded in the traces. Near calls and calls to system
+/// contracts are skipped, but their nested calls are still recorded.
+fn is_traced_zksync_call(call: &Call) -> bool {
+    !matches!(call.r#type, CallType::NearCall) && !is_system_address(call.to.to_address())
+}
+
+/// Records the calls of a zkVM transaction in the [TracingInspector], along with the logs emitted
+/// during them, as the root calls of the traces.
+pub fn trace_zksync_transaction<DB: Database>(
+    tracer: &mut TracingInspector,
+    ecx: &mut EvmContext<DB>,
+    call_traces: Vec<Call>,
+    logs: &[Log],
+) {
+    let mut emitters = HashSet::new();
+    collect_zksync_emitters(&call_traces, &mut emitters);
+    let mut logs =
+        logs.iter().filter(|log| emitters.contains(&log.address)).collect::<VecDeque<_>>();
+
+    trace_zksync_calls(tracer, ecx, call_traces, &mut logs);
+}
+
+/// Records the zkVM calls in the [TracingInspector], nested under its currently active call.
+///
+/// zkVM logs are not associated with the call that emitted them, so each log is attached to the
+/// active call of its emitter, in the order the logs were emitted.
+fn trace_zksync_calls<DB: Database>(
+    tracer: &mut TracingInspector,
+    ecx: &mut EvmContext<DB>,
+    calls: Vec<Call>,
+    logs: &mut VecDeque<&Log>,
+) {
+    for call in calls {
+        if !is_traced_zksync_call(&call) {
+            trace_zksync_calls(tracer, ecx, call.calls, logs);
+            continue;
+        }
+
+        let address = zksync_call_context_address(&call);
+        let from = call.from.to_address();
+        let value = call.value.to_ru256();
+        let result = if call.error.is_some() || call.revert_reason.is_some() {
+            InstructionResult::Revert
+        } else {
+            InstructionResult::Return
+        };
+        let mut gas = Gas::new(call.gas);
+        let _ = gas.record_cost(call.gas_used);
+        let outcome = InterpreterResult { result, output: Bytes::from(call.output), gas };
+
+        if call.r#type == CallType::Create {
+            let mut inputs = CreateInputs {
+                caller: from,
+                scheme: CreateScheme::Create,
+                value,
+                init_code: Bytes::from(call.input),
+                gas_limit: call.gas,
+            };
+            Inspector::<DB>::create(tracer, ecx, &mut inputs);
+            trace_zksync_nested_calls(tracer, ecx, address, call.calls, logs);
+            let address = (result == InstructionResult::Return).then_some(address);
+            Inspector::<DB>::create_end(
+                tracer,
+                ecx,
+                &inputs,
+                CreateOutcome { result: outcome, address },
+            );
+        } else {
+            let is_delegate = call.r#type == CallType::Call(FarCallOpcode::Delegate);
+            let mut inputs = CallInputs {
+                caller: from,
+                bytecode_address: call.to.to_address(),
+                target_address: address,
+                scheme: if is_delegate { CallScheme::DelegateCall } else { CallScheme::Call },
+                value: if is_delegate {
+                    CallValue::Apparent(value)
+                } else {
+                    CallValue::Transfer(value)
+                },
+                input: Bytes::from(call.input),
+                gas_limit: call.gas,
+                is_static: false,
+                return_memory_offset: 0..0,
+                is_eof: false,
+            };
+            Inspector::<DB>::call(tracer, ecx, &mut inputs);
+            trace_zksync_nested_calls(tracer, ecx, address, call.calls, logs);
+            Inspector::<DB>::call_end(
+                tracer,
+                ecx,
+                &inputs,
+                CallOutcome { result: outcome, memory_offset: 0..0 },
+            );
+        }
+    }
+}
+
+/// Records the nested calls of the currently active zkVM call, along with the logs emitted by
+/// `address` in between them.
+fn trace_zksync_nested_calls<DB: Database>(
+    tracer: &mut TracingInspector,
+    ecx: &mut EvmContext<DB>,
+    address: Address,
+    calls: Vec<Call>,
+    logs: &mut VecDeque<&Log>,
+) {
+    let mut interpreter = Interpreter::new(Default::default(), 0, false);
+    let mut record_logs =
+        |tracer: &mut TracingInspector, ecx: &mut EvmContext<DB>, logs: &mut VecDeque<&Log>| {
+            while let Some(log) = logs.pop_front() {
+                if log.address != address {
+                    logs.push_front(log);
+                    break;
+                }
+                Inspector::<DB>::log(tracer, &mut interpreter, ecx, log);
+            }
+        };
+
+    record_logs(tracer, ecx, logs);
+    ecx.journaled_state.depth += 1;
+    for call in calls {
+        trace_zksync_calls(tracer, ecx, vec![call], logs);
+        record_logs(tracer, ecx, logs);
+    }
+    ecx.journaled_state.depth -= 1;
 }
 
 impl<DB: DatabaseExt> Inspector<DB> for InspectorStack {
@@ -1,3 +1,4 @@
+use alloy_primitives::B256;
 use eyre::{Context, Result};
 use foundry_common::compact_to_contract;
 use foundry_compilers::{
@@ -6,16 +7,21 @@ use foundry_compilers::{
         Bytecode, ContractBytecodeSome, Libraries, Source,
     },
     multi::MultiCompilerLanguage,
+    solc::SolcLanguage,
+    zksync::compile::output::ProjectCompileOutput as ZkProjectCompileOutput,
     Artifact, Compiler, ProjectCompileOutput,
 };
 use foundry_evm_core::utils::PcIcMap;
 use foundry_linking::Linker;
+use foundry_zksync_compiler::EraVmSourceMap;
 use rayon::prelude::*;
 use rustc_hash::FxHashMap;
 use solang_parser::pt::SourceUnitPart;
 use std::{
     collections::{BTreeMap, HashMap},
+    ops::Range,
     path::{Path, PathBuf},
+    str::FromStr,
     sync::Arc,
 };
 
@@ -111,6 +117,17 @@ impl ArtifactData {
     }
 }
 
+/// Source data of a contract compiled with zksolc.
+#[derive(Clone, Debug)]
+pub struct ZkArtifactData {
+    /// The contract name.
+    pub name: String,
+    /// The EraVM source map, parsed from the zksolc assembly.
+    pub source_map: EraVmSourceMap,
+    /// The source files referenced by the source map, keyed by their file number.
+    pub sources: HashMap<u32, Arc<SourceData>>,
+}
+
 /// Container with artifacts data useful for identifying individual execution steps.
 #[derive(Clone, Debug, Default)]
 pub struct ContractSources {
@@ -118,6 +135,8 @@ pub struct ContractSources {
     pub sources_by_id: HashMap<String, FxHashMap<u32, Arc<SourceData>>>,
     /// Map over contract name -> Vec<(bytecode, build_id, file_id)>
     pub artifacts_by_name: HashMap<String, Vec<ArtifactData>>,
+    /// Map over EraVM bytecode hash -> zksolc artifact data
+    pub zk_artifacts_by_hash: HashMap<B256, ZkArtifactData>,
 }
 
 impl ContractSources {
@@ -215,6 +234,56 @@ impl ContractSources {
         Ok(())
     }
 
+    /// Collects the EraVM source maps of the zksolc compiled contracts.
+    ///
+    /// Requires the EraVM assembly to be part of the zksolc output, contracts without it are
+    /// skipped.
+    pub fn insert_zk(
+        &mut self,
+        output: &ZkProjectCompileOutput,
+        root: impl AsRef<Path>,
+    ) -> Result<()> {
+        let root = root.as_ref();
+        let mut files: BTreeMap<PathBuf, Arc<SourceData>> = BTreeMap::new();
+        for (name, artifact) in output.artifacts() {
+            let (Some(hash), Some(assembly)) = (&artifact.hash, &artifact.assembly) else {
+                continue;
+            };
+            let code_hash = B256::from_str(hash)?;
+            let source_map = EraVmSourceMap::parse(assembly);
+
+            let mut sources = HashMap::new();
+            for (file, path) in &source_map.files {
+                let path = root.join(path);
+                let source_data = if let Some(source_data) = files.get(&path) {
+                    source_data.clone()
+                } else {
+                    let source = Source::read(&path).wrap_err_with(|| {
+                        format!("failed to read artifact source file for `{}`", path.display())
+                    })?;
+
+                    let stripped = path.strip_prefix(root).unwrap_or(&path).to_path_buf();
+
+                    let source_data = Arc::new(SourceData::new(
+                        source.content.clone(),
+                        MultiCompilerLanguage::Solc(SolcLanguage::Solidity),
+                        stripped,
+                    ));
+
+                    files.insert(path.clone(), source_data.clone());
+
+                    source_data
+                };
+                sources.insert(*file, source_data);
+            }
+
+            self.zk_artifacts_by_hash
+                .insert(code_hash, ZkArtifactData { name, source_map, sources });
+        }
+
+        Ok(())
+    }
+
     /// Returns all sources for a contract by name.
     pub fn get_sources(
         &self,
@@ -285,4 +354,27 @@ impl ContractSources {
             res
         })
     }
+
+    /// Returns the name of the zksolc compiled contract with the given EraVM bytecode hash.
+    pub fn find_zk_contract_name(&self, code_hash: B256) -> Option<&str> {
+        self.zk_artifacts_by_hash.get(&code_hash).map(|artifact| artifact.name.as_str())
+    }
+
+    /// Returns the source range of the line that the EraVM instruction at `pc` was compiled from,
+    /// in the contract with the given bytecode hash.
+    pub fn find_zk_source_mapping(
+        &self,
+        code_hash: B256,
+        pc: usize,
+    ) -> Option<(Range<usize>, &SourceData)> {
+        let artifact = self.zk_artifacts_by_hash.get(&code_hash)?;
+        let element = artifact.source_map.elements.get(pc)?.as_ref()?;
+        let source = artifact.sources.get(&element.file)?;
+
+        // lines are 1-indexed
+        let start: usize =
+            source.source.split_inclusive('\n').take(element.line - 1).map(str::len).sum();
+        let line = source.source[start..].lines().next()?;
+        Some((start..start + line.len(), source.as_ref()))
+    }
 }
@@ -0,0 +1,91 @@
+//! Measures the fuzzing throughput of the zkVM.
+//!
+//! The throughput with the VMs reused across transactions is compared against the baseline of
+//! creating a new VM for every transaction, which is selected with `ZK_DISABLE_VM_REUSE`. The
+//! benchmark fails if reusing the VMs is slower than the baseline.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use foundry_test_utils::{foundry_compilers::PathStyle, util::setup_forge, TestProject};
+use std::time::{Duration, Instant};
+
+/// Number of fuzz runs of each benchmarked test.
+const FUZZ_RUNS: &str = "256";
+
+/// Tolerated slowdown of the reused VMs over the baseline, to account for noise.
+const MAX_SLOWDOWN: f64 = 1.05;
+
+/// Returns a project with a fuzz test that deploys and calls a contract in every run, which
+/// exercises the setup of the zkVM for each call.
+fn fuzz_project() -> TestProject {
+    let (prj, _) = setup_forge("zk_fuzz", PathStyle::Dapptools);
+    prj.add_test(
+        "Fuzz.t.sol",
+        r#"
+contract Counter {
+    uint256 public number;
+
+    function add(uint256 value) public {
+        number += value % 1000;
+    }
+}
+
+contract FuzzTest {
+    Counter counter;
+
+    function setUp() public {
+        counter = new Counter();
+    }
+
+    function testFuzzAdd(uint256 a, uint256 b) public {
+        counter.add(a);
+        counter.add(b);
+        require(counter.number() == a % 1000 + b % 1000, "wrong number");
+    }
+}
+"#,
+    )
+    .unwrap();
+    prj
+}
+
+fn zk_fuzz_benchmark(c: &mut Criterion) {
+    let prj = fuzz_project();
+
+    // compile once so only the test execution is measured
+    prj.forge_command().args(["build", "--zksync"]).assert_success();
+
+    let mut group = c.benchmark_group("forge fuzz");
+    group.sample_size(10);
+    let mut mean_times = Vec::new();
+    for (name, disable_vm_reuse) in [("zksync new vm", "true"), ("zksync reused vm", "false")] {
+        let mut cmd = prj.forge_command();
+        cmd.env("ZK_DISABLE_VM_REUSE", disable_vm_reuse);
+        cmd.args(["test", "--zksync", "--evm-version", "shanghai", "--fuzz-runs", FUZZ_RUNS]);
+
+        let (mut total, mut iterations) = (Duration::ZERO, 0);
+        group.bench_function(name, |b| {
+            b.iter_custom(|iters| {
+                let start = Instant::now();
+                for _ in 0..iters {
+                    cmd.assert_success();
+                }
+                let elapsed = start.elapsed();
+                total += elapsed;
+                iterations += iters;
+                elapsed
+            })
+        });
+        mean_times.push(total.as_secs_f64() / iterations as f64);
+    }
+    group.finish();
+
+    let (baseline, reused) = (mean_times[0], mean_times[1]);
+    assert!(
+        reused <= baseline * MAX_SLOWDOWN,
+        "reusing the VMs regressed the zk fuzz throughput: {reused:.3}s per run of the test suite, \
+         against {baseline:.3}s when creating a new VM for each transaction"
+    );
+}
+
+criterion_group!(benches, zk_fuzz_benchmark);
+criterion_main!(benches);
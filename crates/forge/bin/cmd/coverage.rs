@@ -6,6 +6,7 @@ use forge::{
     coverage::{
         analysis::{SourceAnalysis, SourceAnalyzer, SourceFile, SourceFiles},
         anchors::find_anchors,
+        zksync::find_zk_anchors,
         BytecodeReporter, ContractId, CoverageReport, CoverageReporter, DebugReporter, ItemAnchor,
         LcovReporter, SummaryReporter,
     },
@@ -17,13 +18,17 @@ use foundry_cli::{
     p_println,
     utils::{LoadConfig, STATIC_FUZZ_SEED},
 };
-use foundry_common::{compile::ProjectCompiler, fs};
+use foundry_common::{compile::ProjectCompiler, fs, ContractsByArtifact};
 use foundry_compilers::{
-    artifacts::{sourcemap::SourceMap, CompactBytecode, CompactDeployedBytecode},
+    artifacts::{
+        sourcemap::SourceMap, zksolc::output_selection::OutputSelectionFlag, CompactBytecode,
+        CompactDeployedBytecode,
+    },
+    zksync::compile::output::ProjectCompileOutput as ZkProjectCompileOutput,
     Artifact, ArtifactId, Project, ProjectCompileOutput,
 };
 use foundry_config::{Config, SolcReq};
-use foundry_zksync_compiler::DualCompiledContracts;
+use foundry_zksync_compiler::{DualCompiledContracts, EraVmSourceMap};
 use rayon::prelude::*;
 use rustc_hash::FxHashMap;
 use semver::Version;
@@ -90,16 +95,25 @@ impl CoverageArgs {
         // Coverage analysis requires the Solc AST output.
         config.ast = true;
 
-        let (project, output) = self.build(&config)?;
+        let (project, output, zk_output) = self.build(&config)?;
         p_println!(!self.test.build_args().silent => "Analysing contracts...");
-        let report = self.prepare(&project, &output)?;
+        let report =
+            self.prepare(&project, &output, zk_output.as_ref().map(|(output, _)| output))?;
 
         p_println!(!self.test.build_args().silent => "Running tests...");
-        self.collect(project, &output, report, Arc::new(config), evm_opts).await
+        self.collect(project, &output, zk_output, report, Arc::new(config), evm_opts).await
     }
 
-    /// Builds the project.
-    fn build(&self, config: &Config) -> Result<(Project, ProjectCompileOutput)> {
+    /// Builds the project, also with zksolc if enabled.
+    #[allow(clippy::type_complexity)]
+    fn build(
+        &self,
+        config: &Config,
+    ) -> Result<(
+        Project,
+        ProjectCompileOutput,
+        Option<(ZkProjectCompileOutput, DualCompiledContracts)>,
+    )> {
         // Set up the project
         let mut project = config.create_project(false, false)?;
         if self.ir_minimum {
@@ -137,16 +151,51 @@ impl CoverageArgs {
             project.settings.solc.via_ir = None;
         }
 
-        let output = ProjectCompiler::default()
-            .compile(&project)?
-            .with_stripped_file_prefixes(project.root());
+        let output = ProjectCompiler::default().compile(&project)?;
+        let zk_output = if config.zksync.should_compile() {
+            Some(self.build_zk(config, &project, &output)?)
+        } else {
+            None
+        };
+        let output = output.with_stripped_file_prefixes(project.root());
 
-        Ok((project, output))
+        Ok((project, output, zk_output))
+    }
+
+    /// Builds the project with zksolc, and pairs the zksolc contracts with their solc counterparts.
+    fn build_zk(
+        &self,
+        config: &Config,
+        project: &Project,
+        output: &ProjectCompileOutput,
+    ) -> Result<(ZkProjectCompileOutput, DualCompiledContracts)> {
+        let mut zk_project =
+            foundry_zksync_compiler::config_create_project(config, config.cache, false)?;
+
+        // Coverage analysis requires the EraVM assembly, which carries the source locations.
+        if let Some(selection) = zk_project.settings.settings.output_selection.all.as_mut() {
+            selection
+                .per_contract
+                .get_or_insert_with(Default::default)
+                .insert(OutputSelectionFlag::Assembly);
+        }
+
+        let zk_output = ProjectCompiler::default()
+            .zksync_compile(&zk_project, config.zksync.avoid_contracts())?;
+        let dual_compiled_contracts =
+            DualCompiledContracts::new(output, &zk_output, &project.paths, &zk_project.paths);
+
+        Ok((zk_output.with_stripped_file_prefixes(project.root()), dual_compiled_contracts))
     }
 
     /// Builds the coverage report.
     #[instrument(name = "prepare", skip_all)]
-    fn prepare(&self, project: &Project, output: &ProjectCompileOutput) -> Result<CoverageReport> {
+    fn prepare(
+        &self,
+        project: &Project,
+        output: &ProjectCompileOutput,
+        zk_output: Option<&ZkProjectCompileOutput>,
+    ) -> Result<CoverageReport> {
         let mut report = CoverageReport::default();
 
         // Collect source files.
@@ -187,6 +236,48 @@ impl CoverageArgs {
             })
             .collect();
 
+        // Get the EraVM source maps of the zksolc compiled contracts, which share the contract ID
+        // of their solc counterpart
+        let contract_ids: HashMap<_, _> = artifacts
+            .iter()
+            .filter_map(|artifact| {
+                let path = report
+                    .source_paths
+                    .get(&(artifact.contract_id.version.clone(), artifact.contract_id.source_id))?;
+                Some(((path.clone(), artifact.contract_id.contract_name.clone()), artifact))
+            })
+            .collect();
+        let zk_artifacts: Vec<ZkArtifactData> = zk_output
+            .map(|zk_output| {
+                zk_output
+                    .clone()
+                    .into_artifacts()
+                    .filter_map(|(id, artifact)| {
+                        let artifact_data = contract_ids
+                            .get(&(id.source.clone(), Arc::<str>::from(id.name.as_str())))?;
+                        let source_map = EraVmSourceMap::parse(artifact.assembly.as_ref()?);
+                        Some(ZkArtifactData {
+                            contract_id: artifact_data.contract_id.clone(),
+                            source_map,
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        if zk_output.is_some() && zk_artifacts.iter().all(|a| a.source_map.files.is_empty()) {
+            warn!("no source locations found in the zksolc assembly, zkVM coverage will be empty");
+        }
+        if zk_output.is_some() {
+            let msg = concat!(
+                "Warning! Branch coverage is not supported in the zkVM, the branches of the \
+                 contracts executed in the zkVM are reported as not covered.\n",
+                "Lines, statements and functions executed in the zkVM are only tracked by source \
+                 line, so the items starting on the same line are reported as covered together.",
+            )
+            .yellow();
+            p_println!(!self.test.build_args().silent => "{msg}");
+        }
+
         // Add coverage items
         for (version, sources) in &versioned_sources {
             let source_analysis = SourceAnalyzer::new(sources).analyze()?;
@@ -214,6 +305,33 @@ impl CoverageArgs {
                 .collect::<Vec<_>>();
 
             report.add_anchors(anchors);
+
+            let zk_anchors = zk_artifacts
+                .par_iter()
+                .filter(|artifact| artifact.contract_id.version == *version)
+                .map(|artifact| {
+                    let source_ids = artifact
+                        .source_map
+                        .files
+                        .iter()
+                        .filter_map(|(file, path)| {
+                            let path = path.strip_prefix(&project_paths.root).unwrap_or(path);
+                            let source_id =
+                                report.get_source_id(version.clone(), path.to_path_buf())?;
+                            Some((*file, source_id))
+                        })
+                        .collect();
+                    let anchors = find_zk_anchors(
+                        &artifact.source_map,
+                        &source_ids,
+                        &source_analysis.items,
+                        &items_by_source_id,
+                    );
+                    (artifact.contract_id.clone(), anchors)
+                })
+                .collect::<Vec<_>>();
+
+            report.add_zk_anchors(zk_anchors);
             report.add_items(version.clone(), source_analysis.items);
         }
 
@@ -229,6 +347,7 @@ impl CoverageArgs {
         self,
         project: Project,
         output: &ProjectCompileOutput,
+        zk_output: Option<(ZkProjectCompileOutput, DualCompiledContracts)>,
         mut report: CoverageReport,
         config: Arc<Config>,
         evm_opts: EvmOpts,
@@ -236,6 +355,9 @@ impl CoverageArgs {
         let root = project.paths.root;
         let verbosity = evm_opts.verbosity;
 
+        let (zk_output, dual_compiled_contracts) = zk_output.unzip();
+        let dual_compiled_contracts = dual_compiled_contracts.unwrap_or_default();
+
         // Build the contract runner
         let env = evm_opts.evm_env().await?;
         let runner = MultiContractRunnerBuilder::new(config.clone())
@@ -249,9 +371,26 @@ impl CoverageArgs {
                 ..Default::default()
             })
             .set_coverage(true)
-            .build(&root, output.clone(), None, env, evm_opts, DualCompiledContracts::default())?;
-
-        let known_contracts = runner.known_contracts.clone();
+            .build(
+                &root,
+                output.clone(),
+                zk_output,
+                env,
+                evm_opts,
+                dual_compiled_contracts.clone(),
+            )?;
+
+        // The runner only knows the zksolc contracts in zk mode, hit maps are matched against the
+        // solc contracts instead, as the coverage items are built from the solc output.
+        let known_contracts = if runner.use_zk {
+            ContractsByArtifact::new(
+                output
+                    .artifact_ids()
+                    .map(|(id, artifact)| (id, artifact.clone().into_contract_bytecode())),
+            )
+        } else {
+            runner.known_contracts.clone()
+        };
 
         let filter = self.test.filter(&config);
         let outcome =
@@ -265,31 +404,43 @@ impl CoverageArgs {
             for result in suite.test_results.values() {
                 let Some(hit_maps) = result.coverage.as_ref() else { continue };
                 for map in hit_maps.0.values() {
-                    if let Some((id, _)) = known_contracts.find_by_deployed_code(&map.bytecode) {
-                        hits.push((id, map, true));
+                    if let Some(zk_contract) =
+                        dual_compiled_contracts.find_by_zk_deployed_bytecode(&map.bytecode)
+                    {
+                        // hits recorded in the zkVM, matched to the contract's solc counterpart
+                        if let Some((id, _)) = known_contracts
+                            .find_by_deployed_code(&zk_contract.evm_deployed_bytecode)
+                        {
+                            hits.push((id, map, HitMapKind::ZkVm));
+                        }
+                    } else if let Some((id, _)) =
+                        known_contracts.find_by_deployed_code(&map.bytecode)
+                    {
+                        hits.push((id, map, HitMapKind::Deployed));
                     } else if let Some((id, _)) =
                         known_contracts.find_by_creation_code(&map.bytecode)
                     {
-                        hits.push((id, map, false));
+                        hits.push((id, map, HitMapKind::Creation));
                     }
                 }
             }
             hits
         });
 
-        for (artifact_id, map, is_deployed_code) in data {
+        for (artifact_id, map, kind) in data {
             if let Some(source_id) =
                 report.get_source_id(artifact_id.version.clone(), artifact_id.source.clone())
             {
-                report.add_hit_map(
-                    &ContractId {
-                        version: artifact_id.version.clone(),
-                        source_id,
-                        contract_name: artifact_id.name.as_str().into(),
-                    },
-                    map,
-                    is_deployed_code,
-                )?;
+                let contract_id = ContractId {
+                    version: artifact_id.version.clone(),
+                    source_id,
+                    contract_name: artifact_id.name.as_str().into(),
+                };
+                match kind {
+                    HitMapKind::Creation => report.add_hit_map(&contract_id, map, false)?,
+                    HitMapKind::Deployed => report.add_hit_map(&contract_id, map, true)?,
+                    HitMapKind::ZkVm => report.add_zk_hit_map(&contract_id, map),
+                }
             }
         }
 
@@ -337,6 +488,17 @@ pub enum CoverageReportKind {
     Bytecode,
 }
 
+/// The code a [`HitMap`](forge::coverage::HitMap) was recorded for.
+#[derive(Clone, Copy, Debug)]
+enum HitMapKind {
+    /// EVM creation code.
+    Creation,
+    /// EVM deployed code.
+    Deployed,
+    /// EraVM bytecode, executed in the zkVM.
+    ZkVm,
+}
+
 /// Helper function that will link references in unlinked bytecode to the 0 address.
 ///
 /// This is needed in order to analyze the bytecode for contracts that use libraries.
@@ -389,6 +551,18 @@ impl ArtifactData {
     }
 }
 
+/// The coverage data of a contract compiled with zksolc, used to anchor the coverage items to the
+/// EraVM instructions recorded in the zkVM.
+///
+/// EraVM bytecode has no solc-style source maps, so only the source lines of the instructions are
+/// known. Branch items can't be anchored and are never covered.
+pub struct ZkArtifactData {
+    /// The ID of the solc counterpart of the contract, whose coverage items are anchored.
+    pub contract_id: ContractId,
+    /// The source locations of the instructions, parsed from the zksolc assembly.
+    pub source_map: EraVmSourceMap,
+}
+
 pub struct BytecodeData {
     source_map: SourceMap,
     bytecode: Bytes,
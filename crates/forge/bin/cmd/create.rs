@@ -24,7 +24,7 @@ use foundry_compilers::{
     zksync::artifact_output::zk::ZkContractArtifact,
 };
 use foundry_wallets::WalletSigner;
-use foundry_zksync_core::convert::ConvertH160;
+use foundry_zksync_core::{convert::ConvertH160, PaymasterParams};
 use serde_json::json;
 use std::{
     borrow::Borrow,
@@ -100,6 +100,7 @@ pub struct ZkSyncData {
     bytecode: Vec<u8>,
     bytecode_hash: H256,
     factory_deps: Vec<Vec<u8>>,
+    paymaster_params: Option<PaymasterParams>,
 }
 
 impl CreateArgs {
@@ -208,7 +209,8 @@ impl CreateArgs {
                 visited_bytecodes.insert(bytecode.clone());
                 visited_bytecodes.into_iter().collect()
             };
-            let zk_data = ZkSyncData { bytecode, bytecode_hash, factory_deps };
+            let paymaster_params = self.opts.compiler.zk.paymaster_params();
+            let zk_data = ZkSyncData { bytecode, bytecode_hash, factory_deps, paymaster_params };
 
             let result = if self.unlocked {
                 // Deploy with unlocked account
@@ -354,9 +356,9 @@ impl CreateArgs {
             config.get_etherscan_config_with_chain(Some(chain.into()))?.map(|c| c.key);
 
         let context = if verify.zksync {
-            CompilerVerificationContext::Solc(verify.resolve_context().await?)
-        } else {
             CompilerVerificationContext::ZkSolc(verify.zk_resolve_context().await?)
+        } else {
+            CompilerVerificationContext::Solc(verify.resolve_context().await?)
         };
 
         verify.verification_provider()?.preflight_check(verify, context).await?;
@@ -560,6 +562,8 @@ impl CreateArgs {
         let estimated_gas = foundry_zksync_core::estimate_gas(
             &deployer.tx,
             zk_data.factory_deps.clone(),
+            zk_data.paymaster_params.clone(),
+            None,
             &provider,
         )
         .await?;
@@ -732,6 +736,7 @@ pub struct Deployer<B, P, T> {
     client: B,
     confs: usize,
     zk_factory_deps: Option<Vec<Vec<u8>>>,
+    zk_paymaster_params: Option<PaymasterParams>,
     _p: PhantomData<P>,
     _t: PhantomData<T>,
 }
@@ -747,6 +752,7 @@ where
             client: self.client.clone(),
             confs: self.confs,
             zk_factory_deps: self.zk_factory_deps.clone(),
+            zk_paymaster_params: self.zk_paymaster_params.clone(),
             _p: PhantomData,
             _t: PhantomData,
         }
@@ -777,6 +783,8 @@ where
         let tx = foundry_zksync_core::new_eip712_transaction(
             self.tx,
             factory_deps,
+            self.zk_paymaster_params,
+            None,
             self.client.borrow(),
             signer.expect("No signer was found"),
         )
@@ -926,6 +934,7 @@ where
             tx,
             confs: 1,
             zk_factory_deps: None,
+            zk_paymaster_params: None,
             _p: PhantomData,
             _t: PhantomData,
         })
@@ -970,6 +979,7 @@ where
             tx,
             confs: 1,
             zk_factory_deps: Some(vec![zk_data.bytecode.clone()]),
+            zk_paymaster_params: zk_data.paymaster_params.clone(),
             _p: PhantomData,
             _t: PhantomData,
         })
@@ -1,9 +1,12 @@
-use alloy_primitives::Address;
-use clap::Parser;
+use alloy_primitives::{hex, Address};
+use clap::{Parser, ValueEnum};
 use comfy_table::{presets::ASCII_MARKDOWN, Table};
 use eyre::{Context, Result};
 use forge::revm::primitives::Eof;
-use foundry_cli::opts::{CompilerArgs, CoreBuildArgs};
+use foundry_cli::{
+    opts::{CompilerArgs, CoreBuildArgs},
+    utils::{remove_zk_contract, LoadConfig},
+};
 use foundry_common::{compile::ProjectCompiler, fmt::pretty_eof};
 use foundry_compilers::{
     artifacts::{
@@ -11,14 +14,17 @@ use foundry_compilers::{
             BytecodeOutputSelection, ContractOutputSelection, DeployedBytecodeOutputSelection,
             EvmOutputSelection, EwasmOutputSelection,
         },
+        zksolc::output_selection::OutputSelectionFlag,
         CompactBytecode, StorageLayout,
     },
     info::ContractInfo,
     utils::canonicalize,
 };
+use foundry_zksync_compiler::DualCompiledContracts;
+use foundry_zksync_core::{convert::ConvertH256, hash_bytecode, H256};
 use once_cell::sync::Lazy;
 use regex::Regex;
-use std::fmt;
+use std::{collections::BTreeMap, fmt, str::FromStr};
 
 /// CLI arguments for `forge inspect`.
 #[derive(Clone, Debug, Parser)]
@@ -28,7 +34,7 @@ pub struct InspectArgs {
 
     /// The contract artifact field to inspect.
     #[arg(value_enum)]
-    pub field: ContractArtifactField,
+    pub field: InspectField,
 
     /// Pretty print the selected field, if supported.
     #[arg(long)]
@@ -45,6 +51,16 @@ impl InspectArgs {
 
         trace!(target: "forge", ?field, ?contract, "running forge inspect");
 
+        if build.compiler.zk.enabled() {
+            return inspect_zk(contract, field, pretty, build)
+        }
+        let field = match field {
+            InspectField::Contract(field) => field,
+            InspectField::Zk(field) => {
+                eyre::bail!("`{field}` is only available for zksolc artifacts, pass `--zksync`")
+            }
+        };
+
         // Map field to ContractOutputSelection
         let mut cos = build.compiler.extra_output;
         if !field.is_default() && !cos.iter().any(|selected| field == *selected) {
@@ -174,6 +190,111 @@ impl InspectArgs {
     }
 }
 
+/// Inspects the zksolc artifact of the contract, whose bytecode and factory dependencies are read
+/// from the [DualCompiledContracts] of the project.
+fn inspect_zk(
+    contract: ContractInfo,
+    field: InspectField,
+    pretty: bool,
+    build: CoreBuildArgs,
+) -> Result<()> {
+    type Caf = ContractArtifactField;
+    type Zkf = ZkContractArtifactField;
+
+    let config = build.try_load_config_emit_warnings()?;
+    let project = config.project()?;
+    let mut zk_project =
+        foundry_zksync_compiler::config_create_project(&config, config.cache, false)?;
+
+    // The EraVM assembly is the only field that is not part of the default zksolc output selection
+    if field == InspectField::Zk(Zkf::EravmAssembly) {
+        if let Some(selection) = zk_project.settings.settings.output_selection.all.as_mut() {
+            selection
+                .per_contract
+                .get_or_insert_with(Default::default)
+                .insert(OutputSelectionFlag::Assembly);
+        }
+    }
+
+    let target_path = if let Some(path) = &contract.path {
+        canonicalize(project.root().join(path))?
+    } else {
+        project.find_contract_path(&contract.name)?
+    };
+    let output =
+        ProjectCompiler::new().quiet(true).files([target_path.clone()]).compile(&project)?;
+    let mut zk_output = ProjectCompiler::new()
+        .quiet(true)
+        .files([target_path.clone()])
+        .zksync_allow_missing_libraries(true)
+        .zksync_compile(&zk_project, config.zksync.avoid_contracts())?;
+    let dual_compiled_contracts =
+        DualCompiledContracts::new(&output, &zk_output, &project.paths, &zk_project.paths);
+
+    let artifact = remove_zk_contract(&mut zk_output, &target_path, &contract.name)?;
+    let dual_compiled_contract = || {
+        artifact
+            .hash
+            .as_deref()
+            .and_then(|hash| H256::from_str(hash).ok())
+            .and_then(|hash| dual_compiled_contracts.find_by_zk_bytecode_hash(hash))
+            .ok_or_else(|| eyre::eyre!("Could not find the zksolc bytecode of `{contract}`"))
+    };
+
+    match field {
+        InspectField::Contract(Caf::Abi) => {
+            let abi =
+                artifact.abi.as_ref().ok_or_else(|| eyre::eyre!("Failed to fetch lossless ABI"))?;
+            if pretty {
+                let source = foundry_cli::utils::abi_to_solidity(abi, &contract.name)?;
+                println!("{source}");
+            } else {
+                print_json(abi)?;
+            }
+        }
+        // EraVM contracts are deployed by their bytecode hash, so the deployed bytecode is the
+        // same as the bytecode
+        InspectField::Contract(Caf::Bytecode | Caf::DeployedBytecode) => {
+            println!("{}", hex::encode_prefixed(&dual_compiled_contract()?.zk_deployed_bytecode));
+        }
+        InspectField::Contract(field) => {
+            eyre::bail!("`{field}` is not supported with `--zksync`")
+        }
+        InspectField::Zk(Zkf::BytecodeHash) => {
+            println!("{}", dual_compiled_contract()?.zk_bytecode_hash.to_b256());
+        }
+        InspectField::Zk(Zkf::FactoryDeps) => {
+            // the factory dependencies of the contract and of its dependencies, by bytecode hash
+            let factory_deps = dual_compiled_contracts
+                .fetch_all_factory_deps(dual_compiled_contract()?)
+                .into_iter()
+                .map(|dep| {
+                    let name = dual_compiled_contracts
+                        .find_by_zk_deployed_bytecode(&dep)
+                        .map(|contract| contract.name.clone())
+                        .unwrap_or_default();
+                    (hash_bytecode(&dep).to_b256().to_string(), name)
+                })
+                .collect::<BTreeMap<_, _>>();
+            print_json(&factory_deps)?;
+        }
+        InspectField::Zk(Zkf::EravmAssembly) => {
+            let Some(assembly) = &artifact.assembly else {
+                eyre::bail!("Could not get EraVM assembly")
+            };
+            println!("{assembly}");
+        }
+        InspectField::Zk(Zkf::ZkSize) => {
+            println!("{}", dual_compiled_contract()?.zk_deployed_bytecode.len());
+        }
+        InspectField::Zk(Zkf::MissingLibraries) => {
+            print_json(&artifact.missing_libraries.clone().unwrap_or_default())?;
+        }
+    }
+
+    Ok(())
+}
+
 pub fn print_storage_layout(storage_layout: Option<&StorageLayout>, pretty: bool) -> Result<()> {
     let Some(storage_layout) = storage_layout else {
         eyre::bail!("Could not get storage layout");
@@ -315,6 +436,71 @@ impl_value_enum! {
     }
 }
 
+/// Contract level output of zksolc which is not part of the solc output
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ZkContractArtifactField {
+    BytecodeHash,
+    FactoryDeps,
+    EravmAssembly,
+    ZkSize,
+    MissingLibraries,
+}
+
+impl_value_enum! {
+    enum ZkContractArtifactField {
+        BytecodeHash     => "bytecodeHash" | "bytecode_hash" | "bytecode-hash" | "bytecodehash",
+        FactoryDeps      => "factoryDeps" | "factory_deps" | "factory-deps" | "factorydeps",
+        EravmAssembly    => "eravmAssembly" | "eravm_assembly" | "eravm-assembly"
+                            | "eravmassembly" | "eravm-asm",
+        ZkSize           => "zkSize" | "zk_size" | "zk-size" | "zksize",
+        MissingLibraries => "missingLibraries" | "missing_libraries" | "missing-libraries"
+                            | "missinglibraries",
+    }
+}
+
+impl fmt::Display for ZkContractArtifactField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The artifact field to inspect, either of the solc or of the zksolc output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InspectField {
+    Contract(ContractArtifactField),
+    Zk(ZkContractArtifactField),
+}
+
+impl ValueEnum for InspectField {
+    fn value_variants<'a>() -> &'a [Self] {
+        static ALL: Lazy<Vec<InspectField>> = Lazy::new(|| {
+            ContractArtifactField::ALL
+                .iter()
+                .copied()
+                .map(InspectField::Contract)
+                .chain(ZkContractArtifactField::ALL.iter().copied().map(InspectField::Zk))
+                .collect()
+        });
+        &ALL
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        match self {
+            Self::Contract(field) => field.to_possible_value(),
+            Self::Zk(field) => field.to_possible_value(),
+        }
+    }
+}
+
+impl fmt::Display for InspectField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Contract(field) => field.fmt(f),
+            Self::Zk(field) => field.fmt(f),
+        }
+    }
+}
+
 impl From<ContractArtifactField> for ContractOutputSelection {
     fn from(field: ContractArtifactField) -> Self {
         type Caf = ContractArtifactField;
@@ -467,4 +653,16 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn zk_fields_do_not_shadow_contract_fields() {
+        for &field in ZkContractArtifactField::ALL {
+            let s = field.as_str();
+            assert_eq!(s.parse::<ZkContractArtifactField>().unwrap(), field);
+            for name in std::iter::once(&s).chain(field.aliases()) {
+                assert!(name.parse::<ContractArtifactField>().is_err(), "{name}");
+                assert_eq!(InspectField::from_str(name, false).unwrap(), InspectField::Zk(field));
+            }
+        }
+    }
 }
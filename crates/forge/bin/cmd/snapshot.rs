@@ -4,6 +4,7 @@ use clap::{builder::RangedU64ValueParser, Parser, ValueHint};
 use eyre::{Context, Result};
 use forge::result::{SuiteTestResult, TestKindReport, TestOutcome};
 use foundry_cli::utils::STATIC_FUZZ_SEED;
+use foundry_zksync_core::vm::ZkGasUsage;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use std::{
@@ -18,8 +19,10 @@ use yansi::Paint;
 
 /// A regex that matches a basic snapshot entry like
 /// `Test:testDeposit() (gas: 58804)`
+/// or a zkVM gas breakdown entry like
+/// `Test:test() (gas: 588, zk gas: 13905, computation: 115, pubdata: 64, storage writes: 2)`
 pub static RE_BASIC_SNAPSHOT_ENTRY: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"(?P<file>(.*?)):(?P<sig>(\w+)\s*\((.*?)\))\s*\(((gas:)?\s*(?P<gas>\d+)|(runs:\s*(?P<runs>\d+),\s*μ:\s*(?P<avg>\d+),\s*~:\s*(?P<med>\d+))|(runs:\s*(?P<invruns>\d+),\s*calls:\s*(?P<calls>\d+),\s*reverts:\s*(?P<reverts>\d+)))\)").unwrap()
+    Regex::new(r"(?P<file>(.*?)):(?P<sig>(\w+)\s*\((.*?)\))\s*\(((gas:)?\s*(?P<gas>\d+)(,\s*zk gas:\s*(?P<zkgas>\d+),\s*computation:\s*(?P<computation>\d+),\s*pubdata:\s*(?P<pubdata>\d+),\s*storage writes:\s*(?P<writes>\d+))?|(runs:\s*(?P<runs>\d+),\s*μ:\s*(?P<avg>\d+),\s*~:\s*(?P<med>\d+))|(runs:\s*(?P<invruns>\d+),\s*calls:\s*(?P<calls>\d+),\s*reverts:\s*(?P<reverts>\d+)))\)").unwrap()
 });
 
 /// CLI arguments for `forge snapshot`.
@@ -191,6 +194,8 @@ impl SnapshotConfig {
 ///
 /// Has the form:
 ///   `<signature>(gas:? 40181)` for normal tests
+///   `<signature>(gas: 40181, zk gas: 139056, computation: 1151, pubdata: 192, storage writes: 2)`
+///   for normal tests with the zkVM gas breakdown
 ///   `<signature>(runs: 256, μ: 40181, ~: 40181)` for fuzz tests
 ///   `<signature>(runs: 256, calls: 40181, reverts: 40181)` for invariant tests
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -210,11 +215,18 @@ impl FromStr for SnapshotEntry {
                 cap.name("file").and_then(|file| {
                     cap.name("sig").and_then(|sig| {
                         if let Some(gas) = cap.name("gas") {
+                            let zk_gas = cap.name("zkgas").map(|zk_gas| ZkGasUsage {
+                                gas_used: zk_gas.as_str().parse().unwrap(),
+                                computation: cap["computation"].parse().unwrap(),
+                                pubdata: cap["pubdata"].parse().unwrap(),
+                                storage_writes: cap["writes"].parse().unwrap(),
+                            });
                             Some(Self {
                                 contract_name: file.as_str().to_string(),
                                 signature: sig.as_str().to_string(),
                                 gas_used: TestKindReport::Unit {
                                     gas: gas.as_str().parse().unwrap(),
+                                    zk_gas,
                                 },
                             })
                         } else if let Some(runs) = cap.name("runs") {
@@ -454,11 +466,34 @@ mod tests {
             SnapshotEntry {
                 contract_name: "Test".to_string(),
                 signature: "deposit()".to_string(),
-                gas_used: TestKindReport::Unit { gas: 7222 }
+                gas_used: TestKindReport::Unit { gas: 7222, zk_gas: None }
             }
         );
     }
 
+    #[test]
+    fn can_parse_zk_snapshot_entry() {
+        let s = "Test:deposit() (gas: 7222, zk gas: 1390562, computation: 11512, pubdata: 192, storage writes: 2)";
+        let entry = SnapshotEntry::from_str(s).unwrap();
+        assert_eq!(
+            entry,
+            SnapshotEntry {
+                contract_name: "Test".to_string(),
+                signature: "deposit()".to_string(),
+                gas_used: TestKindReport::Unit {
+                    gas: 7222,
+                    zk_gas: Some(ZkGasUsage {
+                        gas_used: 1390562,
+                        computation: 11512,
+                        pubdata: 192,
+                        storage_writes: 2,
+                    }),
+                }
+            }
+        );
+        assert_eq!(entry.gas_used.to_string(), s.split_once(' ').unwrap().1);
+    }
+
     #[test]
     fn can_parse_fuzz_snapshot_entry() {
         let s = "Test:deposit() (runs: 256, μ: 100, ~:200)";
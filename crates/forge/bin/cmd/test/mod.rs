@@ -14,6 +14,7 @@ use forge::{
         render_trace_arena, CallTraceDecoderBuilder, InternalTraceMode, TraceKind,
     },
     MultiContractRunner, MultiContractRunnerBuilder, TestFilter, TestOptions, TestOptionsBuilder,
+    LIBRARY_DEPLOYER,
 };
 use foundry_cli::{
     opts::CoreBuildArgs,
@@ -21,7 +22,7 @@ use foundry_cli::{
 };
 use foundry_common::{compile::ProjectCompiler, evm::EvmArgs, fs, shell};
 use foundry_compilers::{
-    artifacts::output_selection::OutputSelection,
+    artifacts::{output_selection::OutputSelection, zksolc::output_selection::OutputSelectionFlag},
     compilers::{multi::MultiCompilerLanguage, CompilerSettings, Language},
     utils::source_files_iter,
     ProjectCompileOutput,
@@ -36,7 +37,7 @@ use foundry_config::{
 };
 use foundry_debugger::Debugger;
 use foundry_evm::traces::identifier::TraceIdentifiers;
-use foundry_zksync_compiler::DualCompiledContracts;
+use foundry_zksync_compiler::{libraries, DualCompiledContracts};
 use regex::Regex;
 use std::{
     collections::{BTreeMap, BTreeSet},
@@ -293,23 +294,57 @@ impl TestArgs {
 
         let output = compiler.compile(&project)?;
 
-        let (zk_output, dual_compiled_contracts) = if config.zksync.should_compile() {
-            let zk_project =
+        let (zk_output, dual_compiled_contracts, zk_libraries) = if config.zksync.should_compile() {
+            let mut zk_project =
                 foundry_zksync_compiler::config_create_project(&config, config.cache, false)?;
 
+            // The debugger maps the EraVM instructions to their sources with the EraVM assembly.
+            if self.debug.is_some() {
+                if let Some(selection) = zk_project.settings.settings.output_selection.all.as_mut()
+                {
+                    selection
+                        .per_contract
+                        .get_or_insert_with(Default::default)
+                        .insert(OutputSelectionFlag::Assembly);
+                }
+            }
+
             let sources_to_compile = self.get_sources_to_compile(&config, &filter)?;
             let zk_compiler = ProjectCompiler::new()
                 .quiet_if(self.json || self.opts.silent)
-                .files(sources_to_compile);
+                .files(sources_to_compile.clone())
+                .zksync_allow_missing_libraries(true);
 
-            let zk_output =
+            let mut zk_output =
                 zk_compiler.zksync_compile(&zk_project, config.zksync.avoid_contracts())?;
+
+            // zksolc links libraries at compile time, so the contracts are compiled again with the
+            // addresses the missing libraries are deployed at before running the tests.
+            let missing_libraries =
+                libraries::get_missing_libraries(&project.paths.root, &zk_output, &[]);
+            let zk_libraries = if missing_libraries.is_empty() {
+                None
+            } else {
+                let zk_libraries = libraries::link_missing_libraries(
+                    missing_libraries,
+                    config.libraries_with_remappings()?,
+                    LIBRARY_DEPLOYER,
+                    0,
+                )?;
+                zk_project.settings.settings.libraries = zk_libraries.libraries.clone();
+                zk_output = ProjectCompiler::new()
+                    .quiet(true)
+                    .files(sources_to_compile)
+                    .zksync_compile(&zk_project, config.zksync.avoid_contracts())?;
+                Some(zk_libraries)
+            };
+
             let dual_compiled_contracts =
                 DualCompiledContracts::new(&output, &zk_output, &project.paths, &zk_project.paths);
 
-            (Some(zk_output), Some(dual_compiled_contracts))
+            (Some(zk_output), Some(dual_compiled_contracts), zk_libraries)
         } else {
-            (None, None)
+            (None, None, None)
         };
 
         // Create test options from general project settings and compiler output.
@@ -349,6 +384,7 @@ impl TestArgs {
 
         // Clone the output only if we actually need it later for the debugger.
         let output_clone = should_debug.then(|| output.clone());
+        let zk_output_clone = should_debug.then(|| zk_output.clone()).flatten();
 
         let config = Arc::new(config);
 
@@ -361,6 +397,7 @@ impl TestArgs {
             .with_fork(evm_opts.get_fork(&config, env.clone()))
             .with_test_options(test_options.clone())
             .enable_isolation(evm_opts.isolate)
+            .with_zk_libraries(zk_libraries)
             .build(
                 project_root,
                 output.clone(),
@@ -405,19 +442,21 @@ impl TestArgs {
                 return Err(eyre::eyre!("no tests were executed"));
             };
 
-            let sources = ContractSources::from_project_output(
+            let mut sources = ContractSources::from_project_output(
                 output_clone.as_ref().unwrap(),
                 project.root(),
                 Some(&libraries),
             )?;
+            if let Some(zk_output) = &zk_output_clone {
+                sources.insert_zk(zk_output, project.root())?;
+            }
 
             // Run the debugger.
-            let mut builder = Debugger::builder()
-                .traces(
-                    test_result.traces.iter().filter(|(t, _)| t.is_execution()).cloned().collect(),
-                )
-                .sources(sources)
-                .breakpoints(test_result.breakpoints.clone());
+            let mut builder =
+                Debugger::builder().sources(sources).breakpoints(test_result.breakpoints.clone());
+            for (_, arena) in test_result.traces.iter().filter(|(t, _)| t.is_execution()) {
+                builder = builder.zk_trace_arena(arena.clone(), test_result.zk_debug_steps.clone());
+            }
             if let Some(decoder) = &outcome.last_run_decoder {
                 builder = builder.decoder(decoder);
             }
@@ -596,6 +635,7 @@ impl TestArgs {
                     gas_report
                         .analyze(result.traces.iter().map(|(_, arena)| arena), &decoder)
                         .await;
+                    gas_report.analyze_zk(&result.zk_gas_usage, &decoder);
 
                     for trace in result.gas_report_traces.iter() {
                         decoder.clear_addresses();
@@ -5,9 +5,10 @@ use crate::{
     traces::{CallTraceArena, CallTraceDecoder, CallTraceNode, DecodedCallData},
 };
 use comfy_table::{presets::ASCII_MARKDOWN, *};
-use foundry_common::{calc, TestFunctionExt};
-use foundry_evm::traces::CallKind;
+use foundry_common::{calc, TestFunctionExt, SELECTOR_LEN};
+use foundry_evm::{inspectors::cheatcodes::ZkCallGasUsage, traces::CallKind};
 use foundry_evm_abi::HARDHAT_CONSOLE_ADDRESS;
+use foundry_zksync_core::vm::ZkGasUsage;
 use serde::{Deserialize, Serialize};
 use std::{
     collections::{BTreeMap, HashSet},
@@ -121,6 +122,43 @@ impl GasReport {
         }
     }
 
+    /// Analyzes the gas usage breakdown of the calls and creates executed in the zkVM.
+    pub fn analyze_zk(&mut self, calls: &[ZkCallGasUsage], decoder: &CallTraceDecoder) {
+        for call in calls {
+            let Some(name) = decoder.contracts.get(&call.address) else { continue };
+            let contract_name = name.rsplit(':').next().unwrap_or(name);
+
+            if !self.should_report(contract_name) {
+                continue;
+            }
+
+            let contract_info = self.contracts.entry(name.to_string()).or_default();
+            if call.is_create {
+                trace!(contract_name, "adding zk create gas info");
+                contract_info.zk_gas = Some(call.usage);
+            } else if let Some(function) = call
+                .data
+                .get(..SELECTOR_LEN)
+                .and_then(|selector| decoder.functions.get(selector))
+                .and_then(|functions| functions.first())
+            {
+                // ignore any test/setup functions
+                if !function.name.test_function_kind().is_known() {
+                    let signature = function.signature();
+                    trace!(contract_name, signature, "adding zk gas info");
+                    contract_info
+                        .functions
+                        .entry(function.name.clone())
+                        .or_default()
+                        .entry(signature)
+                        .or_default()
+                        .zk_calls
+                        .push(call.usage);
+                }
+            }
+        }
+    }
+
     /// Finalizes the gas report by calculating the min, max, mean, and median for each function.
     #[must_use]
     pub fn finalize(mut self) -> Self {
@@ -133,6 +171,7 @@ impl GasReport {
                     func.max = func.calls.last().copied().unwrap_or_default();
                     func.mean = calc::mean(&func.calls);
                     func.median = calc::median_sorted(&func.calls);
+                    func.zk_mean = (!func.zk_calls.is_empty()).then(|| zk_mean(&func.zk_calls));
                 }
             }
         }
@@ -140,6 +179,30 @@ impl GasReport {
     }
 }
 
+/// Calculates the mean of each component of the given zkVM gas usages.
+fn zk_mean(usages: &[ZkGasUsage]) -> ZkGasUsage {
+    let mean = |component: fn(&ZkGasUsage) -> u64| {
+        calc::mean(&usages.iter().map(component).collect::<Vec<_>>())
+    };
+    ZkGasUsage {
+        gas_used: mean(|usage| usage.gas_used),
+        computation: mean(|usage| usage.computation),
+        pubdata: mean(|usage| usage.pubdata),
+        storage_writes: mean(|usage| usage.storage_writes),
+    }
+}
+
+/// Returns the table cells for the given zkVM gas usage, empty if there is none.
+fn zk_cells(usage: Option<&ZkGasUsage>) -> Vec<Cell> {
+    match usage {
+        Some(usage) => [usage.gas_used, usage.computation, usage.pubdata, usage.storage_writes]
+            .into_iter()
+            .map(|value| Cell::new(value.to_string()).fg(Color::Blue))
+            .collect(),
+        None => vec![Cell::new(""); 4],
+    }
+}
+
 impl Display for GasReport {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         for (name, contract) in &self.contracts {
@@ -153,34 +216,66 @@ impl Display for GasReport {
             table.set_header([Cell::new(format!("{name} contract"))
                 .add_attribute(Attribute::Bold)
                 .fg(Color::Green)]);
-            table.add_row([
+            // the zkVM gas breakdown is only shown if any was recorded for the contract
+            let has_zk_gas = contract.zk_gas.is_some() ||
+                contract
+                    .functions
+                    .values()
+                    .flat_map(|sigs| sigs.values())
+                    .any(|gas_info| gas_info.zk_mean.is_some());
+            let zk_header = |prefix: &str| -> Vec<Cell> {
+                if !has_zk_gas {
+                    return Vec::new();
+                }
+                ["zk gas", "computation", "pubdata", "storage writes"]
+                    .map(|name| {
+                        Cell::new(format!("{prefix}{name}"))
+                            .add_attribute(Attribute::Bold)
+                            .fg(Color::Blue)
+                    })
+                    .into()
+            };
+
+            let mut row = vec![
                 Cell::new("Deployment Cost").add_attribute(Attribute::Bold).fg(Color::Cyan),
                 Cell::new("Deployment Size").add_attribute(Attribute::Bold).fg(Color::Cyan),
-            ]);
-            table.add_row([contract.gas.to_string(), contract.size.to_string()]);
+            ];
+            row.extend(zk_header(""));
+            table.add_row(row);
+            let mut row = vec![Cell::new(contract.gas), Cell::new(contract.size)];
+            if has_zk_gas {
+                row.extend(zk_cells(contract.zk_gas.as_ref()));
+            }
+            table.add_row(row);
 
-            table.add_row([
+            let mut row = vec![
                 Cell::new("Function Name").add_attribute(Attribute::Bold).fg(Color::Magenta),
                 Cell::new("min").add_attribute(Attribute::Bold).fg(Color::Green),
                 Cell::new("avg").add_attribute(Attribute::Bold).fg(Color::Yellow),
                 Cell::new("median").add_attribute(Attribute::Bold).fg(Color::Yellow),
                 Cell::new("max").add_attribute(Attribute::Bold).fg(Color::Red),
                 Cell::new("# calls").add_attribute(Attribute::Bold),
-            ]);
+            ];
+            row.extend(zk_header("avg "));
+            table.add_row(row);
             contract.functions.iter().for_each(|(fname, sigs)| {
                 sigs.iter().for_each(|(sig, gas_info)| {
                     // show function signature if overloaded else name
                     let fn_display =
                         if sigs.len() == 1 { fname.clone() } else { sig.replace(':', "") };
 
-                    table.add_row([
+                    let mut row = vec![
                         Cell::new(fn_display).add_attribute(Attribute::Bold),
                         Cell::new(gas_info.min.to_string()).fg(Color::Green),
                         Cell::new(gas_info.mean.to_string()).fg(Color::Yellow),
                         Cell::new(gas_info.median.to_string()).fg(Color::Yellow),
                         Cell::new(gas_info.max.to_string()).fg(Color::Red),
                         Cell::new(gas_info.calls.len().to_string()),
-                    ]);
+                    ];
+                    if has_zk_gas {
+                        row.extend(zk_cells(gas_info.zk_mean.as_ref()));
+                    }
+                    table.add_row(row);
                 })
             });
             writeln!(f, "{table}")?;
@@ -194,6 +289,8 @@ impl Display for GasReport {
 pub struct ContractInfo {
     pub gas: u64,
     pub size: usize,
+    /// Deployment gas usage breakdown, if deployed in the zkVM
+    pub zk_gas: Option<ZkGasUsage>,
     /// Function name -> Function signature -> GasInfo
     pub functions: BTreeMap<String, BTreeMap<String, GasInfo>>,
 }
@@ -205,4 +302,8 @@ pub struct GasInfo {
     pub mean: u64,
     pub median: u64,
     pub max: u64,
+    /// Gas usage breakdown of each call executed in the zkVM
+    pub zk_calls: Vec<ZkGasUsage>,
+    /// Mean gas usage breakdown of the calls executed in the zkVM
+    pub zk_mean: Option<ZkGasUsage>,
 }
@@ -22,7 +22,7 @@ pub mod multi_runner;
 pub use multi_runner::{MultiContractRunner, MultiContractRunnerBuilder};
 
 mod runner;
-pub use runner::ContractRunner;
+pub use runner::{ContractRunner, LIBRARY_DEPLOYER};
 
 mod progress;
 pub mod result;
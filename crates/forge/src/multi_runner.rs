@@ -26,7 +26,10 @@ use foundry_evm::{
     traces::{InternalTraceMode, TraceMode},
 };
 use foundry_linking::{LinkOutput, Linker};
-use foundry_zksync_compiler::DualCompiledContracts;
+use foundry_zksync_compiler::{
+    libraries::{ZkLibraryDeployment, ZkLinkOutput},
+    DualCompiledContracts,
+};
 use rayon::prelude::*;
 use revm::primitives::SpecId;
 
@@ -81,6 +84,8 @@ pub struct MultiContractRunner {
     pub known_contracts: ContractsByArtifact,
     /// Libraries to deploy.
     pub libs_to_deploy: Vec<Bytes>,
+    /// Libraries to deploy through the zkSync `ContractDeployer`.
+    pub zk_libs_to_deploy: Vec<ZkLibraryDeployment>,
     /// Library addresses used to link contracts.
     pub libraries: Libraries,
     /// Dual compiled contracts
@@ -287,6 +292,7 @@ impl MultiContractRunner {
             name: &identifier,
             contract,
             libs_to_deploy: &self.libs_to_deploy,
+            zk_libs_to_deploy: &self.zk_libs_to_deploy,
             executor,
             revert_decoder: &self.revert_decoder,
             initial_balance: self.evm_opts.initial_balance,
@@ -329,6 +335,8 @@ pub struct MultiContractRunnerBuilder {
     pub isolation: bool,
     /// Settings related to fuzz and/or invariant tests
     pub test_options: Option<TestOptions>,
+    /// Libraries linked into the zksolc output, to be deployed before the tests
+    pub zk_libraries: Option<ZkLinkOutput>,
 }
 
 impl MultiContractRunnerBuilder {
@@ -344,6 +352,7 @@ impl MultiContractRunnerBuilder {
             isolation: Default::default(),
             test_options: Default::default(),
             decode_internal: Default::default(),
+            zk_libraries: Default::default(),
         }
     }
 
@@ -392,6 +401,11 @@ impl MultiContractRunnerBuilder {
         self
     }
 
+    pub fn with_zk_libraries(mut self, zk_libraries: Option<ZkLinkOutput>) -> Self {
+        self.zk_libraries = zk_libraries;
+        self
+    }
+
     /// Given an EVM, proceeds to return a runner which is able to execute all tests
     /// against that evm
     pub fn build<C: Compiler>(
@@ -401,13 +415,19 @@ impl MultiContractRunnerBuilder {
         zk_output: Option<ZkProjectCompileOutput>,
         env: revm::primitives::Env,
         evm_opts: EvmOpts,
-        dual_compiled_contracts: DualCompiledContracts,
+        mut dual_compiled_contracts: DualCompiledContracts,
     ) -> Result<MultiContractRunner> {
         let use_zk = zk_output.is_some();
         let mut known_contracts = ContractsByArtifact::default();
         let output = output.with_stripped_file_prefixes(root);
         let linker = Linker::new(root, output.artifact_ids().collect());
 
+        let zk_libs_to_deploy = match (&self.zk_libraries, &zk_output) {
+            (Some(zk_libraries), Some(zk_output)) => zk_libraries.deployments(root, zk_output)?,
+            _ => Vec::new(),
+        };
+        let zk_output = zk_output.map(|zk_output| zk_output.with_stripped_file_prefixes(root));
+
         // Build revert decoder from ABIs of all artifacts.
         let abis = linker
             .contracts
@@ -415,8 +435,10 @@ impl MultiContractRunnerBuilder {
             .filter_map(|(_, contract)| contract.abi.as_ref().map(|abi| abi.borrow()));
         let revert_decoder = RevertDecoder::new().with_abis(abis);
 
+        // Contracts are linked with the zkSync libraries, so that the linked solc bytecodes can be
+        // matched with the zksolc ones.
         let LinkOutput { libraries, libs_to_deploy } = linker.link_with_nonce_or_address(
-            Default::default(),
+            self.zk_libraries.as_ref().map(|zk| zk.libraries.clone()).unwrap_or_default(),
             LIBRARY_DEPLOYER,
             0,
             linker.contracts.keys(),
@@ -448,7 +470,24 @@ impl MultiContractRunnerBuilder {
         if !use_zk {
             known_contracts = ContractsByArtifact::new(linked_contracts);
         } else if let Some(zk_output) = zk_output {
-            let zk_contracts = zk_output.with_stripped_file_prefixes(root).into_artifacts();
+            if self.zk_libraries.is_some() {
+                dual_compiled_contracts.extend_with_linked(
+                    linked_contracts.iter().filter_map(|(id, contract)| {
+                        let bytecode = contract.bytecode.as_ref()?.object.as_bytes()?;
+                        let deployed_bytecode = contract
+                            .deployed_bytecode
+                            .as_ref()?
+                            .bytecode
+                            .as_ref()?
+                            .object
+                            .as_bytes()?;
+                        Some((id, &bytecode[..], &deployed_bytecode[..]))
+                    }),
+                    &zk_output,
+                );
+            }
+
+            let zk_contracts = zk_output.into_artifacts();
             let mut zk_contracts_map = BTreeMap::new();
 
             for (id, contract) in zk_contracts {
@@ -494,6 +533,7 @@ impl MultiContractRunnerBuilder {
             isolation: self.isolation,
             known_contracts,
             libs_to_deploy,
+            zk_libs_to_deploy,
             libraries,
             dual_compiled_contracts,
             use_zk,
@@ -11,8 +11,10 @@ use foundry_evm::{
     coverage::HitMaps,
     executors::{EvmError, RawCallResult},
     fuzz::{CounterExample, FuzzCase, FuzzFixtures, FuzzTestResult},
+    inspectors::cheatcodes::ZkCallGasUsage,
     traces::{CallTraceArena, CallTraceDecoder, TraceKind, Traces},
 };
+use foundry_zksync_core::vm::{EraVmStep, ZkGasUsage};
 use serde::{Deserialize, Serialize};
 use std::{
     collections::{BTreeMap, HashMap},
@@ -370,6 +372,10 @@ pub struct TestResult {
     #[serde(skip)]
     pub coverage: Option<HitMaps>,
 
+    /// Gas usage breakdown of the ZK-VM calls, including the ones made during setup
+    #[serde(skip)]
+    pub zk_gas_usage: Vec<ZkCallGasUsage>,
+
     /// Labeled addresses
     pub labeled_addresses: HashMap<Address, String>,
 
@@ -377,6 +383,11 @@ pub struct TestResult {
 
     /// pc breakpoint char map
     pub breakpoints: Breakpoints,
+
+    /// EraVM steps of the ZK-VM calls made by the test, keyed by the index of their node in the
+    /// execution trace
+    #[serde(skip)]
+    pub zk_debug_steps: HashMap<usize, Vec<EraVmStep>>,
 }
 
 impl fmt::Display for TestResult {
@@ -420,6 +431,7 @@ impl TestResult {
             logs: setup.logs,
             traces: setup.traces,
             coverage: setup.coverage,
+            zk_gas_usage: setup.zk_gas_usage,
             ..Default::default()
         }
     }
@@ -463,8 +475,18 @@ impl TestResult {
         reason: Option<String>,
         raw_call_result: RawCallResult,
     ) -> Self {
-        self.kind =
-            TestKind::Unit { gas: raw_call_result.gas_used.wrapping_sub(raw_call_result.stipend) };
+        let zk_gas = (!raw_call_result.zk_gas_usage.is_empty()).then(|| {
+            raw_call_result.zk_gas_usage.iter().fold(ZkGasUsage::default(), |mut total, call| {
+                total += call.usage;
+                total
+            })
+        });
+        self.kind = TestKind::Unit {
+            gas: raw_call_result.gas_used.wrapping_sub(raw_call_result.stipend),
+            zk_gas,
+        };
+        self.zk_gas_usage.extend(raw_call_result.zk_gas_usage);
+        self.zk_debug_steps = raw_call_result.zk_debug_steps;
 
         // Record logs, labels, traces and merge coverages.
         self.logs.extend(raw_call_result.logs);
@@ -592,7 +614,7 @@ impl TestResult {
 /// Data report by a test.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum TestKindReport {
-    Unit { gas: u64 },
+    Unit { gas: u64, zk_gas: Option<ZkGasUsage> },
     Fuzz { runs: usize, mean_gas: u64, median_gas: u64 },
     Invariant { runs: usize, calls: usize, reverts: usize },
 }
@@ -600,9 +622,16 @@ pub enum TestKindReport {
 impl fmt::Display for TestKindReport {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            Self::Unit { gas } => {
+            Self::Unit { gas, zk_gas: None } => {
                 write!(f, "(gas: {gas})")
             }
+            Self::Unit { gas, zk_gas: Some(zk_gas) } => {
+                write!(
+                    f,
+                    "(gas: {gas}, zk gas: {}, computation: {}, pubdata: {}, storage writes: {})",
+                    zk_gas.gas_used, zk_gas.computation, zk_gas.pubdata, zk_gas.storage_writes
+                )
+            }
             Self::Fuzz { runs, mean_gas, median_gas } => {
                 write!(f, "(runs: {runs}, μ: {mean_gas}, ~: {median_gas})")
             }
@@ -617,7 +646,7 @@ impl TestKindReport {
     /// Returns the main gas value to compare against
     pub fn gas(&self) -> u64 {
         match *self {
-            Self::Unit { gas } => gas,
+            Self::Unit { gas, .. } => gas,
             // We use the median for comparisons
             Self::Fuzz { median_gas, .. } => median_gas,
             // We return 0 since it's not applicable
@@ -630,7 +659,12 @@ impl TestKindReport {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum TestKind {
     /// A unit test.
-    Unit { gas: u64 },
+    Unit {
+        gas: u64,
+        /// The gas usage breakdown of the ZK-VM calls, if recorded
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        zk_gas: Option<ZkGasUsage>,
+    },
     /// A fuzz test.
     Fuzz {
         /// we keep this for the debugger
@@ -645,7 +679,7 @@ pub enum TestKind {
 
 impl Default for TestKind {
     fn default() -> Self {
-        Self::Unit { gas: 0 }
+        Self::Unit { gas: 0, zk_gas: None }
     }
 }
 
@@ -653,7 +687,7 @@ impl TestKind {
     /// The gas consumed by this test
     pub fn report(&self) -> TestKindReport {
         match *self {
-            Self::Unit { gas } => TestKindReport::Unit { gas },
+            Self::Unit { gas, zk_gas } => TestKindReport::Unit { gas, zk_gas },
             Self::Fuzz { first_case: _, runs, mean_gas, median_gas } => {
                 TestKindReport::Fuzz { runs, mean_gas, median_gas }
             }
@@ -680,6 +714,8 @@ pub struct TestSetup {
     pub reason: Option<String>,
     /// Coverage info during setup
     pub coverage: Option<HitMaps>,
+    /// Gas usage breakdown of the ZK-VM calls during setup
+    pub zk_gas_usage: Vec<ZkCallGasUsage>,
     /// Defined fuzz test fixtures
     pub fuzz_fixtures: FuzzFixtures,
 }
@@ -725,6 +761,7 @@ impl TestSetup {
             labeled_addresses,
             reason: None,
             coverage,
+            zk_gas_usage: Vec::new(),
             fuzz_fixtures,
         }
     }
@@ -743,6 +780,7 @@ impl TestSetup {
             labeled_addresses,
             reason: Some(reason),
             coverage: None,
+            zk_gas_usage: Vec::new(),
             fuzz_fixtures: FuzzFixtures::default(),
         }
     }
@@ -31,8 +31,14 @@ use foundry_evm::{
         invariant::{CallDetails, InvariantContract},
         CounterExample, FuzzFixtures,
     },
+    revm::primitives::CreateScheme,
     traces::{load_contracts, TraceKind, TraceMode},
 };
+use foundry_zksync_compiler::libraries::ZkLibraryDeployment;
+use foundry_zksync_core::{
+    convert::ConvertH160, encode_create_params, state::get_balance_storage, ZkTransactionMetadata,
+    CONTRACT_DEPLOYER_ADDRESS,
+};
 use proptest::test_runner::TestRunner;
 use rayon::prelude::*;
 use std::{
@@ -57,6 +63,9 @@ pub struct ContractRunner<'a> {
     pub contract: &'a TestContract,
     /// The libraries that need to be deployed before the contract.
     pub libs_to_deploy: &'a Vec<Bytes>,
+    /// The libraries that need to be deployed through the zkSync `ContractDeployer` before the
+    /// contract.
+    pub zk_libs_to_deploy: &'a Vec<ZkLibraryDeployment>,
     /// The executor used by the runner.
     pub executor: Executor,
     /// Revert decoder. Contains all known errors.
@@ -117,6 +126,41 @@ impl<'a> ContractRunner<'a> {
             }
         }
 
+        // zkSync libraries are deployed in the zkVM, where balances are tracked by a system
+        // contract.
+        let (balance_account, balance_slot) = get_balance_storage(LIBRARY_DEPLOYER);
+        if !self.zk_libs_to_deploy.is_empty() {
+            self.executor.backend_mut().insert_account_storage(
+                balance_account,
+                balance_slot,
+                U256::MAX,
+            )?;
+        }
+        for library in self.zk_libs_to_deploy.iter() {
+            let calldata =
+                encode_create_params(&CreateScheme::Create, library.bytecode_hash, Vec::new());
+            self.executor
+                .setup_zk_tx(ZkTransactionMetadata::new(library.factory_deps.clone(), None));
+            match self
+                .executor
+                .transact_raw(
+                    LIBRARY_DEPLOYER,
+                    CONTRACT_DEPLOYER_ADDRESS.to_address(),
+                    calldata.into(),
+                    U256::ZERO,
+                )?
+                .into_result(Some(self.revert_decoder))
+            {
+                Ok(raw) => {
+                    logs.extend(raw.logs);
+                    traces.extend(raw.traces.map(|traces| (TraceKind::Deployment, traces)));
+                }
+                Err(e) => {
+                    return Ok(TestSetup::from_evm_error_with(e, logs, traces, Default::default()))
+                }
+            }
+        }
+
         let address = self.sender.create(self.executor.get_nonce(self.sender)?);
 
         // Set the contracts initial balance before deployment, so it is available during
@@ -146,6 +190,13 @@ impl<'a> ContractRunner<'a> {
         self.executor.set_balance(self.sender, self.initial_balance)?;
         self.executor.set_balance(CALLER, self.initial_balance)?;
         self.executor.set_balance(LIBRARY_DEPLOYER, self.initial_balance)?;
+        if !self.zk_libs_to_deploy.is_empty() {
+            self.executor.backend_mut().insert_account_storage(
+                balance_account,
+                balance_slot,
+                self.initial_balance,
+            )?;
+        }
 
         self.executor.deploy_create2_deployer()?;
 
@@ -153,9 +204,19 @@ impl<'a> ContractRunner<'a> {
         let result = if call_setup {
             trace!("calling setUp");
             let res = self.executor.setup(None, address, Some(self.revert_decoder));
+            let mut zk_gas_usage = Vec::new();
             let (setup_logs, setup_traces, labeled_addresses, reason, coverage) = match res {
-                Ok(RawCallResult { traces, labels, logs, coverage, deployments, .. }) => {
+                Ok(RawCallResult {
+                    traces,
+                    labels,
+                    logs,
+                    coverage,
+                    deployments,
+                    zk_gas_usage: setup_zk_gas_usage,
+                    ..
+                }) => {
                     zk_setup_deployments.extend(deployments);
+                    zk_gas_usage = setup_zk_gas_usage;
                     trace!(%address, "successfully called setUp");
                     (logs, traces, labels, None, coverage)
                 }
@@ -181,6 +242,7 @@ impl<'a> ContractRunner<'a> {
                 labeled_addresses,
                 reason,
                 coverage,
+                zk_gas_usage,
                 fuzz_fixtures: self.fuzz_fixtures(address),
             }
         } else {
@@ -47,6 +47,30 @@ forgetest_init!(test_zk_build_sizes, |prj, cmd| {
     assert!(pattern.is_match(&stdout), "Unexpected size output:\n{stdout}");
 });
 
+// tests that contracts without bytecode are not checked against the zksync limits
+forgetest_init!(test_zk_build_sizes_with_interface, |prj, cmd| {
+    prj.add_source(
+        "ICounter",
+        r"
+interface ICounter {
+    function increment() external;
+}
+
+abstract contract AbstractCounter is ICounter {
+    uint256 public number;
+}
+",
+    )
+    .unwrap();
+
+    cmd.args(["build", "--sizes", "--zksync", "--evm-version", "shanghai"]);
+    let stdout = cmd.stdout_lossy();
+    let pattern = Regex::new(r"\|\s*ICounter\s*\|\s*0\s*\|").unwrap();
+
+    assert!(pattern.is_match(&stdout), "Unexpected size output:\n{stdout}");
+    assert!(stdout.contains("AbstractCounter"), "Unexpected size output:\n{stdout}");
+});
+
 // tests that skip key in config can be used to skip non-compilable contract
 forgetest_init!(test_can_skip_contract, |prj, cmd| {
     prj.add_source(
@@ -1605,6 +1605,41 @@ forgetest_init!(can_inspect_ir_optimized, |_prj, cmd| {
     cmd.assert_success();
 });
 
+// checks `forge inspect --zksync` prints the zksolc artifact fields
+forgetest_init!(can_inspect_zk_fields, |_prj, cmd| {
+    cmd.args(["inspect", "--zksync", TEMPLATE_CONTRACT, "zkSize"]).assert_success().stdout_eq(
+        str![[r#"
+800
+
+"#]],
+    );
+
+    cmd.forge_fuse().args(["inspect", "--zksync", TEMPLATE_CONTRACT, "bytecodeHash"]);
+    let hash = cmd.stdout_lossy();
+    assert!(hash.trim().starts_with("0x01"), "unexpected bytecode hash: {hash}");
+    assert_eq!(hash.trim().len(), 66, "unexpected bytecode hash: {hash}");
+
+    cmd.forge_fuse().args(["inspect", "--zksync", TEMPLATE_CONTRACT, "factoryDeps"]);
+    cmd.assert_success().stdout_eq(str![[r#"
+{}
+
+"#]]);
+
+    cmd.forge_fuse().args(["inspect", "--zksync", TEMPLATE_CONTRACT, "eravmAssembly"]);
+    cmd.assert_non_empty_stdout();
+
+    cmd.forge_fuse().args(["inspect", TEMPLATE_CONTRACT, "zkSize"]);
+    let err = cmd.stderr_lossy();
+    assert!(err.contains("only available for zksolc artifacts"), "{err}");
+
+    // the bytecode is the zksolc one, which is hashed to the bytecode hash
+    cmd.forge_fuse().args(["inspect", "--zksync", TEMPLATE_CONTRACT, "bytecode"]);
+    let bytecode = hex::decode(cmd.stdout_lossy().trim()).unwrap();
+    assert_eq!(bytecode.len(), 800);
+    let bytecode_hash = foundry_zksync_core::hash_bytecode(&bytecode);
+    assert_eq!(hex::encode_prefixed(bytecode_hash), hash.trim());
+});
+
 // checks forge bind works correctly on the default project
 forgetest_init!(can_bind, |_prj, cmd| {
     cmd.arg("bind");
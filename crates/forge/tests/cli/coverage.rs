@@ -898,3 +898,59 @@ contract FooTest is DSTest {
 "#]],
     );
 });
+
+forgetest!(test_zk_coverage, |prj, cmd| {
+    prj.insert_ds_test();
+    prj.add_source(
+        "AContract.sol",
+        r#"
+contract AContract {
+    int public i;
+
+    function init() public {
+        i = 0;
+    }
+
+    function foo() public {
+        i = 1;
+    }
+}
+    "#,
+    )
+    .unwrap();
+
+    prj.add_source(
+        "AContractTest.sol",
+        r#"
+import "./test.sol";
+import {AContract} from "./AContract.sol";
+
+contract AContractTest is DSTest {
+    AContract a;
+
+    function setUp() public {
+        a = new AContract();
+        a.init();
+    }
+
+    function testFoo() public {
+        a.foo();
+    }
+}
+    "#,
+    )
+    .unwrap();
+
+    // Assert 100% coverage of the contract executed in the zkVM.
+    cmd.arg("coverage").args(["--zksync", "--summary"]).assert_success().stdout_eq(str![[r#"
+...
+Warning! Branch coverage is not supported in the zkVM, the branches of the contracts executed in the zkVM are reported as not covered.
+Lines, statements and functions executed in the zkVM are only tracked by source line, so the items starting on the same line are reported as covered together.
+...
+| File              | % Lines       | % Statements  | % Branches    | % Funcs       |
+|-------------------|---------------|---------------|---------------|---------------|
+| src/AContract.sol | 100.00% (2/2) | 100.00% (2/2) | 100.00% (0/0) | 100.00% (2/2) |
+| Total             | 100.00% (2/2) | 100.00% (2/2) | 100.00% (0/0) | 100.00% (2/2) |
+
+"#]]);
+});
@@ -1555,3 +1555,87 @@ contract DeployScript is Script {
     let transactions = transactions.transactions;
     assert_eq!(transactions.len(), 3);
 });
+
+// tests that libraries missing from the zksolc output are deployed by the sender and linked
+forgetest_async!(test_zk_can_deploy_and_link_libraries_in_script, |prj, cmd| {
+    #[derive(serde::Deserialize, Debug)]
+    #[allow(dead_code)]
+    struct ZkTransactions {
+        transactions: Vec<serde_json::Value>,
+        libraries: Vec<String>,
+    }
+
+    let node = foundry_test_utils::ZkSyncNode::start();
+
+    cmd.args(["init", "--force"]).arg(prj.root());
+    cmd.assert_non_empty_stdout();
+    cmd.forge_fuse();
+
+    prj.add_script(
+        "Deploy.s.sol",
+        r#"
+pragma solidity ^0.8.18;
+
+import {Script} from "forge-std/Script.sol";
+
+library Adder {
+    function add(uint256 a, uint256 b) external pure returns (uint256) {
+        return a + b;
+    }
+}
+
+library Doubler {
+    function double(uint256 a) external pure returns (uint256) {
+        return Adder.add(a, a);
+    }
+}
+
+contract UsesLibraries {
+    function quadruple(uint256 a) external pure returns (uint256) {
+        return Doubler.double(Doubler.double(a));
+    }
+}
+
+contract DeployScript is Script {
+    function run() external {
+        vm.startBroadcast();
+        UsesLibraries target = new UsesLibraries();
+        vm.stopBroadcast();
+
+        require(target.quadruple(3) == 12, "libraries not linked");
+    }
+}
+   "#,
+    )
+    .unwrap();
+
+    cmd.arg("script").args([
+        "--zksync",
+        "DeployScript",
+        "--broadcast",
+        "--private-key",
+        "0x3d3cbc973389cb26f657686445bcc75662b415b656078503592ac8c1abb8810e",
+        "--chain",
+        "260",
+        "--gas-estimate-multiplier",
+        "310",
+        "--rpc-url",
+        node.url().as_str(),
+        "--slow",
+        "--evm-version",
+        "shanghai",
+    ]);
+
+    assert!(cmd.stdout_lossy().contains("ONCHAIN EXECUTION COMPLETE & SUCCESSFUL"));
+
+    let run_latest = foundry_common::fs::json_files(prj.root().join("broadcast").as_path())
+        .find(|file| file.ends_with("run-latest.json"))
+        .expect("No broadcast artifacts");
+
+    let content = foundry_common::fs::read_to_string(run_latest).unwrap();
+
+    // both libraries are deployed before the contract using them
+    let transactions: ZkTransactions = serde_json::from_str(&content).unwrap();
+    assert_eq!(transactions.transactions.len(), 3);
+    assert_eq!(transactions.libraries.len(), 2);
+});
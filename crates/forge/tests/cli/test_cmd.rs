@@ -1053,3 +1053,120 @@ contract CallEmptyCode is Test {
     let output = cmd.stdout_lossy();
     assert!(output.contains("call may fail or behave unexpectedly due to empty code"));
 });
+
+// tests that the zkVM gas breakdown is reported in gas reports and snapshots
+forgetest_async!(test_zk_gas_breakdown, |prj, cmd| {
+    foundry_test_utils::util::initialize(prj.root());
+
+    prj.add_test(
+        "GasBreakdown.t.sol",
+        r#"
+import "forge-std/Test.sol";
+
+contract Counter {
+    uint256 public number;
+
+    function increment() public {
+        number++;
+    }
+}
+
+contract GasBreakdownTest is Test {
+    Counter counter;
+
+    function setUp() public {
+        counter = new Counter();
+    }
+
+    function testIncrement() public {
+        counter.increment();
+        assertEq(counter.number(), 1);
+    }
+}
+"#,
+    )
+    .unwrap();
+
+    cmd.args([
+        "test",
+        "--zksync",
+        "--evm-version",
+        "shanghai",
+        "--zk-gas-breakdown",
+        "--gas-report",
+    ]);
+    let output = cmd.stdout_lossy();
+    assert!(output.contains("[PASS] testIncrement()"));
+    assert!(output.contains("avg storage writes"));
+
+    cmd.forge_fuse().args([
+        "snapshot",
+        "--zksync",
+        "--evm-version",
+        "shanghai",
+        "--zk-gas-breakdown",
+    ]);
+    cmd.assert_non_empty_stdout();
+
+    let snapshot = std::fs::read_to_string(prj.root().join(".gas-snapshot")).unwrap();
+    let re = regex::Regex::new(
+        r"GasBreakdownTest:testIncrement\(\) \(gas: \d+, zk gas: \d+, computation: \d+, pubdata: \d+, storage writes: \d+\)",
+    )
+    .unwrap();
+    assert!(re.is_match(&snapshot), "{snapshot}");
+
+    // the snapshot can be read back
+    cmd.arg("--check");
+    cmd.assert_success();
+});
+
+// tests that libraries missing from the zksolc output are deployed and linked before the tests
+forgetest_async!(test_zk_can_deploy_and_link_libraries, |prj, cmd| {
+    foundry_test_utils::util::initialize(prj.root());
+
+    prj.add_source(
+        "Libraries.sol",
+        r#"
+library Adder {
+    function add(uint256 a, uint256 b) external pure returns (uint256) {
+        return a + b;
+    }
+}
+
+// depends on `Adder`, so it is deployed in a later batch
+library Doubler {
+    function double(uint256 a) external pure returns (uint256) {
+        return Adder.add(a, a);
+    }
+}
+
+contract UsesLibraries {
+    function quadruple(uint256 a) external pure returns (uint256) {
+        return Doubler.double(Doubler.double(a));
+    }
+}
+"#,
+    )
+    .unwrap();
+
+    prj.add_test(
+        "Libraries.t.sol",
+        r#"
+import "forge-std/Test.sol";
+import "src/Libraries.sol";
+
+contract LibrariesTest is Test {
+    function testLibrariesAreLinked() public {
+        UsesLibraries target = new UsesLibraries();
+        assertEq(target.quadruple(3), 12);
+    }
+}
+"#,
+    )
+    .unwrap();
+
+    cmd.args(["test", "--zksync", "--evm-version", "shanghai"]);
+
+    let output = cmd.stdout_lossy();
+    assert!(output.contains("[PASS] testLibrariesAreLinked()"));
+});
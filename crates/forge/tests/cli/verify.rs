@@ -1,13 +1,22 @@
-//! Contains various tests for checking forge commands related to verifying contracts on Etherscan
-//! and Sourcify.
+//! Contains various tests for checking forge commands related to verifying contracts on Etherscan,
+//! Sourcify and the zkSync block explorer.
 
 use crate::utils::{self, EnvExternalities};
+use axum::{
+    extract::State,
+    routing::{get, post},
+    Json, Router,
+};
 use foundry_common::retry::Retry;
 use foundry_test_utils::{
-    forgetest,
-    util::{TestCommand, TestProject},
+    forgetest, forgetest_async,
+    util::{self, TestCommand, TestProject},
+    ZkSyncNode,
+};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
 };
-use std::time::Duration;
 
 /// Adds a `Unique` contract to the source directory of the project that can be imported as
 /// `import {Unique} from "./unique.sol";`
@@ -235,3 +244,128 @@ forgetest!(can_create_verify_random_contract_sepolia, |prj, cmd| {
 forgetest!(can_guess_constructor_args, |prj, cmd| {
     guess_constructor_args(EnvExternalities::goerli(), prj, cmd);
 });
+
+type ZkVerificationRequests = Arc<Mutex<Vec<serde_json::Value>>>;
+
+/// Spawns a mock of the zkSync block explorer verification API, which records the submitted
+/// requests and reports every verification as successful.
+async fn spawn_zk_verifier() -> (String, ZkVerificationRequests) {
+    let requests = ZkVerificationRequests::default();
+    let app = Router::new()
+        .route(
+            "/contract_verification",
+            post(
+                |State(requests): State<ZkVerificationRequests>,
+                 Json(request): Json<serde_json::Value>| async move {
+                    let mut requests = requests.lock().unwrap();
+                    requests.push(request);
+                    Json(requests.len())
+                },
+            ),
+        )
+        .route(
+            "/contract_verification/:id",
+            get(|| async { Json(serde_json::json!({ "status": "successful" })) }),
+        )
+        .with_state(requests.clone());
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/contract_verification", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    (url, requests)
+}
+
+// tests `verify-contract --zksync --verifier zksync` against a mock of the zkSync block explorer
+forgetest_async!(test_zk_can_verify_with_zksync_verifier, |prj, cmd| {
+    let (verifier_url, requests) = spawn_zk_verifier().await;
+
+    prj.add_source(
+        "Counter.sol",
+        r#"
+contract Counter {
+    uint256 public number;
+
+    constructor(uint256 initial) {
+        number = initial;
+    }
+}
+"#,
+    )
+    .unwrap();
+
+    let constructor_args = format!("{:064x}", 1);
+    cmd.arg("verify-contract").root_arg().args([
+        "0x0000000000000000000000000000000000000123",
+        "src/Counter.sol:Counter",
+        "--zksync",
+        "--compiler-version",
+        "0.8.24",
+        "--constructor-args",
+        &constructor_args,
+        "--verifier",
+        "zksync",
+        "--verifier-url",
+        &verifier_url,
+        "--watch",
+    ]);
+
+    let out = cmd.stdout_lossy();
+    assert!(out.contains("Verification ID: `1`"), "{out}");
+    assert!(out.contains("Contract successfully verified"), "{out}");
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    let request = &requests[0];
+    assert_eq!(request["contractAddress"], "0x0000000000000000000000000000000000000123");
+    assert_eq!(request["contractName"], "src/Counter.sol:Counter");
+    assert_eq!(request["codeFormat"], "solidity-standard-json-input");
+    assert!(request["compilerSolcVersion"].as_str().unwrap().starts_with("zkVM-0.8.24-"));
+    assert!(request["compilerZksolcVersion"].as_str().unwrap().starts_with('v'));
+    assert_eq!(request["constructorArguments"], format!("0x{constructor_args}"));
+    assert_eq!(request["optimizationUsed"], true);
+    assert_eq!(request["isSystem"], false);
+    assert!(request["sourceCode"]["sources"].is_object());
+});
+
+// tests `create --zk-startup && verify-bytecode --zksync` against a local zkSync node
+forgetest_async!(test_zk_can_verify_bytecode, |prj, cmd| {
+    util::initialize(prj.root());
+    prj.add_source("ERC20.sol", include_str!("../../../../testdata/zk/ERC20.sol")).unwrap();
+
+    let node = ZkSyncNode::start();
+    let url = node.url();
+
+    let private_key =
+        ZkSyncNode::rich_wallets().next().map(|(_, pk, _)| pk).expect("No rich wallets available");
+
+    cmd.forge_fuse().args([
+        "create",
+        "--zk-startup",
+        "./src/ERC20.sol:MyToken",
+        "--rpc-url",
+        url.as_str(),
+        "--private-key",
+        private_key,
+    ]);
+
+    let (stdout, _) = cmd.output_lossy();
+    let address = stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("Deployed to: "))
+        .expect("contract was not deployed")
+        .trim()
+        .to_string();
+
+    cmd.forge_fuse().args([
+        "verify-bytecode",
+        "--zksync",
+        address.as_str(),
+        "src/ERC20.sol:MyToken",
+        "--rpc-url",
+        url.as_str(),
+    ]);
+
+    let (stdout, _) = cmd.output_lossy();
+    assert!(stdout.contains("Runtime code matched with status full"), "{stdout}");
+});
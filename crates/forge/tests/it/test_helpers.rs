@@ -31,7 +31,7 @@ use std::{
     env, fmt,
     io::Write,
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{Arc, OnceLock},
 };
 
 type ZkProject = Project<ZkSolcCompiler, ZkArtifactOutput>;
@@ -86,17 +86,25 @@ impl ForgeTestProfile {
         self.config().project().expect("Failed to build project")
     }
 
-    pub fn zk_project(&self) -> ZkProject {
-        let zk_config = self.zk_config();
+    /// Build the zksolc project for the given zksync test config.
+    ///
+    /// Project output files are written to testdata/zk/zkout, or to
+    /// testdata/zk/zkout-eravm-extensions if the EraVM extensions are enabled.
+    pub fn zk_project(zk_config: &Config) -> ZkProject {
+        let (zkout, cache) = if zk_config.zksync.enable_eravm_extensions {
+            ("zkout-eravm-extensions", "cache-eravm-extensions")
+        } else {
+            ("zkout", "cache")
+        };
         let mut zk_project =
-            foundry_zksync_compiler::config_create_project(&zk_config, zk_config.cache, false)
+            foundry_zksync_compiler::config_create_project(zk_config, zk_config.cache, false)
                 .expect("failed creating zksync project");
-        zk_project.paths.artifacts = zk_config.root.as_ref().join("zk").join("zkout");
+        zk_project.paths.artifacts = zk_config.root.as_ref().join("zk").join(zkout);
         zk_project.paths.cache = zk_config
             .root
             .as_ref()
             .join("zk")
-            .join("cache")
+            .join(cache)
             .join(ZKSYNC_SOLIDITY_FILES_CACHE_FILENAME);
 
         zk_project
@@ -212,6 +220,16 @@ impl ForgeTestProfile {
 
         zk_config
     }
+
+    /// Build [Config] for zksync test profile with the EraVM extensions enabled.
+    ///
+    /// Custom accounts need the system calls enabled by the extensions to manage their nonces and
+    /// deploy contracts.
+    pub fn zk_eravm_extensions_config(&self) -> Config {
+        let mut zk_config = self.zk_config();
+        zk_config.zksync.enable_eravm_extensions = true;
+        zk_config
+    }
 }
 
 /// Container for test data for zkSync specific tests.
@@ -223,6 +241,20 @@ pub struct ZkTestData {
     pub zk_output: ZkProjectCompileOutput,
 }
 
+impl ZkTestData {
+    /// Compiles the zksync test data with the given config.
+    fn new(zk_config: Config) -> Self {
+        let zk_project = ForgeTestProfile::zk_project(&zk_config);
+
+        let mut project = zk_config.project().expect("failed obtaining project");
+        let output = get_compiled(&mut project);
+        let zk_output = get_zk_compiled(&zk_project);
+        let dual_compiled_contracts =
+            DualCompiledContracts::new(&output, &zk_output, &project.paths, &zk_project.paths);
+        Self { dual_compiled_contracts, zk_config, zk_project, output, zk_output }
+    }
+}
+
 /// Container for test data for a specific test profile.
 pub struct ForgeTestData {
     pub project: Project,
@@ -232,6 +264,8 @@ pub struct ForgeTestData {
     pub config: Config,
     pub profile: ForgeTestProfile,
     pub zk_test_data: ZkTestData,
+    /// The zksync test data compiled with the EraVM extensions enabled, compiled on first use.
+    zk_eravm_extensions_test_data: OnceLock<ZkTestData>,
 }
 
 impl ForgeTestData {
@@ -247,19 +281,24 @@ impl ForgeTestData {
         let config = profile.config();
         let evm_opts = profile.evm_opts();
 
-        let zk_test_data = {
-            let zk_config = profile.zk_config();
-            let zk_project = profile.zk_project();
-
-            let mut project = zk_config.project().expect("failed obtaining project");
-            let output = get_compiled(&mut project);
-            let zk_output = get_zk_compiled(&zk_project);
-            let dual_compiled_contracts =
-                DualCompiledContracts::new(&output, &zk_output, &project.paths, &zk_project.paths);
-            ZkTestData { dual_compiled_contracts, zk_config, zk_project, output, zk_output }
-        };
+        let zk_test_data = ZkTestData::new(profile.zk_config());
+
+        Self {
+            project,
+            output,
+            test_opts,
+            evm_opts,
+            config,
+            profile,
+            zk_test_data,
+            zk_eravm_extensions_test_data: OnceLock::new(),
+        }
+    }
 
-        Self { project, output, test_opts, evm_opts, config, profile, zk_test_data }
+    /// Returns the zksync test data compiled with the EraVM extensions enabled.
+    pub fn zk_eravm_extensions_test_data(&self) -> &ZkTestData {
+        self.zk_eravm_extensions_test_data
+            .get_or_init(|| ZkTestData::new(self.profile.zk_eravm_extensions_config()))
     }
 
     /// Builds a base runner
@@ -292,6 +331,15 @@ impl ForgeTestData {
         self.runner_with_zksync_config(zk_config)
     }
 
+    /// Builds a non-tracing zksync runner with the EraVM extensions enabled, for the tests of
+    /// custom accounts.
+    pub fn runner_zksync_with_eravm_extensions(&self) -> MultiContractRunner {
+        let mut zk_config = self.zk_eravm_extensions_test_data().zk_config.clone();
+        zk_config.fs_permissions =
+            FsPermissions::new(vec![PathPermission::read_write(manifest_root())]);
+        self.runner_with_zksync_config(zk_config)
+    }
+
     /// Builds a non-tracing runner
     pub fn runner_with_config(&self, mut config: Config) -> MultiContractRunner {
         config.rpc_endpoints = rpc_endpoints();
@@ -331,7 +379,13 @@ impl ForgeTestData {
         // no prompt testing
         zk_config.prompt_timeout = 0;
 
-        let root = self.zk_test_data.zk_project.root();
+        // the test data is compiled with the EraVM extensions only if they are enabled
+        let zk_test_data = if zk_config.zksync.enable_eravm_extensions {
+            self.zk_eravm_extensions_test_data()
+        } else {
+            &self.zk_test_data
+        };
+        let root = zk_test_data.zk_project.root();
         let mut opts = self.evm_opts.clone();
 
         if zk_config.isolate {
@@ -339,9 +393,9 @@ impl ForgeTestData {
         }
 
         let env = opts.local_evm_env();
-        let output = self.zk_test_data.output.clone();
-        let zk_output = self.zk_test_data.zk_output.clone();
-        let dual_compiled_contracts = self.zk_test_data.dual_compiled_contracts.clone();
+        let output = zk_test_data.output.clone();
+        let zk_output = zk_test_data.zk_output.clone();
+        let dual_compiled_contracts = zk_test_data.dual_compiled_contracts.clone();
         let mut test_opts = self.test_opts.clone();
         test_opts.fuzz.no_zksync_reserved_addresses = zk_config.fuzz.no_zksync_reserved_addresses;
         let sender = zk_config.sender;
@@ -0,0 +1,29 @@
+//! Forge tests for zksync custom accounts.
+
+use crate::{config::*, test_helpers::TEST_DATA_DEFAULT};
+use alloy_primitives::{address, Bytes};
+use forge::revm::primitives::SpecId;
+use foundry_config::fs_permissions::PathPermission;
+use foundry_test_utils::Filter;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_zk_custom_account() {
+    let runner = TEST_DATA_DEFAULT.runner_zksync_with_eravm_extensions();
+    let filter = Filter::new(".*", "ZkCustomAccountTest", ".*");
+
+    TestConfig::with_filter(runner, filter).evm_spec(SpecId::SHANGHAI).run().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_zk_custom_account_from_config() {
+    let mut zk_config = TEST_DATA_DEFAULT.zk_eravm_extensions_test_data().zk_config.clone();
+    zk_config
+        .fs_permissions
+        .add(PathPermission::read("./zk/zkout-eravm-extensions/CustomAccount.t.sol"));
+    zk_config.zksync.custom_account = Some(address!("0000000000000000000000000000000000c0ffee"));
+    zk_config.zksync.custom_account_signature = Some(Bytes::from_static(b"valid"));
+    let runner = TEST_DATA_DEFAULT.runner_with_zksync_config(zk_config);
+    let filter = Filter::new(".*", "ZkCustomAccountConfigTest", ".*");
+
+    TestConfig::with_filter(runner, filter).evm_spec(SpecId::SHANGHAI).run().await;
+}
@@ -83,6 +83,16 @@ async fn test_zk_cheat_mock_calls() {
     TestConfig::with_filter(runner, filter).evm_spec(SpecId::SHANGHAI).run().await;
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_zk_deploy_code() {
+    let mut zk_config = TEST_DATA_DEFAULT.zk_test_data.zk_config.clone();
+    zk_config.fs_permissions.add(PathPermission::read("./zk/zkout/ConstantNumber.sol"));
+    let runner = TEST_DATA_DEFAULT.runner_with_zksync_config(zk_config);
+    let filter = Filter::new(".*", "ZkDeployCodeTest", ".*");
+
+    TestConfig::with_filter(runner, filter).evm_spec(SpecId::SHANGHAI).run().await;
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_zk_cheat_works_after_fork() {
     let runner = TEST_DATA_DEFAULT.runner_zksync();
@@ -106,6 +116,14 @@ async fn test_zk_can_mock_modifiers() {
     TestConfig::with_filter(runner, filter).evm_spec(SpecId::SHANGHAI).run().await;
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_zk_mock_calls_in_zkvm() {
+    let runner = TEST_DATA_DEFAULT.runner_zksync();
+    let filter = Filter::new(".*", "ZkMockCallsTest", ".*");
+
+    TestConfig::with_filter(runner, filter).evm_spec(SpecId::SHANGHAI).run().await;
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_zk_record_logs() {
     let runner = TEST_DATA_DEFAULT.runner_zksync();
@@ -121,3 +139,35 @@ async fn test_zk_cheatcodes_in_zkvm() {
 
     TestConfig::with_filter(runner, filter).evm_spec(SpecId::SHANGHAI).run().await;
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_zk_system_storage_cheatcodes() {
+    let runner = TEST_DATA_DEFAULT.runner_zksync();
+    let filter = Filter::new(".*", "ZkSystemStorageTest", ".*");
+
+    TestConfig::with_filter(runner, filter).evm_spec(SpecId::SHANGHAI).run().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_zk_compute_address_cheatcodes() {
+    let runner = TEST_DATA_DEFAULT.runner_zksync();
+    let filter = Filter::new(".*", "ZkComputeAddressTest", ".*");
+
+    TestConfig::with_filter(runner, filter).evm_spec(SpecId::SHANGHAI).run().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_zk_l2_to_l1_messages() {
+    let runner = TEST_DATA_DEFAULT.runner_zksync();
+    let filter = Filter::new(".*", "ZkL2ToL1MessagesTest", ".*");
+
+    TestConfig::with_filter(runner, filter).evm_spec(SpecId::SHANGHAI).run().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_zk_execute_l1_tx() {
+    let runner = TEST_DATA_DEFAULT.runner_zksync();
+    let filter = Filter::new(".*", "ZkL1TxTest", ".*");
+
+    TestConfig::with_filter(runner, filter).evm_spec(SpecId::SHANGHAI).run().await;
+}
@@ -1,4 +1,5 @@
 //! Forge tests for zkysnc functionality.
+mod account;
 mod basic;
 mod cheats;
 mod contracts;
@@ -11,5 +12,9 @@ mod invariant;
 mod logs;
 mod nft;
 mod ownership;
+mod paymaster;
+mod prank;
 mod proxy;
 mod repros;
+mod system_env;
+mod traces;
@@ -0,0 +1,39 @@
+//! Forge tests for the zksync system environment.
+
+use crate::{config::*, test_helpers::TEST_DATA_DEFAULT};
+use forge::revm::primitives::SpecId;
+use foundry_config::fs_permissions::PathPermission;
+use foundry_test_utils::Filter;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_zk_protocol_version() {
+    let mut zk_config = TEST_DATA_DEFAULT.zk_test_data.zk_config.clone();
+    zk_config.zksync.protocol_version = Some(24);
+    let runner = TEST_DATA_DEFAULT.runner_with_zksync_config(zk_config);
+    let filter = Filter::new(".*", "ZkProtocolVersionTest", ".*");
+
+    TestConfig::with_filter(runner, filter).evm_spec(SpecId::SHANGHAI).run().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_zk_unsupported_protocol_version_is_ignored() {
+    let mut zk_config = TEST_DATA_DEFAULT.zk_test_data.zk_config.clone();
+    zk_config.zksync.protocol_version = Some(23);
+    let runner = TEST_DATA_DEFAULT.runner_with_zksync_config(zk_config);
+    let filter = Filter::new(".*", "ZkProtocolVersionTest", ".*");
+
+    TestConfig::with_filter(runner, filter).evm_spec(SpecId::SHANGHAI).run().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_zk_system_contracts_security() {
+    let mut zk_config = TEST_DATA_DEFAULT.zk_eravm_extensions_test_data().zk_config.clone();
+    zk_config
+        .fs_permissions
+        .add(PathPermission::read("./zk/zkout-eravm-extensions/CustomAccount.t.sol"));
+    zk_config.zksync.system_contracts_security = true;
+    let runner = TEST_DATA_DEFAULT.runner_with_zksync_config(zk_config);
+    let filter = Filter::new(".*", "ZkSystemContractsSecurityTest", ".*");
+
+    TestConfig::with_filter(runner, filter).evm_spec(SpecId::SHANGHAI).run().await;
+}
@@ -0,0 +1,70 @@
+//! Forge tests for zksync traces.
+
+use crate::{config::*, test_helpers::TEST_DATA_DEFAULT};
+use forge::{result::TestStatus, revm::primitives::SpecId};
+use foundry_evm::traces::{CallKind, TraceKind};
+use foundry_test_utils::Filter;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_zk_traces_work_during_call() {
+    let runner = TEST_DATA_DEFAULT.runner_zksync();
+    let filter = Filter::new("testZkTraceOutputDuringCall", "ZkTraceTest", ".*");
+
+    let mut results = TestConfig::with_filter(runner, filter).evm_spec(SpecId::SHANGHAI).test();
+    let mut suite = results.remove("zk/Trace.t.sol:ZkTraceTest").unwrap();
+    let test = suite.test_results.remove("testZkTraceOutputDuringCall()").unwrap();
+    assert_eq!(test.status, TestStatus::Success);
+
+    let (kind, traces) = test.traces.last().unwrap().clone();
+    assert_eq!(kind, TraceKind::Execution);
+    let nodes = traces.into_nodes();
+
+    // test call -> [create Adder, Adder::add]
+    let test_call = &nodes[0];
+    assert_eq!(test_call.children.len(), 2);
+
+    // Adder::add -> [create Number, Number::five, Number::five]
+    let add_call = &nodes[test_call.children[1]];
+    assert_eq!(add_call.trace.kind, CallKind::Call);
+    assert_eq!(add_call.trace.depth, 1);
+    assert!(add_call.trace.success);
+    assert_eq!(add_call.logs.len(), 1);
+    assert_eq!(add_call.children.len(), 3);
+
+    let create_number = &nodes[add_call.children[0]];
+    assert_eq!(create_number.trace.kind, CallKind::Create);
+    assert_eq!(create_number.trace.depth, 2);
+
+    for idx in &add_call.children[1..] {
+        let five_call = &nodes[*idx];
+        assert_eq!(five_call.trace.kind, CallKind::Call);
+        assert_eq!(five_call.trace.address, create_number.trace.address);
+        assert_eq!(five_call.trace.depth, 2);
+        assert!(five_call.trace.success);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_zk_debug_steps_are_recorded_for_zk_calls() {
+    let mut runner = TEST_DATA_DEFAULT.runner_zksync();
+    runner.debug = true;
+    let filter = Filter::new("testZkTraceOutputDuringCall", "ZkTraceTest", ".*");
+
+    let mut results = TestConfig::with_filter(runner, filter).evm_spec(SpecId::SHANGHAI).test();
+    let mut suite = results.remove("zk/Trace.t.sol:ZkTraceTest").unwrap();
+    let test = suite.test_results.remove("testZkTraceOutputDuringCall()").unwrap();
+    assert_eq!(test.status, TestStatus::Success);
+
+    let (_, traces) = test.traces.last().unwrap().clone();
+    let nodes = traces.into_nodes();
+    let add_call_idx = nodes[0].children[1];
+    let add_call = &nodes[add_call_idx];
+
+    let steps = test.zk_debug_steps.get(&add_call_idx).expect("no debug steps for zk call");
+    assert!(!steps.is_empty());
+    assert_eq!(steps[0].address, add_call.trace.address);
+    assert!(steps.iter().all(|step| !foundry_zksync_core::is_system_address(step.address)));
+    // the heap is recorded when entering the call, and only again once it may have changed
+    assert!(steps[0].heap.is_some());
+    assert!(steps.iter().any(|step| step.heap.is_none()));
+}
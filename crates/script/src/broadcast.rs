@@ -26,7 +26,9 @@ use std::{
     collections::{HashMap, HashSet},
     sync::Arc,
 };
-use zksync_web3_rs::eip712::{Eip712Meta, Eip712Transaction, Eip712TransactionRequest};
+use zksync_web3_rs::eip712::{
+    Eip712Meta, Eip712Transaction, Eip712TransactionRequest, PaymasterParams,
+};
 
 pub async fn estimate_gas<P, T>(
     tx: &mut WithOtherFields<TransactionRequest>,
@@ -60,7 +62,13 @@ async fn convert_to_zksync(
     tx: WithOtherFields<TransactionRequest>,
     zk: &ZkTransaction,
 ) -> Result<(Eip712TransactionRequest, Eip712Transaction)> {
-    let custom_data = Eip712Meta::new().factory_deps(zk.factory_deps.clone());
+    let mut custom_data = Eip712Meta::new().factory_deps(zk.factory_deps.clone());
+    if let Some(paymaster_data) = &zk.paymaster_data {
+        custom_data = custom_data.paymaster_params(PaymasterParams {
+            paymaster: paymaster_data.paymaster,
+            paymaster_input: paymaster_data.paymaster_input.clone(),
+        });
+    }
 
     let gas_price = match tx.gas_price() {
         Some(price) => price,
@@ -126,6 +134,12 @@ pub async fn send_transaction(
                 "eip712Meta".into(),
                 serde_json::to_value(&Eip712Meta {
                     factory_deps: zk.factory_deps.clone(),
+                    paymaster_params: zk.paymaster_data.as_ref().map(|paymaster_data| {
+                        PaymasterParams {
+                            paymaster: paymaster_data.paymaster,
+                            paymaster_input: paymaster_data.paymaster_input.clone(),
+                        }
+                    }),
                     ..Default::default()
                 })
                 .expect("failed serializing json"),
@@ -18,11 +18,16 @@ use foundry_compilers::{
     info::ContractInfo,
     solc::SolcLanguage,
     utils::source_files_iter,
+    zksync::compile::output::ProjectCompileOutput as ZkProjectCompileOutput,
     ArtifactId, ProjectCompileOutput,
 };
 use foundry_evm::{constants::DEFAULT_CREATE2_DEPLOYER, traces::debug::ContractSources};
 use foundry_linking::Linker;
-use foundry_zksync_compiler::DualCompiledContracts;
+use foundry_zksync_compiler::{
+    libraries::{self, ZkLibraryDeployment, ZkMissingLibrary},
+    DualCompiledContracts,
+};
+use foundry_zksync_core::state::{get_nonce_storage, parse_full_nonce};
 use std::{path::PathBuf, str::FromStr, sync::Arc};
 
 /// Container for the compiled contracts.
@@ -35,6 +40,8 @@ pub struct BuildData {
     /// ID of target contract artifact.
     pub target: ArtifactId,
     pub dual_compiled_contracts: Option<DualCompiledContracts>,
+    /// Libraries missing from the zksolc output, deployed and linked when linking the contracts.
+    pub zk_missing_libraries: Vec<ZkMissingLibrary>,
 }
 
 impl BuildData {
@@ -45,6 +52,10 @@ impl BuildData {
     /// Links contracts. Uses CREATE2 linking when possible, otherwise falls back to
     /// default linking with sender nonce and address.
     pub async fn link(self, script_config: &ScriptConfig) -> Result<LinkedBuildData> {
+        if !self.zk_missing_libraries.is_empty() && script_config.config.zksync.run_in_zk_mode() {
+            return self.link_zk(script_config).await
+        }
+
         let can_use_create2 = if let Some(fork_url) = &script_config.evm_opts.fork_url {
             let provider = try_get_http_provider(fork_url)?;
             let deployer_code = provider.get_code_at(DEFAULT_CREATE2_DEPLOYER).await?;
@@ -92,9 +103,90 @@ impl BuildData {
         LinkedBuildData::new(libraries, predeploy_libs, self)
     }
 
+    /// Links contracts compiled with zksolc. Libraries missing from the zksolc output are deployed
+    /// by the sender through the `ContractDeployer`, so their addresses are computed from its
+    /// deployment nonce.
+    async fn link_zk(mut self, script_config: &ScriptConfig) -> Result<LinkedBuildData> {
+        let sender = script_config.evm_opts.sender;
+        let deploy_nonce = if let Some(fork_url) = &script_config.evm_opts.fork_url {
+            let provider = try_get_http_provider(fork_url)?;
+            let (nonce_holder, slot) = get_nonce_storage(sender);
+            parse_full_nonce(provider.get_storage_at(nonce_holder, slot).await?).deploy_nonce
+        } else {
+            // If --fork-url is not provided, the sender has not deployed any contract yet.
+            0
+        };
+
+        let zk_link = libraries::link_missing_libraries(
+            self.zk_missing_libraries.clone(),
+            script_config.config.libraries_with_remappings()?,
+            sender,
+            deploy_nonce,
+        )?;
+
+        let output = self.get_linker().link_with_nonce_or_address(
+            zk_link.libraries.clone(),
+            sender,
+            script_config.sender_nonce,
+            [&self.target],
+        )?;
+
+        let zk_output = self.zk_relink(script_config, &output.libraries)?;
+        let deployments = zk_link.deployments(&self.project_root, &zk_output)?;
+
+        LinkedBuildData::new(output.libraries, ScriptPredeployLibraries::Zk(deployments), self)
+    }
+
+    /// Compiles the contracts with zksolc again, linking them with the given libraries, since
+    /// zksolc links libraries at compile time.
+    fn zk_relink(
+        &mut self,
+        script_config: &ScriptConfig,
+        libraries: &Libraries,
+    ) -> Result<ZkProjectCompileOutput> {
+        let config = &script_config.config;
+        let mut zk_project =
+            foundry_zksync_compiler::config_create_project(config, config.cache, false)?;
+        zk_project.settings.settings.libraries = libraries.clone();
+
+        let sources_to_compile =
+            source_files_iter(zk_project.paths.sources.as_path(), SolcLanguage::FILE_EXTENSIONS)
+                .chain([self.target.source.clone()]);
+        let zk_output = ProjectCompiler::new()
+            .quiet(true)
+            .files(sources_to_compile)
+            .zksync_compile(&zk_project, config.zksync.avoid_contracts())?;
+
+        let linked_contracts =
+            ContractsByArtifact::new(self.get_linker().get_linked_artifacts(libraries)?);
+        let mut dual_compiled_contracts = DualCompiledContracts::new(
+            &self.output,
+            &zk_output,
+            &config.project_paths(),
+            &zk_project.paths,
+        );
+        dual_compiled_contracts.extend_with_linked(
+            linked_contracts.iter().filter_map(|(id, contract)| {
+                Some((id, contract.bytecode()?.as_ref(), contract.deployed_bytecode()?.as_ref()))
+            }),
+            &zk_output,
+        );
+        self.dual_compiled_contracts = Some(dual_compiled_contracts);
+
+        Ok(zk_output)
+    }
+
     /// Links the build data with the given libraries. Expects supplied libraries set being enough
     /// to fully link target contract.
-    pub fn link_with_libraries(self, libraries: Libraries) -> Result<LinkedBuildData> {
+    pub fn link_with_libraries(
+        mut self,
+        script_config: &ScriptConfig,
+        libraries: Libraries,
+    ) -> Result<LinkedBuildData> {
+        if !self.zk_missing_libraries.is_empty() && script_config.config.zksync.run_in_zk_mode() {
+            self.zk_relink(script_config, &libraries)?;
+        }
+
         LinkedBuildData::new(libraries, ScriptPredeployLibraries::Default(Vec::new()), self)
     }
 }
@@ -103,6 +195,8 @@ impl BuildData {
 pub enum ScriptPredeployLibraries {
     Default(Vec<Bytes>),
     Create2(Vec<Bytes>, B256),
+    /// Libraries deployed through the zkSync `ContractDeployer`.
+    Zk(Vec<ZkLibraryDeployment>),
 }
 
 impl ScriptPredeployLibraries {
@@ -110,6 +204,7 @@ impl ScriptPredeployLibraries {
         match self {
             Self::Default(libs) => libs.len(),
             Self::Create2(libs, _) => libs.len(),
+            Self::Zk(libs) => libs.len(),
         }
     }
 }
@@ -200,6 +295,7 @@ impl PreprocessedState {
             .compile(&project)?;
 
         // ZK
+        let mut zk_missing_libraries = Vec::new();
         let dual_compiled_contracts = if script_config.config.zksync.should_compile() {
             let zk_project = foundry_zksync_compiler::config_create_project(
                 &script_config.config,
@@ -210,11 +306,15 @@ impl PreprocessedState {
                 source_files_iter(project.paths.sources.as_path(), SolcLanguage::FILE_EXTENSIONS)
                     .chain([target_path.clone()]);
 
-            let zk_compiler =
-                ProjectCompiler::new().quiet_if(args.opts.silent).files(sources_to_compile);
+            let zk_compiler = ProjectCompiler::new()
+                .quiet_if(args.opts.silent)
+                .files(sources_to_compile)
+                .zksync_allow_missing_libraries(true);
 
             let zk_output = zk_compiler
                 .zksync_compile(&zk_project, script_config.config.zksync.avoid_contracts())?;
+            zk_missing_libraries =
+                libraries::get_missing_libraries(project.root(), &zk_output, &[]);
             Some(DualCompiledContracts::new(&output, &zk_output, &project.paths, &zk_project.paths))
         } else {
             None
@@ -263,6 +363,7 @@ impl PreprocessedState {
                 target,
                 project_root: project.root().clone(),
                 dual_compiled_contracts,
+                zk_missing_libraries,
             },
         })
     }
@@ -353,7 +454,7 @@ impl CompiledState {
             ScriptSequenceKind::Multi(_) => Libraries::default(),
         };
 
-        let linked_build_data = build_data.link_with_libraries(libraries)?;
+        let linked_build_data = build_data.link_with_libraries(&script_config, libraries)?;
 
         Ok(BundledState {
             args,
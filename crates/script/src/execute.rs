@@ -7,7 +7,7 @@ use crate::{
 use super::{runner::ScriptRunner, JsonResult, NestedValue, ScriptResult};
 use alloy_dyn_abi::FunctionExt;
 use alloy_json_abi::{Function, InternalType, JsonAbi};
-use alloy_primitives::{Address, Bytes};
+use alloy_primitives::{Address, Bytes, TxKind};
 use alloy_provider::Provider;
 use async_recursion::async_recursion;
 use eyre::{OptionExt, Result};
@@ -29,6 +29,7 @@ use foundry_evm::{
         render_trace_arena, CallTraceDecoder, CallTraceDecoderBuilder, TraceKind,
     },
 };
+use foundry_zksync_core::{convert::ConvertH160, CONTRACT_DEPLOYER_ADDRESS};
 use futures::future::join_all;
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
@@ -190,8 +191,12 @@ impl PreExecutionState {
             if self.build_data.predeploy_libraries.libraries_count() > 0 &&
                 self.args.evm_opts.sender.is_none()
             {
+                let zk_deployer = TxKind::Call(CONTRACT_DEPLOYER_ADDRESS.to_address());
                 for tx in txs.iter() {
-                    if tx.transaction.to.is_none() {
+                    // zkSync contracts are deployed by calling the `ContractDeployer`
+                    let is_create = tx.transaction.to.is_none() ||
+                        (tx.zk_tx.is_some() && tx.transaction.to == Some(zk_deployer));
+                    if is_create {
                         let sender = tx.transaction.from.expect("no sender");
                         if let Some(ns) = new_sender {
                             if sender != ns {
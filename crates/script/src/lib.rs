@@ -17,7 +17,7 @@ use broadcast::next_nonce;
 use build::PreprocessedState;
 use clap::{Parser, ValueHint};
 use dialoguer::Confirm;
-use eyre::{ContextCompat, Result};
+use eyre::{ContextCompat, Result, WrapErr};
 use forge_verify::RetryArgs;
 use foundry_cli::{opts::CoreBuildArgs, utils::LoadConfig};
 use foundry_common::{
@@ -265,6 +265,11 @@ impl ScriptArgs {
                 &pre_simulation.execution_result,
                 &pre_simulation.build_data.known_contracts,
             )?;
+            if let Some(dual_compiled_contracts) =
+                &pre_simulation.build_data.build_data.dual_compiled_contracts
+            {
+                check_zk_limits(&pre_simulation.execution_result, dual_compiled_contracts)?;
+            }
 
             pre_simulation.fill_metadata().await?.bundle().await?
         };
@@ -353,8 +358,6 @@ impl ScriptArgs {
         result: &ScriptResult,
         known_contracts: &ContractsByArtifact,
     ) -> Result<()> {
-        //TODO: zk mode contract size check
-
         // (name, &init, &deployed)[]
         let mut bytecodes: Vec<(String, &[u8], &[u8])> = vec![];
 
@@ -440,6 +443,35 @@ impl ScriptArgs {
     }
 }
 
+/// Checks that the factory dependencies of every zkSync transaction can be deployed on EraVM.
+///
+/// Contrary to the EVM contract size check, the network always rejects these transactions, so
+/// this errors instead of asking confirmation of the user.
+fn check_zk_limits(
+    result: &ScriptResult,
+    dual_compiled_contracts: &DualCompiledContracts,
+) -> Result<()> {
+    for (i, tx) in result.transactions.iter().flatten().enumerate() {
+        let Some(zk_tx) = &tx.zk_tx else { continue };
+
+        let names = zk_tx
+            .factory_deps
+            .iter()
+            .map(|dep| {
+                dual_compiled_contracts
+                    .find_by_zk_bytecode_hash(foundry_zksync_core::hash_bytecode(dep))
+                    .map_or_else(|| "Unknown".to_string(), |contract| contract.name.clone())
+            })
+            .collect::<Vec<_>>();
+        foundry_zksync_compiler::limits::check_factory_deps(
+            names.iter().map(String::as_str).zip(zk_tx.factory_deps.iter().map(Vec::as_slice)),
+        )
+        .wrap_err_with(|| format!("Transaction #{} can't be deployed on zkSync", i + 1))?;
+    }
+
+    Ok(())
+}
+
 impl Provider for ScriptArgs {
     fn metadata(&self) -> Metadata {
         Metadata::named("Script Args Provider")
@@ -9,10 +9,13 @@ use foundry_evm::{
     constants::{CALLER, DEFAULT_CREATE2_DEPLOYER},
     executors::{DeployResult, EvmError, ExecutionErr, Executor, RawCallResult},
     opts::EvmOpts,
-    revm::interpreter::{return_ok, InstructionResult},
+    revm::{
+        interpreter::{return_ok, InstructionResult},
+        primitives::CreateScheme,
+    },
     traces::{TraceKind, Traces},
 };
-use foundry_zksync_core::ZkTransactionMetadata;
+use foundry_zksync_core::{convert::ConvertH160, ZkTransactionMetadata, CONTRACT_DEPLOYER_ADDRESS};
 use std::collections::VecDeque;
 use yansi::Paint;
 
@@ -126,6 +129,46 @@ impl ScriptRunner {
                     sender_nonce + library_transactions.len() as u64,
                 )?;
             }
+            ScriptPredeployLibraries::Zk(libraries) => {
+                let deployer = CONTRACT_DEPLOYER_ADDRESS.to_address();
+                for library in libraries {
+                    let calldata: Bytes = foundry_zksync_core::encode_create_params(
+                        &CreateScheme::Create,
+                        library.bytecode_hash,
+                        Vec::new(),
+                    )
+                    .into();
+                    let zk_tx = ZkTransactionMetadata::new(library.factory_deps.clone(), None);
+
+                    self.executor.setup_zk_tx(zk_tx.clone());
+                    let result = self
+                        .executor
+                        .transact_raw(self.evm_opts.sender, deployer, calldata.clone(), U256::ZERO)
+                        .expect("couldn't deploy library");
+                    eyre::ensure!(!result.reverted, "couldn't deploy library");
+
+                    if let Some(deploy_traces) = result.traces {
+                        traces.push((TraceKind::Deployment, deploy_traces));
+                    }
+
+                    library_transactions.push_back(BroadcastableTransaction {
+                        rpc: self.evm_opts.fork_url.clone(),
+                        transaction: TransactionRequest {
+                            from: Some(self.evm_opts.sender),
+                            input: Some(calldata).into(),
+                            nonce: Some(sender_nonce + library_transactions.len() as u64),
+                            to: Some(TxKind::Call(deployer)),
+                            ..Default::default()
+                        },
+                        zk_tx: Some(zk_tx),
+                    });
+                }
+
+                self.executor.set_nonce(
+                    self.evm_opts.sender,
+                    sender_nonce + library_transactions.len() as u64,
+                )?;
+            }
         };
 
         let address = CALLER.create(self.executor.get_nonce(CALLER)?);
@@ -287,7 +287,12 @@ impl ScriptSequence {
                 if let (Some(address), Some(data)) =
                     (receipt.contract_address, tx.tx().input.input())
                 {
-                    match verify.get_verify_args(address, offset, &data.0, &self.libraries) {
+                    let verify_args = if tx.zk.is_some() {
+                        verify.get_zk_verify_args(address, &data.0, &self.libraries)
+                    } else {
+                        verify.get_verify_args(address, offset, &data.0, &self.libraries)
+                    };
+                    match verify_args {
                         Some(verify) => future_verifications.push(verify.run()),
                         None => unverifiable_contracts.push(address),
                     };
@@ -99,7 +99,13 @@ impl PreSimulationState {
                 let rpc = transaction.rpc.expect("missing broadcastable tx rpc url");
                 let mut runner = runners.get(&rpc).expect("invalid rpc url").write();
 
-                let zk = transaction.zk_tx;
+                let zk = transaction.zk_tx.map(|mut zk_tx| {
+                    // paymaster set via cheatcode takes precedence over the cli arguments
+                    zk_tx.paymaster_data = zk_tx
+                        .paymaster_data
+                        .or_else(|| self.args.opts.compiler.zk.paymaster_params());
+                    zk_tx
+                });
                 let mut tx = transaction.transaction;
                 let to = if let Some(TxKind::Call(to)) = tx.to { Some(to) } else { None };
                 let result = runner
@@ -144,7 +150,10 @@ impl PreSimulationState {
                     &self.execution_artifacts.decoder,
                     created_contracts,
                     is_fixed_gas_limit,
-                    zk.map(|zk_tx| ZkTransaction { factory_deps: zk_tx.factory_deps }),
+                    zk.map(|zk_tx| ZkTransaction {
+                        factory_deps: zk_tx.factory_deps,
+                        paymaster_data: zk_tx.paymaster_data,
+                    }),
                 )?;
 
                 eyre::Ok((Some(tx), result.traces))
@@ -228,8 +237,12 @@ impl PreSimulationState {
             .into_iter()
             .map(|btx| {
                 let mut tx = TransactionWithMetadata::from_tx_request(btx.transaction);
-                tx.zk =
-                    btx.zk_tx.map(|metadata| ZkTransaction { factory_deps: metadata.factory_deps });
+                tx.zk = btx.zk_tx.map(|metadata| ZkTransaction {
+                    factory_deps: metadata.factory_deps,
+                    paymaster_data: metadata
+                        .paymaster_data
+                        .or_else(|| self.args.opts.compiler.zk.paymaster_params()),
+                });
                 tx.rpc = btx.rpc.expect("missing broadcastable tx rpc url");
                 tx
             })
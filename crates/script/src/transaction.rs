@@ -6,6 +6,7 @@ use alloy_serde::WithOtherFields;
 use eyre::{ContextCompat, Result, WrapErr};
 use foundry_common::{fmt::format_token_raw, ContractData, SELECTOR_LEN};
 use foundry_evm::{constants::DEFAULT_CREATE2_DEPLOYER, traces::CallTraceDecoder};
+use foundry_zksync_core::PaymasterParams;
 use itertools::Itertools;
 use revm_inspectors::tracing::types::CallKind;
 use serde::{Deserialize, Serialize};
@@ -24,6 +25,8 @@ pub struct AdditionalContract {
 #[serde(rename_all = "camelCase")]
 pub struct ZkTransaction {
     pub factory_deps: Vec<Vec<u8>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paymaster_data: Option<PaymasterParams>,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -6,6 +6,7 @@ use foundry_cli::opts::{EtherscanOpts, ProjectPathsArgs};
 use foundry_common::ContractsByArtifact;
 use foundry_compilers::{info::ContractInfo, Project};
 use foundry_config::{Chain, Config};
+use foundry_zksync_compiler::DualCompiledContracts;
 use semver::Version;
 
 /// State after we have broadcasted the script.
@@ -26,6 +27,7 @@ impl BroadcastedState {
             &script_config.config.project()?,
             &script_config.config,
             build_data.known_contracts,
+            build_data.build_data.dual_compiled_contracts,
             args.retry,
             args.verifier,
         );
@@ -49,6 +51,7 @@ pub struct VerifyBundle {
     pub verifier: VerifierArgs,
     pub via_ir: bool,
     pub zksync: bool,
+    pub dual_compiled_contracts: Option<DualCompiledContracts>,
 }
 
 impl VerifyBundle {
@@ -56,6 +59,7 @@ impl VerifyBundle {
         project: &Project,
         config: &Config,
         known_contracts: ContractsByArtifact,
+        dual_compiled_contracts: Option<DualCompiledContracts>,
         retry: RetryArgs,
         verifier: VerifierArgs,
     ) -> Self {
@@ -87,6 +91,7 @@ impl VerifyBundle {
             verifier,
             via_ir,
             zksync,
+            dual_compiled_contracts,
         }
     }
 
@@ -161,4 +166,23 @@ impl VerifyBundle {
         }
         None
     }
+
+    /// Given a `VerifyBundle` and the calldata of a deployment through the zkSync
+    /// `ContractDeployer`, it tries to generate a valid `VerifyArgs` to use against the
+    /// `contract_address`.
+    ///
+    /// The deployed contract is identified by its zksolc bytecode hash, and matched with the known
+    /// contracts through its solc bytecode.
+    pub fn get_zk_verify_args(
+        &self,
+        contract_address: Address,
+        data: &[u8],
+        libraries: &[String],
+    ) -> Option<VerifyArgs> {
+        let (bytecode_hash, constructor_args) = foundry_zksync_core::decode_create_params(data)?;
+        let contract =
+            self.dual_compiled_contracts.as_ref()?.find_by_zk_bytecode_hash(bytecode_hash)?;
+        let init_code = [contract.evm_bytecode.as_slice(), &constructor_args].concat();
+        self.get_verify_args(contract_address, 0, &init_code, libraries)
+    }
 }
@@ -1,4 +1,4 @@
-use alloy_primitives::{Address, Bytes, U256};
+use alloy_primitives::{hex, Address, Bytes, B256, U256};
 use alloy_provider::Provider;
 use alloy_rpc_types::{BlockId, BlockNumberOrTag};
 use clap::{Parser, ValueHint};
@@ -8,16 +8,27 @@ use foundry_cli::{
     opts::EtherscanOpts,
     utils::{self, read_constructor_args_file, LoadConfig},
 };
-use foundry_common::{compile::ProjectCompiler, provider::ProviderBuilder};
+use foundry_common::{
+    compile::ProjectCompiler,
+    provider::{ProviderBuilder, RetryProvider},
+};
 use foundry_compilers::{
     artifacts::{BytecodeHash, BytecodeObject, CompactContractBytecode, EvmVersion},
     info::ContractInfo,
+    utils::canonicalize,
+    zksolc::settings::BytecodeHash as ZkBytecodeHash,
     Artifact,
 };
 use foundry_config::{figment, filter::SkipBuildFilter, impl_figment_convert, Chain, Config};
 use foundry_evm::{
     constants::DEFAULT_CREATE2_DEPLOYER, executors::TracingExecutor, utils::configure_tx_env,
 };
+use foundry_zksync_core::{
+    convert::{ConvertH160, ConvertH256},
+    hash_bytecode,
+    state::get_account_code_storage,
+    CONTRACT_DEPLOYER_ADDRESS,
+};
 use revm_primitives::{db::Database, EnvWithHandlerCfg, HandlerCfg, SpecId};
 use semver::Version;
 use serde::{Deserialize, Serialize};
@@ -76,6 +87,10 @@ pub struct VerifyBytecodeArgs {
     /// Suppress logs and emit json results to stdout
     #[clap(long, default_value = "false")]
     pub json: bool,
+
+    /// Verify a contract deployed on zkSync, compiling it with zksolc.
+    #[clap(long)]
+    pub zksync: bool,
 }
 
 impl figment::Provider for VerifyBytecodeArgs {
@@ -134,6 +149,10 @@ impl VerifyBytecodeArgs {
         self.etherscan_opts.key =
             config.get_etherscan_config_with_chain(Some(chain))?.map(|c| c.key);
 
+        if self.zksync {
+            return self.run_zk(&config, &provider, chain).await
+        }
+
         // If etherscan key is not set, we can't proceed with etherscan verification
         let Some(key) = self.etherscan_opts.key.clone() else {
             eyre::bail!("Etherscan API key is required for verification");
@@ -257,7 +276,7 @@ impl VerifyBytecodeArgs {
             (did_match, with_status),
             BytecodeType::Creation,
             &mut json_results,
-            etherscan_metadata,
+            Some(etherscan_metadata),
             &config,
         );
 
@@ -267,7 +286,7 @@ impl VerifyBytecodeArgs {
                 (did_match, with_status),
                 BytecodeType::Runtime,
                 &mut json_results,
-                etherscan_metadata,
+                Some(etherscan_metadata),
                 &config,
             );
             if self.json {
@@ -395,7 +414,7 @@ impl VerifyBytecodeArgs {
             (did_match, with_status),
             BytecodeType::Runtime,
             &mut json_results,
-            etherscan_metadata,
+            Some(etherscan_metadata),
             &config,
         );
 
@@ -405,6 +424,152 @@ impl VerifyBytecodeArgs {
         Ok(())
     }
 
+    /// Verifies the bytecode of a contract deployed on zkSync.
+    ///
+    /// EraVM contracts are identified on-chain by the versioned hash of their bytecode, which is
+    /// stored in the `AccountCodeStorage` system contract, and are deployed by calling the
+    /// `ContractDeployer` with that hash and the constructor input instead of with creation code.
+    /// Hence the runtime code is matched by comparing the hash of the locally built bytecode, and
+    /// the creation by checking the bytecode hash and constructor input of the deployment call.
+    async fn run_zk(&self, config: &Config, provider: &RetryProvider, chain: Chain) -> Result<()> {
+        let block = self.block.unwrap_or_default();
+        let (account, slot) = get_account_code_storage(self.address);
+        let onchain_hash =
+            B256::from(provider.get_storage_at(account, slot).block_id(block).await?);
+        let onchain_runtime_code = provider.get_code_at(self.address).block_id(block).await?;
+
+        // zksolc appends a keccak256 metadata hash by default, without it the bytecode of
+        // identical sources is identical so it can still be fully matched
+        let verification_type = self.verification_type;
+        let has_metadata = !matches!(config.zksync.bytecode_hash, Some(ZkBytecodeHash::None));
+        trace!(?verification_type, has_metadata);
+
+        let local_bytecode = self.build_zk_project(config)?;
+
+        let mut json_results: Vec<JsonResult> = vec![];
+
+        // The deployment call is only available if the contract was deployed directly by a
+        // transaction to the `ContractDeployer`, which is found through the block explorer.
+        match self.zk_creation_params(provider, chain).await? {
+            Some((bytecode_hash, constructor_args)) => {
+                let provided_constructor_args = if let Some(args) = self.constructor_args.clone() {
+                    Some(args)
+                } else if let Some(path) = self.constructor_args_path.clone() {
+                    Some(read_constructor_args_file(path)?.join(""))
+                } else {
+                    None
+                };
+                let constructor_args = hex::encode(constructor_args);
+                let args_match = provided_constructor_args
+                    .map_or(true, |provided| provided.trim_start_matches("0x") == constructor_args);
+                if !args_match && !self.json {
+                    println!(
+                        "{}",
+                        format!("The provided constructor args do not match the constructor args of the deployment: {constructor_args}").red().bold(),
+                    );
+                }
+
+                let (did_match, with_status) = if args_match && bytecode_hash == onchain_hash {
+                    try_zk_match(
+                        &local_bytecode,
+                        onchain_hash,
+                        &onchain_runtime_code,
+                        &verification_type,
+                        has_metadata,
+                    )
+                } else {
+                    (false, Some(verification_type))
+                };
+                self.print_result(
+                    (did_match, with_status),
+                    BytecodeType::Creation,
+                    &mut json_results,
+                    None,
+                    config,
+                );
+            }
+            None => {
+                if !self.json {
+                    println!(
+                        "{}",
+                        "Could not find the deployment of the contract, skipping the verification of its creation".yellow()
+                    );
+                }
+            }
+        }
+
+        let (did_match, with_status) = try_zk_match(
+            &local_bytecode,
+            onchain_hash,
+            &onchain_runtime_code,
+            &verification_type,
+            has_metadata,
+        );
+        self.print_result(
+            (did_match, with_status),
+            BytecodeType::Runtime,
+            &mut json_results,
+            None,
+            config,
+        );
+
+        if self.json {
+            println!("{}", serde_json::to_string(&json_results)?);
+        }
+        Ok(())
+    }
+
+    /// Returns the bytecode hash and constructor input of the call to the `ContractDeployer` that
+    /// deployed the contract, if its creation transaction can be found with Etherscan.
+    async fn zk_creation_params(
+        &self,
+        provider: &RetryProvider,
+        chain: Chain,
+    ) -> Result<Option<(B256, Vec<u8>)>> {
+        let Some(key) = self.etherscan_opts.key.clone() else { return Ok(None) };
+        let etherscan = Client::new(chain, key)?;
+        let creation_data = etherscan.contract_creation_data(self.address).await?;
+
+        trace!(creation_tx_hash = ?creation_data.transaction_hash);
+        let transaction = provider
+            .get_transaction_by_hash(creation_data.transaction_hash)
+            .await
+            .or_else(|e| eyre::bail!("Couldn't fetch transaction from RPC: {:?}", e))?
+            .ok_or_else(|| {
+                eyre::eyre!("Transaction not found for hash {}", creation_data.transaction_hash)
+            })?;
+
+        if transaction.to != Some(CONTRACT_DEPLOYER_ADDRESS.to_address()) {
+            return Ok(None)
+        }
+        Ok(foundry_zksync_core::decode_create_params(&transaction.input)
+            .map(|(bytecode_hash, constructor_args)| (bytecode_hash.to_b256(), constructor_args)))
+    }
+
+    fn build_zk_project(&self, config: &Config) -> Result<Vec<u8>> {
+        let project = foundry_zksync_compiler::config_create_project(config, config.cache, false)?;
+        let contract_path = if let Some(ref path) = self.contract.path {
+            canonicalize(project.root().join(path))?
+        } else {
+            project.find_contract_path(&self.contract.name)?
+        };
+
+        let mut output = ProjectCompiler::new()
+            .quiet(self.json)
+            .files([contract_path.clone()])
+            .zksync_compile(&project, config.zksync.avoid_contracts())?;
+
+        let artifact = utils::remove_zk_contract(&mut output, &contract_path, &self.contract.name)?;
+        let local_bytecode = artifact
+            .bytecode
+            .ok_or_eyre("Contract artifact does not have bytecode")?
+            .object
+            .into_bytes()
+            .ok_or_eyre("Unlinked bytecode is not supported for verification")?;
+
+        Ok(local_bytecode.to_vec())
+    }
+
     fn build_project(&self, config: &Config) -> Result<Bytes> {
         let project = config.project()?;
         let compiler = ProjectCompiler::new();
@@ -486,7 +651,7 @@ impl VerifyBytecodeArgs {
         res: (bool, Option<VerificationType>),
         bytecode_type: BytecodeType,
         json_results: &mut Vec<JsonResult>,
-        etherscan_config: &Metadata,
+        etherscan_config: Option<&Metadata>,
         config: &Config,
     ) {
         if res.0 {
@@ -514,7 +679,9 @@ impl VerifyBytecodeArgs {
                 .red()
                 .bold()
             );
-            let mismatches = find_mismatch_in_settings(etherscan_config, config);
+            let mismatches = etherscan_config
+                .map(|etherscan_config| find_mismatch_in_settings(etherscan_config, config))
+                .unwrap_or_default();
             for mismatch in mismatches {
                 println!("{}", mismatch.red().bold());
             }
@@ -608,6 +775,48 @@ fn try_match(
     }
 }
 
+/// Matches the local zksolc bytecode with the bytecode deployed on zkSync, which is identified by
+/// its versioned bytecode hash.
+fn try_zk_match(
+    local_bytecode: &[u8],
+    onchain_hash: B256,
+    onchain_bytecode: &[u8],
+    match_type: &VerificationType,
+    has_metadata: bool,
+) -> (bool, Option<VerificationType>) {
+    // 1. Try full match
+    if *match_type == VerificationType::Full &&
+        hash_bytecode(local_bytecode).to_b256() == onchain_hash
+    {
+        return (true, Some(VerificationType::Full))
+    }
+
+    // 2. Try partial match, ignoring the metadata hash
+    let matched = if has_metadata {
+        match (strip_zk_metadata_hash(local_bytecode), strip_zk_metadata_hash(onchain_bytecode)) {
+            (Some(local), Some(onchain)) => local == onchain,
+            _ => false,
+        }
+    } else {
+        local_bytecode == onchain_bytecode
+    };
+    (matched, Some(VerificationType::Partial))
+}
+
+/// Returns the zksolc bytecode without its metadata hash, or `None` if it is not a valid EraVM
+/// bytecode.
+///
+/// EraVM bytecodes are made of an odd number of 32 bytes words. zksolc pads the code with a zero
+/// word if needed and then appends the keccak256 metadata hash as the last word, so identical code
+/// keeps the same padding and only the last word has to be discarded.
+fn strip_zk_metadata_hash(bytecode: &[u8]) -> Option<&[u8]> {
+    let words = bytecode.len() / 32;
+    if bytecode.len() % 32 != 0 || words % 2 == 0 {
+        return None
+    }
+    Some(&bytecode[..bytecode.len() - 32])
+}
+
 fn try_partial_match(
     mut local_bytecode: &[u8],
     mut bytecode: &[u8],
@@ -682,3 +891,51 @@ fn find_mismatch_in_settings(
 
     mismatches
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_strip_zk_metadata_hash() {
+        let code = [[1u8; 32], [2u8; 32]].concat();
+        let bytecode = [code.as_slice(), &[0xaa; 32]].concat();
+        assert_eq!(strip_zk_metadata_hash(&bytecode), Some(code.as_slice()));
+
+        // the padding word is kept, the metadata hash is always the last word
+        let padded = [[1u8; 32], [0u8; 32], [0xbb; 32]].concat();
+        assert_eq!(strip_zk_metadata_hash(&padded), Some(&padded[..64]));
+
+        assert_eq!(strip_zk_metadata_hash(&bytecode[..64]), None);
+        assert_eq!(strip_zk_metadata_hash(&bytecode[..95]), None);
+    }
+
+    #[test]
+    fn zk_match_ignores_only_the_metadata_hash() {
+        let local = [[1u8; 32], [2u8; 32], [0xaa; 32]].concat();
+        let onchain = [[1u8; 32], [2u8; 32], [0xbb; 32]].concat();
+        let onchain_hash = hash_bytecode(&onchain).to_b256();
+
+        assert_eq!(
+            try_zk_match(&local, onchain_hash, &onchain, &VerificationType::Full, true),
+            (true, Some(VerificationType::Partial))
+        );
+        assert_eq!(
+            try_zk_match(&onchain, onchain_hash, &onchain, &VerificationType::Full, true),
+            (true, Some(VerificationType::Full))
+        );
+
+        // a different code word is not mistaken for the metadata hash
+        let other = [[1u8; 32], [3u8; 32], [0xaa; 32]].concat();
+        assert_eq!(
+            try_zk_match(&other, onchain_hash, &onchain, &VerificationType::Full, true),
+            (false, Some(VerificationType::Partial))
+        );
+
+        // without a metadata hash identical bytecodes are fully matched
+        assert_eq!(
+            try_zk_match(&onchain, onchain_hash, &onchain, &VerificationType::Full, false),
+            (true, Some(VerificationType::Full))
+        );
+    }
+}
@@ -27,7 +27,7 @@ use futures::FutureExt;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use semver::{BuildMetadata, Version};
-use std::fmt::Debug;
+use std::{fmt::Debug, path::Path};
 
 mod flatten;
 mod standard_json;
@@ -386,23 +386,7 @@ impl EtherscanVerificationProvider {
         context: &CompilerVerificationContext,
     ) -> Result<Option<String>> {
         if let Some(ref constructor_args_path) = args.constructor_args_path {
-            let abi = context.get_target_abi()?;
-            let constructor = abi
-                .constructor()
-                .ok_or_else(|| eyre!("Can't retrieve constructor info from artifact ABI."))?;
-            #[allow(deprecated)]
-            let func = Function {
-                name: "constructor".to_string(),
-                inputs: constructor.inputs.clone(),
-                outputs: vec![],
-                state_mutability: alloy_json_abi::StateMutability::NonPayable,
-            };
-            let encoded_args = encode_function_args(
-                &func,
-                read_constructor_args_file(constructor_args_path.to_path_buf())?,
-            )?;
-            let encoded_args = hex::encode(encoded_args);
-            return Ok(Some(encoded_args[8..].into()))
+            return Ok(Some(encode_constructor_args_file(context, constructor_args_path)?))
         }
         if args.guess_constructor_args {
             return Ok(Some(self.guess_constructor_args(args, context).await?))
@@ -505,6 +489,31 @@ impl EtherscanVerificationProvider {
     }
 }
 
+/// Reads the constructor arguments from the given file and ABI-encodes them with the constructor
+/// of the target contract.
+pub(crate) fn encode_constructor_args_file(
+    context: &CompilerVerificationContext,
+    constructor_args_path: &Path,
+) -> Result<String> {
+    let abi = context.get_target_abi()?;
+    let constructor = abi
+        .constructor()
+        .ok_or_else(|| eyre!("Can't retrieve constructor info from artifact ABI."))?;
+    #[allow(deprecated)]
+    let func = Function {
+        name: "constructor".to_string(),
+        inputs: constructor.inputs.clone(),
+        outputs: vec![],
+        state_mutability: alloy_json_abi::StateMutability::NonPayable,
+    };
+    let encoded_args = encode_function_args(
+        &func,
+        read_constructor_args_file(constructor_args_path.to_path_buf())?,
+    )?;
+    let encoded_args = hex::encode(encoded_args);
+    Ok(encoded_args[8..].into())
+}
+
 /// Given any solc [Version] return a [Version] with build metadata
 ///
 /// # Example
@@ -36,6 +36,7 @@ use provider::VerificationProvider;
 pub mod bytecode;
 pub mod retry;
 mod sourcify;
+mod zksync;
 
 pub use retry::RetryArgs;
 
@@ -416,6 +417,8 @@ pub struct VerifyCheckArgs {
     /// For Etherscan - Submission GUID.
     ///
     /// For Sourcify - Contract Address.
+    ///
+    /// For zkSync - Verification ID.
     id: String,
 
     #[command(flatten)]
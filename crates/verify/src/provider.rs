@@ -1,6 +1,6 @@
 use super::{
-    etherscan::EtherscanVerificationProvider, sourcify::SourcifyVerificationProvider, VerifyArgs,
-    VerifyCheckArgs,
+    etherscan::EtherscanVerificationProvider, sourcify::SourcifyVerificationProvider,
+    zksync::ZkSyncVerificationProvider, VerifyArgs, VerifyCheckArgs,
 };
 use crate::zk_provider::CompilerVerificationContext;
 use alloy_json_abi::JsonAbi;
@@ -128,6 +128,7 @@ impl FromStr for VerificationProviderType {
             "s" | "sourcify" => Ok(Self::Sourcify),
             "b" | "blockscout" => Ok(Self::Blockscout),
             "o" | "oklink" => Ok(Self::Oklink),
+            "z" | "zksync" => Ok(Self::ZkSync),
             _ => Err(format!("Unknown provider: {s}")),
         }
     }
@@ -148,6 +149,9 @@ impl fmt::Display for VerificationProviderType {
             Self::Oklink => {
                 write!(f, "oklink")?;
             }
+            Self::ZkSync => {
+                write!(f, "zksync")?;
+            }
         };
         Ok(())
     }
@@ -160,6 +164,8 @@ pub enum VerificationProviderType {
     Sourcify,
     Blockscout,
     Oklink,
+    #[value(name = "zksync")]
+    ZkSync,
 }
 
 impl VerificationProviderType {
@@ -175,6 +181,7 @@ impl VerificationProviderType {
             Self::Sourcify => Ok(Box::<SourcifyVerificationProvider>::default()),
             Self::Blockscout => Ok(Box::<EtherscanVerificationProvider>::default()),
             Self::Oklink => Ok(Box::<EtherscanVerificationProvider>::default()),
+            Self::ZkSync => Ok(Box::<ZkSyncVerificationProvider>::default()),
         }
     }
 }
@@ -16,13 +16,18 @@ use foundry_compilers::{
 };
 use foundry_config::Config;
 use semver::Version;
-use std::path::PathBuf;
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
 
 #[derive(Debug, Clone)]
 pub struct ZkVersion {
     pub zksolc: Version,
     pub solc: Version,
     pub is_zksync_solc: bool,
+    /// The release of the zkSync fork of solc, if it is used.
+    pub zksync_solc_release: Option<Version>,
 }
 
 /// Container with data required for contract verification.
@@ -47,8 +52,10 @@ impl ZkVerificationContext {
         project.no_artifacts = true;
         let zksolc_version = ZkSolc::new(project.compiler.zksolc.clone()).version()?;
         let mut is_zksync_solc = false;
+        let solc_release;
 
         let solc_version = if let Some(solc) = &config.zksync.solc_path {
+            solc_release = zksync_solc_release(solc);
             let solc = Solc::new(solc)?;
             //TODO: determine if this solc is zksync or not
             solc.version
@@ -63,6 +70,8 @@ impl ZkVerificationContext {
                 ZkSolc::solc_blocking_install(&context_solc_version.to_string())?
             };
 
+            solc_release = zksync_solc_release(&solc_path);
+
             let solc = Solc::new_with_version(solc_path, context_solc_version.clone());
             project.compiler.solc = SolcCompiler::Specific(solc);
 
@@ -70,8 +79,12 @@ impl ZkVerificationContext {
             context_solc_version
         };
 
-        let compiler_version =
-            ZkVersion { zksolc: zksolc_version, solc: solc_version, is_zksync_solc };
+        let compiler_version = ZkVersion {
+            zksolc: zksolc_version,
+            solc: solc_version,
+            is_zksync_solc,
+            zksync_solc_release: solc_release,
+        };
 
         Ok(Self { config, project, target_name, target_path, compiler_version })
     }
@@ -206,3 +219,48 @@ impl CompilerVerificationContext {
         }
     }
 }
+
+/// Returns the release of the zkSync fork of solc at the given path, reported by `solc --version`
+/// on a `ZKsync: <solc version>-<release>` line, or `None` if it is not the zkSync fork.
+fn zksync_solc_release(solc: &Path) -> Option<Version> {
+    let output = match Command::new(solc).arg("--version").output() {
+        Ok(output) => output,
+        Err(err) => {
+            warn!("failed to run {}: {err}", solc.display());
+            return None
+        }
+    };
+    let release = parse_zksync_solc_release(&String::from_utf8_lossy(&output.stdout));
+    if release.is_none() {
+        debug!("{} is not the zkSync fork of solc", solc.display());
+    }
+    release
+}
+
+fn parse_zksync_solc_release(version_output: &str) -> Option<Version> {
+    let line = version_output
+        .lines()
+        .find(|line| line.trim_start().to_ascii_lowercase().starts_with("zksync"))?;
+    let version = line.split_whitespace().last()?;
+    let (_, release) = version.split_once('-')?;
+    Version::parse(release).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_zksync_solc_release() {
+        let output = "solc, the solidity compiler commandline interface
+Version: 0.8.26+commit.8a97fa7a.Linux.g++
+ZKsync: 0.8.26-1.0.1
+";
+        assert_eq!(parse_zksync_solc_release(output), Some(Version::new(1, 0, 1)));
+
+        let output = "solc, the solidity compiler commandline interface
+Version: 0.8.26+commit.8a97fa7a.Linux.g++
+";
+        assert_eq!(parse_zksync_solc_release(output), None);
+    }
+}
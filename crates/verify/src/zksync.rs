@@ -0,0 +1,290 @@
+use super::{provider::VerificationProvider, VerifyArgs, VerifyCheckArgs};
+use crate::{
+    etherscan::encode_constructor_args_file,
+    retry::RETRY_CHECK_ON_VERIFY,
+    zk_provider::{CompilerVerificationContext, ZkVerificationContext},
+    VerifierArgs,
+};
+use eyre::{eyre, Context, Result};
+use foundry_common::retry::{Retry, RetryError};
+use foundry_config::{Chain, NamedChain};
+use futures::FutureExt;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+
+/// The contract verification endpoint of the zkSync Era mainnet block explorer.
+pub static ZKSYNC_MAINNET_VERIFIER_URL: &str =
+    "https://zksync2-mainnet-explorer.zksync.io/contract_verification";
+
+/// The contract verification endpoint of the zkSync Era sepolia testnet block explorer.
+pub static ZKSYNC_SEPOLIA_VERIFIER_URL: &str =
+    "https://explorer.sepolia.era.zksync.dev/contract_verification";
+
+/// The type that can verify a contract on the zkSync Era block explorer.
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct ZkSyncVerificationProvider;
+
+#[async_trait::async_trait]
+impl VerificationProvider for ZkSyncVerificationProvider {
+    async fn preflight_check(
+        &mut self,
+        args: VerifyArgs,
+        context: CompilerVerificationContext,
+    ) -> Result<()> {
+        let _ = verifier_url(&args.verifier, args.etherscan.chain)?;
+        let _ = self.prepare_request(&args, &context)?;
+        Ok(())
+    }
+
+    async fn verify(
+        &mut self,
+        args: VerifyArgs,
+        context: CompilerVerificationContext,
+    ) -> Result<()> {
+        let url = verifier_url(&args.verifier, args.etherscan.chain)?;
+        let request = self.prepare_request(&args, &context)?;
+
+        trace!(target: "forge::verify", ?request, "submitting verification request");
+
+        let client = reqwest::Client::new();
+        let retry: Retry = args.retry.into();
+        let verification_id = retry
+            .run_async(|| {
+                async {
+                    println!(
+                        "\nSubmitting verification for [{}] {}.",
+                        request.contract_name, args.address
+                    );
+                    let response = client.post(url.clone()).json(&request).send().await?;
+
+                    let status = response.status();
+                    let text = response.text().await?;
+                    if !status.is_success() {
+                        if text.contains("already verified") {
+                            return Ok(None)
+                        }
+                        eyre::bail!(
+                            "zkSync verification request for address ({}) failed with status code {status}\nDetails: {text}",
+                            args.address,
+                        );
+                    }
+
+                    let id = serde_json::from_str::<u64>(&text)
+                        .wrap_err_with(|| format!("Unexpected verification response: {text}"))?;
+                    Ok(Some(id))
+                }
+                .boxed()
+            })
+            .await?;
+
+        let Some(verification_id) = verification_id else {
+            println!("Contract source code already verified");
+            return Ok(())
+        };
+
+        println!("Submitted contract for verification:\n\tVerification ID: `{verification_id}`");
+
+        if args.watch {
+            let check_args = VerifyCheckArgs {
+                id: verification_id.to_string(),
+                etherscan: args.etherscan,
+                retry: RETRY_CHECK_ON_VERIFY,
+                verifier: args.verifier,
+            };
+            return self.check(check_args).await
+        }
+
+        Ok(())
+    }
+
+    async fn check(&self, args: VerifyCheckArgs) -> Result<()> {
+        let url = verifier_url(&args.verifier, args.etherscan.chain)?;
+        let url = Url::parse(&format!("{}/{}", url.as_str().trim_end_matches('/'), args.id))?;
+
+        let retry: Retry = args.retry.into();
+        retry
+            .run_async_until_break(|| {
+                async {
+                    let response = reqwest::get(url.clone())
+                        .await
+                        .wrap_err("Failed to request verification status")
+                        .map_err(RetryError::Retry)?;
+                    if !response.status().is_success() {
+                        return Err(RetryError::Retry(eyre!(
+                            "Failed to request verification status with status code {}",
+                            response.status()
+                        )))
+                    }
+
+                    let resp = response
+                        .json::<ZkVerificationStatus>()
+                        .await
+                        .wrap_err("Failed to parse verification status")
+                        .map_err(RetryError::Retry)?;
+
+                    trace!(target: "forge::verify", ?resp, "Received verification response");
+
+                    eprintln!("Contract verification status:\nResponse: `{}`", resp.status);
+
+                    match resp.status.as_str() {
+                        "queued" => {
+                            Err(RetryError::Retry(eyre!("Verification is still pending...")))
+                        }
+                        "in_progress" => {
+                            Err(RetryError::Retry(eyre!("Verification is in progress...")))
+                        }
+                        "successful" => {
+                            println!("Contract successfully verified");
+                            Ok(())
+                        }
+                        "failed" => {
+                            let mut details = resp.error.unwrap_or_default();
+                            for error in resp.compilation_errors.unwrap_or_default() {
+                                details.push('\n');
+                                details.push_str(&error);
+                            }
+                            Err(RetryError::Break(eyre!(
+                                "Contract failed to verify.\nDetails: `{details}`"
+                            )))
+                        }
+                        status => {
+                            Err(RetryError::Break(eyre!("Unknown verification status: {status}")))
+                        }
+                    }
+                }
+                .boxed()
+            })
+            .await
+            .wrap_err("Checking verification result failed")
+    }
+}
+
+impl ZkSyncVerificationProvider {
+    /// Configures the request to the zkSync block explorer verification API using the given
+    /// [`VerifyArgs`].
+    fn prepare_request(
+        &self,
+        args: &VerifyArgs,
+        context: &CompilerVerificationContext,
+    ) -> Result<ZkVerificationRequest> {
+        let CompilerVerificationContext::ZkSolc(zk_context) = context else {
+            eyre::bail!("The zkSync verifier can only verify contracts compiled with zksolc, pass `--zksync` to compile them")
+        };
+        if args.flatten {
+            eyre::bail!("The zkSync verifier only supports standard json input, `--flatten` is not supported")
+        }
+
+        let source_code = foundry_compilers::zksync::project_standard_json_input(
+            &zk_context.project,
+            &zk_context.target_path,
+        )
+        .wrap_err("failed to get zksolc standard json")?;
+        let source_code = serde_json::to_value(&source_code)
+            .wrap_err("Failed to parse zksync standard json input")?;
+
+        let contract_name = format!(
+            "{}:{}",
+            zk_context
+                .target_path
+                .strip_prefix(zk_context.project.root())
+                .unwrap_or(zk_context.target_path.as_path())
+                .display(),
+            zk_context.target_name
+        );
+
+        let constructor_arguments = if let Some(ref path) = args.constructor_args_path {
+            encode_constructor_args_file(context, path)?
+        } else if args.guess_constructor_args {
+            eyre::bail!("`--guess-constructor-args` is not supported by the zkSync verifier")
+        } else {
+            args.constructor_args.clone().unwrap_or_default()
+        };
+
+        let settings = &zk_context.project.settings.settings;
+        Ok(ZkVerificationRequest {
+            contract_address: args.address.to_checksum(None),
+            source_code,
+            code_format: "solidity-standard-json-input".to_string(),
+            contract_name,
+            compiler_zksolc_version: format!("v{}", zk_context.compiler_version.zksolc),
+            compiler_solc_version: compiler_solc_version(zk_context)?,
+            optimization_used: settings.optimizer.enabled.unwrap_or_default(),
+            optimizer_mode: settings.optimizer.mode.map(|mode| mode.to_string()),
+            constructor_arguments: format!("0x{}", constructor_arguments.trim_start_matches("0x")),
+            is_system: settings.enable_eravm_extensions,
+            force_evmla: settings.force_evmla,
+        })
+    }
+}
+
+/// Returns the solc version in the format expected by the block explorer, which identifies the
+/// zkSync fork of solc with a `zkVM-` prefix and its release.
+fn compiler_solc_version(context: &ZkVerificationContext) -> Result<String> {
+    let solc = &context.compiler_version.solc;
+    let Some(release) = &context.compiler_version.zksync_solc_release else {
+        eyre::bail!("The zkSync verifier requires the zkSync fork of solc, but the release of solc {solc} could not be determined from `solc --version`")
+    };
+    Ok(format!("zkVM-{}.{}.{}-{release}", solc.major, solc.minor, solc.patch))
+}
+
+/// Returns the verification endpoint to use, which defaults to the one of the block explorer of
+/// the given chain if `--verifier-url` is not set.
+fn verifier_url(verifier: &VerifierArgs, chain: Option<Chain>) -> Result<Url> {
+    let url = match (verifier.verifier_url.as_deref(), chain.and_then(|c| c.named())) {
+        (Some(url), _) => url,
+        (None, Some(NamedChain::ZkSync)) => ZKSYNC_MAINNET_VERIFIER_URL,
+        (None, Some(NamedChain::ZkSyncTestnet)) => ZKSYNC_SEPOLIA_VERIFIER_URL,
+        _ => eyre::bail!("The zkSync verifier requires `--verifier-url` to be set for this chain"),
+    };
+    Url::parse(url).wrap_err_with(|| format!("Invalid verifier URL {url}"))
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZkVerificationRequest {
+    contract_address: String,
+    source_code: serde_json::Value,
+    code_format: String,
+    contract_name: String,
+    compiler_zksolc_version: String,
+    compiler_solc_version: String,
+    optimization_used: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    optimizer_mode: Option<String>,
+    constructor_arguments: String,
+    is_system: bool,
+    force_evmla: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZkVerificationStatus {
+    status: String,
+    error: Option<String>,
+    compilation_errors: Option<Vec<String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_resolve_verifier_url() {
+        let verifier = VerifierArgs::default();
+        assert_eq!(
+            verifier_url(&verifier, Some(NamedChain::ZkSync.into())).unwrap().as_str(),
+            ZKSYNC_MAINNET_VERIFIER_URL
+        );
+        assert!(verifier_url(&verifier, Some(NamedChain::Mainnet.into())).is_err());
+
+        let verifier = VerifierArgs {
+            verifier_url: Some("http://127.0.0.1:3020/contract_verification".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            verifier_url(&verifier, Some(NamedChain::ZkSync.into())).unwrap().as_str(),
+            "http://127.0.0.1:3020/contract_verification"
+        );
+    }
+}
@@ -19,6 +19,8 @@ pub use zksolc::*;
 
 pub mod libraries;
 
+pub mod limits;
+
 use foundry_compilers::{
     artifacts::Severity,
     error::SolcError,
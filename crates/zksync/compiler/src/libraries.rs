@@ -1,15 +1,22 @@
 //! Handles resolution nd storage of missing libraries emitted by zksolc
 
 use std::{
+    collections::HashSet,
     fs,
     io::Write,
     path::{Path, PathBuf},
+    str::FromStr,
 };
 
+use alloy_primitives::Address;
 use serde::{Deserialize, Serialize};
 use tracing::{trace, warn};
+use zksync_types::{utils::deployed_address_create, H256};
 
-use foundry_compilers::info::ContractInfo;
+use foundry_compilers::{
+    artifacts::Libraries, info::ContractInfo,
+    zksync::compile::output::ProjectCompileOutput as ZkProjectCompileOutput,
+};
 
 /// Missing Library entry
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -22,6 +29,69 @@ pub struct ZkMissingLibrary {
     pub missing_libraries: Vec<String>,
 }
 
+/// Libraries to deploy for the zksolc output to be fully linked, see [`link_missing_libraries`].
+#[derive(Debug, Default, Clone)]
+pub struct ZkLinkOutput {
+    /// All the libraries the contracts are linked with, including the ones to deploy.
+    pub libraries: Libraries,
+    /// Libraries to deploy, in deployment order.
+    pub libs_to_deploy: Vec<ContractInfo>,
+}
+
+/// A library to be deployed through the `ContractDeployer`.
+#[derive(Debug, Clone)]
+pub struct ZkLibraryDeployment {
+    /// Bytecode hash of the library.
+    pub bytecode_hash: H256,
+    /// Factory dependencies of the deployment, including the library bytecode.
+    pub factory_deps: Vec<Vec<u8>>,
+}
+
+impl ZkLinkOutput {
+    /// Returns the deployments of the libraries to deploy, from the zksolc output the libraries
+    /// were linked in.
+    pub fn deployments(
+        &self,
+        project_root: impl AsRef<Path>,
+        output: &ZkProjectCompileOutput,
+    ) -> eyre::Result<Vec<ZkLibraryDeployment>> {
+        self.libs_to_deploy
+            .iter()
+            .map(|lib| {
+                let path = project_root.as_ref().join(lib.path.as_deref().unwrap_or_default());
+                let artifact = output
+                    .find(&path, &lib.name)
+                    .ok_or_else(|| eyre::eyre!("could not find library {lib} in zksolc output"))?;
+                let (Some(hash), Some(bytecode)) = (&artifact.hash, &artifact.bytecode) else {
+                    eyre::bail!("library {lib} was not compiled by zksolc")
+                };
+
+                let mut factory_deps = Vec::new();
+                for dep_hash in artifact.factory_dependencies.iter().flat_map(|deps| deps.keys()) {
+                    let dep = output
+                        .artifacts()
+                        .find_map(|(_, dep)| {
+                            (dep.hash.as_ref() == Some(dep_hash))
+                                .then(|| dep.bytecode.as_ref()?.object.as_bytes().cloned())
+                                .flatten()
+                        })
+                        .ok_or_else(|| {
+                            eyre::eyre!("could not find factory dependency {dep_hash} of {lib}")
+                        })?;
+                    factory_deps.push(dep.to_vec());
+                }
+                let bytecode = bytecode
+                    .object
+                    .as_bytes()
+                    .ok_or_else(|| eyre::eyre!("library {lib} is not fully linked"))?;
+                factory_deps.push(bytecode.to_vec());
+
+                Ok(ZkLibraryDeployment { bytecode_hash: H256::from_str(hash)?, factory_deps })
+            })
+            .collect()
+    }
+}
+
 /// Return the missing libraries cache path
 pub(crate) fn get_missing_libraries_cache_path(project_root: impl AsRef<Path>) -> PathBuf {
     project_root.as_ref().join(".zksolc-libraries-cache/missing_library_dependencies.json")
@@ -39,6 +109,58 @@ pub fn add_dependencies_to_missing_libraries_cache(
     Ok(())
 }
 
+/// Returns the libraries that are missing for the contracts of the given zksolc output to be fully
+/// linked, along with their own missing libraries.
+///
+/// Only the contracts of the given files are considered, or all of them if `files` is empty.
+pub fn get_missing_libraries(
+    project_root: impl AsRef<Path>,
+    output: &ZkProjectCompileOutput,
+    files: &[PathBuf],
+) -> Vec<ZkMissingLibrary> {
+    let mut missing_libs_unique: HashSet<String> = HashSet::new();
+    for (artifact_id, artifact) in output.artifact_ids() {
+        // TODO: when compiling specific files, the output might still add cached artifacts
+        // that are not part of the file list to the output, which may cause missing libraries
+        // error to trigger for files that were not intended to be compiled.
+        // This behaviour needs to be investigated better on the foundry-compilers side.
+        // For now we filter, checking only the files passed to compile.
+        let is_target_file = files.is_empty() || files.iter().any(|f| artifact_id.path == *f);
+        if is_target_file {
+            if let Some(mls) = &artifact.missing_libraries {
+                missing_libs_unique.extend(mls.clone());
+            }
+        }
+    }
+
+    missing_libs_unique
+        .into_iter()
+        .map(|ml| {
+            let mut split = ml.split(':');
+            let contract_path =
+                split.next().expect("Failed to extract contract path for missing library");
+            let contract_name =
+                split.next().expect("Failed to extract contract name for missing library");
+
+            let mut abs_path_buf = PathBuf::new();
+            abs_path_buf.push(project_root.as_ref());
+            abs_path_buf.push(contract_path);
+
+            let art = output.find(abs_path_buf.as_path(), contract_name).unwrap_or_else(|| {
+                panic!(
+                    "Could not find contract {contract_name} at path {contract_path} for compilation output"
+                )
+            });
+
+            ZkMissingLibrary {
+                contract_path: contract_path.to_string(),
+                contract_name: contract_name.to_string(),
+                missing_libraries: art.missing_libraries.clone().unwrap_or_default(),
+            }
+        })
+        .collect()
+}
+
 /// Returns the detected missing libraries from previous compilation
 pub fn get_detected_missing_libraries(
     project_root: impl AsRef<Path>,
@@ -127,3 +249,49 @@ pub fn resolve_libraries(
         batches.push(batch);
     }
 }
+
+/// Computes the addresses the missing libraries will have once deployed by `deployer` through the
+/// `ContractDeployer`, starting from its `deploy_nonce`.
+///
+/// Libraries already present in `libraries` are not deployed again, the others are deployed in the
+/// batched order returned by [`resolve_libraries`], so that each library is deployed after its own
+/// dependencies.
+pub fn link_missing_libraries(
+    missing_libraries: Vec<ZkMissingLibrary>,
+    libraries: Libraries,
+    deployer: Address,
+    mut deploy_nonce: u64,
+) -> eyre::Result<ZkLinkOutput> {
+    let already_deployed_libraries = libraries
+        .libs
+        .iter()
+        .flat_map(|(path, libs)| {
+            libs.keys().map(|name| ContractInfo {
+                path: Some(path.to_string_lossy().to_string()),
+                name: name.clone(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut output = ZkLinkOutput { libraries, libs_to_deploy: Vec::new() };
+    for lib in
+        resolve_libraries(missing_libraries, &already_deployed_libraries)?.into_iter().flatten()
+    {
+        let address = deployed_address_create(
+            zksync_types::Address::from_slice(deployer.as_slice()),
+            deploy_nonce.into(),
+        );
+        deploy_nonce += 1;
+        trace!(?lib, ?address, "linking missing library");
+
+        output
+            .libraries
+            .libs
+            .entry(lib.path.clone().unwrap_or_default().into())
+            .or_default()
+            .insert(lib.name.clone(), Address::from_slice(address.as_bytes()).to_checksum(None));
+        output.libs_to_deploy.push(lib);
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,98 @@
+//! EraVM limits on the bytecodes that can be deployed.
+//!
+//! See <https://docs.zksync.io/build/developer-reference/ethereum-differences/contract-deployment#contract-size-limit-and-format-of-bytecode-hash>
+
+use zksync_types::MAX_NEW_FACTORY_DEPS;
+use zksync_utils::bytecode::validate_bytecode;
+
+/// Size of an EraVM word, in bytes.
+pub const WORD_SIZE: usize = 32;
+
+/// Maximum total size of the bytecodes published by a transaction, in bytes.
+///
+/// Factory dependencies are published on L1 as pubdata, so this is bounded by the pubdata a single
+/// transaction can publish, which is also the effective contract size limit. It is far below the
+/// bytecode length limit of `u16::MAX` words checked by [validate_bytecode].
+pub const MAX_PUBLISHED_BYTECODES_SIZE: usize = 450999;
+
+/// Maximum number of factory dependencies of a transaction.
+pub const MAX_FACTORY_DEPS: usize = MAX_NEW_FACTORY_DEPS;
+
+const FALLBACK_OZ_HINT: &str =
+    "Consider enabling `fallback_oz` (`--zk-fallback-oz`) to optimize the contracts for size.";
+
+/// Checks that the given bytecode is a valid EraVM bytecode that fits the size limits.
+pub fn check_bytecode(name: &str, bytecode: &[u8]) -> eyre::Result<()> {
+    validate_bytecode(bytecode)
+        .map_err(|err| eyre::eyre!("`{name}` is not a valid EraVM bytecode: {err}"))?;
+
+    let size = bytecode.len();
+    if size > MAX_PUBLISHED_BYTECODES_SIZE {
+        eyre::bail!(
+            "`{name}` is above the contract size limit ({size} > {MAX_PUBLISHED_BYTECODES_SIZE} bytes).\n{FALLBACK_OZ_HINT}"
+        )
+    }
+
+    Ok(())
+}
+
+/// Checks that the factory dependencies of a transaction, given with the name of their contract,
+/// can be published by a single transaction.
+pub fn check_factory_deps<'a>(
+    factory_deps: impl IntoIterator<Item = (&'a str, &'a [u8])>,
+) -> eyre::Result<()> {
+    let mut count = 0;
+    let mut total_size = 0;
+    let mut largest: Option<(&str, usize)> = None;
+    for (name, bytecode) in factory_deps {
+        check_bytecode(name, bytecode)?;
+
+        count += 1;
+        total_size += bytecode.len();
+        if largest.map_or(true, |(_, size)| bytecode.len() > size) {
+            largest = Some((name, bytecode.len()));
+        }
+    }
+
+    if count > MAX_FACTORY_DEPS {
+        eyre::bail!(
+            "transaction has too many factory dependencies ({count} > {MAX_FACTORY_DEPS}), deploy some of them separately"
+        )
+    }
+    if total_size > MAX_PUBLISHED_BYTECODES_SIZE {
+        let (name, size) = largest.unwrap_or_default();
+        eyre::bail!(
+            "factory dependencies of the transaction are above the pubdata limit ({total_size} > {MAX_PUBLISHED_BYTECODES_SIZE} bytes), the largest one is `{name}` ({size} bytes).\n{FALLBACK_OZ_HINT}"
+        )
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_check_bytecode_limits() {
+        assert!(check_bytecode("A", &[0; WORD_SIZE]).is_ok());
+        assert!(check_bytecode("A", &[0; WORD_SIZE + 1]).is_err());
+        assert!(check_bytecode("A", &[0; WORD_SIZE * 2]).is_err());
+
+        let too_large = vec![0; ((MAX_PUBLISHED_BYTECODES_SIZE / WORD_SIZE + 1) | 1) * WORD_SIZE];
+        let err = check_bytecode("A", &too_large).unwrap_err().to_string();
+        assert!(err.contains("fallback_oz"), "{err}");
+    }
+
+    #[test]
+    fn can_check_factory_deps_limits() {
+        let dep = vec![0; (MAX_PUBLISHED_BYTECODES_SIZE / WORD_SIZE / 2 | 1) * WORD_SIZE];
+        let dep = dep.as_slice();
+        assert!(check_factory_deps([("A", dep)]).is_ok());
+        assert!(check_factory_deps([("A", dep), ("B", dep), ("C", dep)]).is_err());
+
+        let small = [0; WORD_SIZE];
+        let deps = (0..=MAX_FACTORY_DEPS).map(|_| ("A", small.as_slice()));
+        assert!(check_factory_deps(deps).is_err());
+    }
+}
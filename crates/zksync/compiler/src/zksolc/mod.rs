@@ -5,14 +5,22 @@ use std::{
 };
 
 use foundry_compilers::{
-    solc::SolcLanguage, zksync::compile::output::ProjectCompileOutput as ZkProjectCompileOutput,
-    Artifact, ArtifactOutput, ConfigurableArtifacts, ProjectCompileOutput, ProjectPathsConfig,
+    solc::SolcLanguage,
+    zksync::{
+        artifact_output::zk::ZkContractArtifact,
+        compile::output::ProjectCompileOutput as ZkProjectCompileOutput,
+    },
+    Artifact, ArtifactId, ArtifactOutput, ConfigurableArtifacts, ProjectCompileOutput,
+    ProjectPathsConfig,
 };
 
 use alloy_primitives::{keccak256, B256};
 use tracing::debug;
 use zksync_types::H256;
 
+mod source_map;
+pub use source_map::{EraVmSourceElement, EraVmSourceMap};
+
 /// Defines a contract that has been dual compiled with both zksolc and solc
 #[derive(Debug, Default, Clone)]
 pub struct DualCompiledContract {
@@ -89,20 +97,7 @@ impl DualCompiledContracts {
             }
         }
 
-        // DualCompiledContracts uses a vec of bytecodes as factory deps field vs
-        // the <hash, name> map zksolc outputs, hence we need all bytecodes upfront to
-        // then do the conversion
-        let mut zksolc_all_bytecodes: HashMap<String, Vec<u8>> = Default::default();
-        for (_, zk_artifact) in zk_output.artifacts() {
-            if let (Some(hash), Some(bytecode)) = (&zk_artifact.hash, &zk_artifact.bytecode) {
-                // TODO: we can do this because no bytecode object could be unlinked
-                // at this stage for zksolc, and BytecodeObject as ref will get the bytecode bytes.
-                // We should be careful however and check/handle errors in
-                // case an Unlinked BytecodeObject gets here somehow
-                let bytes = bytecode.object.clone().into_bytes().unwrap();
-                zksolc_all_bytecodes.insert(hash.clone(), bytes.to_vec());
-            }
-        }
+        let zksolc_all_bytecodes = zksolc_all_bytecodes(zk_output);
 
         for (contract_name, (artifact_path, artifact)) in zk_output_artifacts {
             let contract_file = artifact_path
@@ -115,56 +110,106 @@ impl DualCompiledContracts {
                 })
                 .to_path_buf();
 
-            let maybe_bytecode = &artifact.bytecode;
-            let maybe_hash = &artifact.hash;
-            let maybe_factory_deps = &artifact.factory_dependencies;
-
-            if let (Some(bytecode), Some(hash), Some(factory_deps_map)) =
-                (maybe_bytecode, maybe_hash, maybe_factory_deps)
+            if let Some((solc_bytecode, solc_deployed_bytecode)) =
+                solc_bytecodes.get(&contract_file)
             {
-                if let Some((solc_bytecode, solc_deployed_bytecode)) =
-                    solc_bytecodes.get(&contract_file)
-                {
-                    // TODO: we can do this because no bytecode object could be unlinked
-                    // at this stage for zksolc, and BytecodeObject as ref will get the bytecode
-                    // bytes. However, we should check and
-                    // handle errors in case an Unlinked BytecodeObject gets
-                    // here somehow
-                    let bytecode_vec = bytecode.object.clone().into_bytes().unwrap().to_vec();
-                    let mut factory_deps_vec: Vec<Vec<u8>> = factory_deps_map
-                        .keys()
-                        .map(|factory_hash| zksolc_all_bytecodes.get(factory_hash).unwrap())
-                        .cloned()
-                        .collect();
-
-                    factory_deps_vec.push(bytecode_vec.clone());
-
-                    dual_compiled_contracts.push(DualCompiledContract {
-                        name: contract_name,
-                        zk_bytecode_hash: H256::from_str(hash).unwrap(),
-                        zk_deployed_bytecode: bytecode_vec,
-                        zk_factory_deps: factory_deps_vec,
-                        evm_bytecode_hash: keccak256(solc_deployed_bytecode),
-                        evm_bytecode: solc_bytecode.to_vec(),
-                        evm_deployed_bytecode: solc_deployed_bytecode.to_vec(),
-                    });
-                } else {
-                    tracing::error!("matching solc artifact not found for {contract_file:?}");
+                if let Some(contract) = new_dual_compiled_contract(
+                    contract_name,
+                    artifact,
+                    &zksolc_all_bytecodes,
+                    solc_bytecode,
+                    solc_deployed_bytecode,
+                ) {
+                    dual_compiled_contracts.push(contract);
                 }
+            } else if artifact.bytecode.is_some() {
+                tracing::error!("matching solc artifact not found for {contract_file:?}");
             }
         }
 
         Self { contracts: dual_compiled_contracts }
     }
 
+    /// Adds the contracts whose solc bytecode is only known once linked with their libraries,
+    /// matching the linked solc artifacts with the zksolc artifacts of the same source and name.
+    ///
+    /// Contracts that are already part of the collection are skipped.
+    pub fn extend_with_linked<'a>(
+        &mut self,
+        linked: impl IntoIterator<Item = (&'a ArtifactId, &'a [u8], &'a [u8])>,
+        zk_output: &ZkProjectCompileOutput,
+    ) {
+        let zksolc_all_bytecodes = zksolc_all_bytecodes(zk_output);
+        let zk_artifacts = zk_output.artifact_ids().collect::<Vec<_>>();
+
+        for (id, solc_bytecode, solc_deployed_bytecode) in linked {
+            let Some((_, artifact)) = zk_artifacts
+                .iter()
+                .find(|(zk_id, _)| zk_id.source == id.source && zk_id.name == id.name)
+            else {
+                continue
+            };
+            let Some(contract) = new_dual_compiled_contract(
+                id.name.clone(),
+                artifact,
+                &zksolc_all_bytecodes,
+                solc_bytecode,
+                solc_deployed_bytecode,
+            ) else {
+                continue
+            };
+
+            if self.find_by_zk_bytecode_hash(contract.zk_bytecode_hash).is_none() {
+                debug!(name = contract.name, "adding linked dual compiled contract");
+                self.contracts.push(contract);
+            }
+        }
+    }
+
     /// Finds a contract matching the ZK deployed bytecode
     pub fn find_by_zk_deployed_bytecode(&self, bytecode: &[u8]) -> Option<&DualCompiledContract> {
-        self.contracts.iter().find(|contract| bytecode.starts_with(&contract.zk_deployed_bytecode))
+        self.contracts.iter().find(|contract| contract.zk_deployed_bytecode == bytecode)
     }
 
     /// Finds a contract matching the EVM bytecode
     pub fn find_by_evm_bytecode(&self, bytecode: &[u8]) -> Option<&DualCompiledContract> {
-        self.contracts.iter().find(|contract| bytecode.starts_with(&contract.evm_bytecode))
+        self.contracts.iter().find(|contract| contract.evm_bytecode == bytecode)
+    }
+
+    /// Finds a contract whose EVM bytecode, without constructor arguments, has the given hash
+    pub fn find_by_evm_init_code_hash(
+        &self,
+        init_code_hash: B256,
+    ) -> Option<&DualCompiledContract> {
+        self.contracts.iter().find(|contract| keccak256(&contract.evm_bytecode) == init_code_hash)
+    }
+
+    /// Finds a contract deployed with the given init code, which is either the solc or the zksolc
+    /// bytecode of the contract followed by the constructor arguments.
+    ///
+    /// The constructor arguments are ABI-encoded, so they are a whole number of words. As the
+    /// bytecode of a contract can be a prefix of the bytecode of another one, the longest bytecode
+    /// matching the init code is used.
+    ///
+    /// Returns the contract along with the constructor arguments.
+    pub fn find_by_init_code<'a>(
+        &self,
+        init_code: &'a [u8],
+    ) -> Option<(&DualCompiledContract, &'a [u8])> {
+        self.contracts
+            .iter()
+            .flat_map(|contract| {
+                [contract.evm_bytecode.as_slice(), contract.zk_deployed_bytecode.as_slice()]
+                    .into_iter()
+                    .filter(|bytecode| {
+                        !bytecode.is_empty() &&
+                            init_code.starts_with(bytecode) &&
+                            (init_code.len() - bytecode.len()) % 32 == 0
+                    })
+                    .map(move |bytecode| (contract, bytecode.len()))
+            })
+            .max_by_key(|(_, bytecode_len)| *bytecode_len)
+            .map(|(contract, bytecode_len)| (contract, &init_code[bytecode_len..]))
     }
 
     /// Finds a contract matching the ZK bytecode hash
@@ -215,3 +260,98 @@ impl DualCompiledContracts {
         self.contracts.push(contract);
     }
 }
+
+/// Returns all the bytecodes of the zksolc output by their hash.
+///
+/// DualCompiledContracts uses a vec of bytecodes as factory deps field vs the <hash, name> map
+/// zksolc outputs, hence we need all bytecodes upfront to then do the conversion.
+fn zksolc_all_bytecodes(zk_output: &ZkProjectCompileOutput) -> HashMap<String, Vec<u8>> {
+    let mut zksolc_all_bytecodes: HashMap<String, Vec<u8>> = Default::default();
+    for (_, zk_artifact) in zk_output.artifacts() {
+        if let (Some(hash), Some(bytecode)) = (&zk_artifact.hash, &zk_artifact.bytecode) {
+            // TODO: we can do this because no bytecode object could be unlinked
+            // at this stage for zksolc, and BytecodeObject as ref will get the bytecode bytes.
+            // We should be careful however and check/handle errors in
+            // case an Unlinked BytecodeObject gets here somehow
+            let bytes = bytecode.object.clone().into_bytes().unwrap();
+            zksolc_all_bytecodes.insert(hash.clone(), bytes.to_vec());
+        }
+    }
+    zksolc_all_bytecodes
+}
+
+/// Creates a `[DualCompiledContract]` from the zksolc artifact and the matching solc bytecodes.
+fn new_dual_compiled_contract(
+    name: String,
+    artifact: &ZkContractArtifact,
+    zksolc_all_bytecodes: &HashMap<String, Vec<u8>>,
+    solc_bytecode: &[u8],
+    solc_deployed_bytecode: &[u8],
+) -> Option<DualCompiledContract> {
+    let (Some(bytecode), Some(hash), Some(factory_deps_map)) =
+        (&artifact.bytecode, &artifact.hash, &artifact.factory_dependencies)
+    else {
+        return None
+    };
+
+    // TODO: we can do this because no bytecode object could be unlinked
+    // at this stage for zksolc, and BytecodeObject as ref will get the bytecode
+    // bytes. However, we should check and
+    // handle errors in case an Unlinked BytecodeObject gets
+    // here somehow
+    let bytecode_vec = bytecode.object.clone().into_bytes().unwrap().to_vec();
+    let mut factory_deps_vec: Vec<Vec<u8>> = factory_deps_map
+        .keys()
+        .map(|factory_hash| zksolc_all_bytecodes.get(factory_hash).unwrap())
+        .cloned()
+        .collect();
+
+    factory_deps_vec.push(bytecode_vec.clone());
+
+    Some(DualCompiledContract {
+        name,
+        zk_bytecode_hash: H256::from_str(hash).unwrap(),
+        zk_deployed_bytecode: bytecode_vec,
+        zk_factory_deps: factory_deps_vec,
+        evm_bytecode_hash: keccak256(solc_deployed_bytecode),
+        evm_bytecode: solc_bytecode.to_vec(),
+        evm_deployed_bytecode: solc_deployed_bytecode.to_vec(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_find_contract_by_init_code() {
+        let mut contracts = DualCompiledContracts::default();
+        let short = DualCompiledContract {
+            name: "Short".to_string(),
+            evm_bytecode: vec![1; 64],
+            ..Default::default()
+        };
+        let long = DualCompiledContract {
+            name: "Long".to_string(),
+            evm_bytecode: vec![1; 96],
+            ..Default::default()
+        };
+        contracts.push(short);
+        contracts.push(long);
+
+        // the bytecode of `Short` is a prefix of the one of `Long`
+        let init_code = [[1u8; 96], [2; 96]].concat();
+        let (contract, args) = contracts.find_by_init_code(&init_code).unwrap();
+        assert_eq!(contract.name, "Long");
+        assert_eq!(args, [2; 96]);
+
+        let init_code = [[1u8; 64], [2; 32]].concat();
+        let (contract, args) = contracts.find_by_init_code(&init_code).unwrap();
+        assert_eq!(contract.name, "Short");
+        assert_eq!(args, [2; 32]);
+
+        // constructor arguments are a whole number of words
+        assert!(contracts.find_by_init_code(&[vec![1u8; 64], vec![2; 31]].concat()).is_none());
+        assert!(contracts.find_by_evm_bytecode(&[1; 65]).is_none());
+    }
+}
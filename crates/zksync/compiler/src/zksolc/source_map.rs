@@ -0,0 +1,117 @@
+//! EraVM source maps.
+//!
+//! zksolc does not output source maps, instead the EraVM assembly it emits carries the source
+//! location of each instruction as `.loc` directives.
+
+use std::{collections::HashMap, path::PathBuf};
+
+/// The source location of an EraVM instruction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EraVmSourceElement {
+    /// The file number, as declared by the `.file` directives.
+    pub file: u32,
+    /// The line in the source file.
+    pub line: usize,
+}
+
+/// Maps EraVM instructions to their source locations, parsed from the EraVM assembly.
+#[derive(Clone, Debug, Default)]
+pub struct EraVmSourceMap {
+    /// The source file paths, keyed by their file number.
+    pub files: HashMap<u32, PathBuf>,
+    /// The source location of each instruction, indexed by program counter.
+    pub elements: Vec<Option<EraVmSourceElement>>,
+}
+
+impl EraVmSourceMap {
+    /// Parses the source map from the given EraVM assembly.
+    ///
+    /// Each instruction in the code section is one EraVM program counter, and is mapped to the
+    /// location given by the last `.loc` directive preceding it.
+    pub fn parse(assembly: &str) -> Self {
+        let mut source_map = Self::default();
+        let mut in_code_section = true;
+        let mut current = None;
+
+        for line in assembly.lines() {
+            let line = line.split(';').next().unwrap_or_default().trim();
+            if line.is_empty() || line.ends_with(':') {
+                continue;
+            }
+
+            let mut parts = line.split_whitespace();
+            let Some(head) = parts.next() else { continue };
+            match head {
+                ".text" => in_code_section = true,
+                ".rodata" | ".data" | ".bss" => in_code_section = false,
+                ".section" => {
+                    in_code_section = parts.next().is_some_and(|name| name.starts_with(".text"))
+                }
+                ".file" => {
+                    // `.file <number> "<path>"` or `.file <number> "<directory>" "<file>"`, the
+                    // unnumbered variant only names the module.
+                    let Some(number) = parts.next().and_then(|number| number.parse().ok()) else {
+                        continue;
+                    };
+                    let path = line
+                        .split('"')
+                        .skip(1)
+                        .step_by(2)
+                        .fold(PathBuf::new(), |path, component| path.join(component));
+                    source_map.files.insert(number, path);
+                }
+                ".loc" => {
+                    let file = parts.next().and_then(|file| file.parse().ok());
+                    let line = parts.next().and_then(|line| line.parse().ok());
+                    current = file
+                        .zip(line)
+                        .filter(|(_, line)| *line != 0)
+                        .map(|(file, line)| EraVmSourceElement { file, line });
+                }
+                directive if directive.starts_with('.') => {}
+                _ if in_code_section => source_map.elements.push(current.clone()),
+                _ => {}
+            }
+        }
+
+        source_map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_eravm_assembly_source_map() {
+        let assembly = r#"
+	.text
+	.file	"Counter.sol:Counter"
+	.file	1 "src/Counter.sol"
+	.globl	__entry
+__entry:
+.func_begin0:
+	add	128, r0, r3
+	.loc	1 7 9 prologue_end
+	st.1	64, r3
+	sub.s!	0, r2, r0 ; check the call type
+	.loc	1 0 0
+	jump.eq	@.BB0_1
+	.rodata
+CPI0_0:
+	.cell 42
+"#;
+        let source_map = EraVmSourceMap::parse(assembly);
+
+        assert_eq!(source_map.files.get(&1), Some(&PathBuf::from("src/Counter.sol")));
+        assert_eq!(
+            source_map.elements,
+            vec![
+                None,
+                Some(EraVmSourceElement { file: 1, line: 7 }),
+                Some(EraVmSourceElement { file: 1, line: 7 }),
+                None,
+            ]
+        );
+    }
+}
@@ -17,6 +17,9 @@ use zksync_utils::bytecode::hash_bytecode;
 
 use crate::{
     convert::{ConvertAddress, ConvertH160, ConvertH256, ConvertRU256, ConvertU256},
+    state::{
+        get_account_code_storage, get_nonce_storage, new_full_nonce, parse_full_nonce, FullNonce,
+    },
     EMPTY_CODE,
 };
 
@@ -115,6 +118,54 @@ where
     tx_nonce.to_ru256()
 }
 
+/// Gets the deployment nonce for a specific address.
+pub fn get_deploy_nonce<DB>(address: Address, ecx: &mut InnerEvmContext<DB>) -> rU256
+where
+    DB: Database,
+    <DB as Database>::Error: Debug,
+{
+    info!(?address, "cheatcode zkGetDeployNonce");
+
+    let (nonce_addr, nonce_key) = get_nonce_storage(address);
+    ecx.load_account(nonce_addr).expect("account could not be loaded");
+    let (full_nonce, _) = ecx.sload(nonce_addr, nonce_key).unwrap_or_default();
+
+    rU256::from(parse_full_nonce(full_nonce).deploy_nonce)
+}
+
+/// Sets the deployment nonce for a specific address, keeping its transaction nonce.
+pub fn set_deploy_nonce<DB>(address: Address, nonce: u64, ecx: &mut InnerEvmContext<DB>)
+where
+    DB: Database,
+    <DB as Database>::Error: Debug,
+{
+    info!(?address, ?nonce, "cheatcode zkSetDeployNonce");
+
+    let (nonce_addr, nonce_key) = get_nonce_storage(address);
+    ecx.load_account(nonce_addr).expect("account could not be loaded");
+    let (full_nonce, _) = ecx.sload(nonce_addr, nonce_key).unwrap_or_default();
+    let FullNonce { tx_nonce, .. } = parse_full_nonce(full_nonce);
+
+    ecx.touch(&nonce_addr);
+    ecx.sstore(nonce_addr, nonce_key, new_full_nonce(tx_nonce, nonce))
+        .expect("failed storing value");
+}
+
+/// Gets the bytecode hash for a specific address.
+pub fn get_account_code_hash<DB>(address: Address, ecx: &mut InnerEvmContext<DB>) -> B256
+where
+    DB: Database,
+    <DB as Database>::Error: Debug,
+{
+    info!(?address, "cheatcode zkGetAccountCodeHash");
+
+    let (account_code_addr, code_key) = get_account_code_storage(address);
+    ecx.load_account(account_code_addr).expect("account could not be loaded");
+    let (code_hash, _) = ecx.sload(account_code_addr, code_key).unwrap_or_default();
+
+    B256::from(code_hash.to_be_bytes())
+}
+
 /// Sets code for a specific address.
 pub fn etch<DB>(address: Address, bytecode: &[u8], ecx: &mut InnerEvmContext<DB>)
 where
@@ -87,6 +87,18 @@ impl ConvertH256 for H256 {
     }
 }
 
+/// Conversions from [B256]
+pub trait ConvertB256 {
+    /// Convert to [H256]
+    fn to_h256(self) -> H256;
+}
+
+impl ConvertB256 for B256 {
+    fn to_h256(self) -> H256 {
+        H256(self.0)
+    }
+}
+
 /// Conversions from [H160]
 pub trait ConvertH160 {
     /// Convert to [Address]
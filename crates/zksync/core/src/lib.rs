@@ -19,7 +19,7 @@ pub mod vm;
 pub mod state;
 
 use alloy_network::{AnyNetwork, TxSigner};
-use alloy_primitives::{Address, Bytes, U256 as rU256};
+use alloy_primitives::{address, keccak256, Address, Bytes, B256, U256 as rU256};
 use alloy_provider::Provider;
 use alloy_rpc_types::TransactionRequest;
 use alloy_serde::WithOtherFields;
@@ -31,16 +31,19 @@ use convert::{
 };
 use eyre::{eyre, OptionExt};
 pub use utils::{fix_l2_gas_limit, fix_l2_gas_price};
-pub use vm::{balance, encode_create_params, nonce};
+pub use vm::{balance, decode_create_params, encode_create_params, nonce};
 
 use zksync_types::utils::storage_key_for_eth_balance;
 pub use zksync_types::{
-    ACCOUNT_CODE_STORAGE_ADDRESS, CONTRACT_DEPLOYER_ADDRESS, H256, L2_BASE_TOKEN_ADDRESS,
-    NONCE_HOLDER_ADDRESS,
+    transaction_request::PaymasterParams, ACCOUNT_CODE_STORAGE_ADDRESS, CONTRACT_DEPLOYER_ADDRESS,
+    H256, L2_BASE_TOKEN_ADDRESS, NONCE_HOLDER_ADDRESS,
 };
 pub use zksync_utils::bytecode::hash_bytecode;
 use zksync_web3_rs::{
-    eip712::{Eip712Meta, Eip712Transaction, Eip712TransactionRequest},
+    eip712::{
+        Eip712Meta, Eip712Transaction, Eip712TransactionRequest,
+        PaymasterParams as Eip712PaymasterParams,
+    },
     zks_provider::types::Fee,
     zks_utils::EIP712_TX_TYPE,
 };
@@ -50,6 +53,9 @@ type Result<T> = std::result::Result<T, eyre::Report>;
 /// Represents an empty code
 pub const EMPTY_CODE: [u8; 32] = [0; 32];
 
+/// Address of the `Create2Factory` contract, which is predeployed on all zkSync chains.
+pub const CREATE2_FACTORY_ADDRESS: Address = address!("0000000000000000000000000000000000010000");
+
 /// The minimum possible address that is not reserved in the zkSync space.
 const MIN_VALID_ADDRESS: u32 = 2u32.pow(16);
 
@@ -68,21 +74,103 @@ pub fn get_nonce_key(address: Address) -> rU256 {
     zksync_types::get_nonce_key(&address.to_h160()).key().to_ru256()
 }
 
+/// Returns the address of a contract deployed with `CREATE` by the given deployer, using its
+/// deployment nonce.
+///
+/// Unlike the EVM, zkSync derives the address from the deployment nonce of the deployer and not
+/// from its transaction nonce.
+pub fn compute_create_address(deployer: Address, deploy_nonce: u64) -> Address {
+    zksync_types::utils::deployed_address_create(deployer.to_h160(), deploy_nonce.into())
+        .to_address()
+}
+
+/// Returns the address of a contract deployed with `CREATE2` by the given deployer.
+///
+/// Unlike the EVM, zkSync derives the address from the bytecode hash of the contract (see
+/// [hash_bytecode]) and the hash of its constructor input.
+pub fn compute_create2_address(
+    deployer: Address,
+    salt: B256,
+    bytecode_hash: B256,
+    constructor_input_hash: B256,
+) -> Address {
+    let mut bytes = [0u8; 160];
+    bytes[..32].copy_from_slice(keccak256("zksyncCreate2").as_slice());
+    bytes[44..64].copy_from_slice(deployer.as_slice());
+    bytes[64..96].copy_from_slice(salt.as_slice());
+    bytes[96..128].copy_from_slice(bytecode_hash.as_slice());
+    bytes[128..].copy_from_slice(constructor_input_hash.as_slice());
+    Address::from_word(keccak256(bytes))
+}
+
+/// Offset added to the address of an L1 contract sending a priority transaction to get its L2
+/// sender, see `AddressAliasHelper.applyL1ToL2Alias`.
+const L1_TO_L2_ALIAS_OFFSET: Address = address!("1111000000000000000000000000000000001111");
+
+/// Returns the L2 sender of a priority (L1 to L2) transaction sent by the given L1 contract.
+pub fn apply_l1_to_l2_alias(address: Address) -> Address {
+    let aliased = rU256::from_be_slice(address.as_slice())
+        .wrapping_add(rU256::from_be_slice(L1_TO_L2_ALIAS_OFFSET.as_slice()));
+    // the address is truncated to its last 20 bytes, wrapping around on overflow
+    Address::from_word(aliased.into())
+}
+
 /// Represents additional data for ZK transactions.
 #[derive(Clone, Debug, Default)]
 pub struct ZkTransactionMetadata {
     /// Factory Deps for ZK transactions.
     pub factory_deps: Vec<Vec<u8>>,
+    /// Paymaster data for ZK transactions.
+    pub paymaster_data: Option<PaymasterParams>,
 }
 
 impl ZkTransactionMetadata {
-    /// Create a new [`ZkTransactionMetadata`] with the given factory deps
-    pub fn new(factory_deps: Vec<Vec<u8>>) -> Self {
-        Self { factory_deps }
+    /// Create a new [`ZkTransactionMetadata`] with the given factory deps and paymaster data
+    pub fn new(factory_deps: Vec<Vec<u8>>, paymaster_data: Option<PaymasterParams>) -> Self {
+        Self { factory_deps, paymaster_data }
+    }
+}
+
+/// Represents the smart account to send the next ZK transaction from, using zkSync's native
+/// account abstraction.
+#[derive(Clone, Debug, Default)]
+pub struct ZkAccountData {
+    /// Account address.
+    pub address: Address,
+    /// Transaction signature, as expected by the account's `validateTransaction`.
+    pub signature: Bytes,
+}
+
+impl ZkAccountData {
+    /// Create a new [`ZkAccountData`] with the given address and signature
+    pub fn new(address: Address, signature: Bytes) -> Self {
+        Self { address, signature }
+    }
+}
+
+/// Creates a new EIP-712 meta with the provided factory deps, optional paymaster params and
+/// optional gas per pubdata limit.
+fn new_eip712_meta(
+    factory_deps: Vec<Vec<u8>>,
+    paymaster_data: Option<PaymasterParams>,
+    gas_per_pubdata: Option<rU256>,
+) -> Eip712Meta {
+    let mut custom_data = Eip712Meta::new().factory_deps(factory_deps);
+    if let Some(gas_per_pubdata) = gas_per_pubdata {
+        custom_data.gas_per_pubdata = gas_per_pubdata.to_u256();
+    }
+    match paymaster_data {
+        Some(PaymasterParams { paymaster, paymaster_input }) => {
+            custom_data.paymaster_params(Eip712PaymasterParams { paymaster, paymaster_input })
+        }
+        None => custom_data,
     }
 }
 
-/// Creates a new signed EIP-712 transaction with the provided factory deps.
+/// Creates a new signed EIP-712 transaction with the provided factory deps and paymaster params.
+///
+/// The fee parameters which are not set in the transaction, as well as the gas per pubdata limit if
+/// not provided, are taken from `zks_estimateFee`.
 pub async fn new_eip712_transaction<
     P: Provider<T, AnyNetwork>,
     S: TxSigner<Signature> + Sync,
@@ -90,6 +178,8 @@ pub async fn new_eip712_transaction<
 >(
     tx: WithOtherFields<TransactionRequest>,
     factory_deps: Vec<Vec<u8>>,
+    paymaster_data: Option<PaymasterParams>,
+    gas_per_pubdata: Option<rU256>,
     provider: P,
     signer: S,
 ) -> Result<Bytes> {
@@ -106,7 +196,7 @@ pub async fn new_eip712_transaction<
     let gas_price = tx.gas_price.ok_or_eyre("`gas_price` cannot be empty")?;
 
     let data = tx.input.clone().into_input().unwrap_or_default();
-    let custom_data = Eip712Meta::new().factory_deps(factory_deps);
+    let custom_data = new_eip712_meta(factory_deps, paymaster_data, gas_per_pubdata);
 
     let mut deploy_request = Eip712TransactionRequest::new()
         .r#type(EIP712_TX_TYPE)
@@ -131,11 +221,16 @@ pub async fn new_eip712_transaction<
         .max_fee_per_gas(fee.max_fee_per_gas)
         .max_priority_fee_per_gas(fee.max_priority_fee_per_gas)
         .gas_price(gas_price);
+    if gas_per_pubdata.is_none() {
+        deploy_request.custom_data.gas_per_pubdata = fee.gas_per_pubdata_limit;
+    }
 
-    let signable: Eip712Transaction = deploy_request
+    let mut signable: Eip712Transaction = deploy_request
         .clone()
         .try_into()
         .map_err(|err| eyre!("failed converting deploy request to eip-712 tx {:?}", err))?;
+    // the conversion does not propagate the gas per pubdata limit
+    signable.gas_per_pubdata_byte_limit = deploy_request.custom_data.gas_per_pubdata;
 
     let mut signable = signable.to_signable_tx();
     let signature =
@@ -161,6 +256,8 @@ pub struct EstimatedGas {
 pub async fn estimate_gas<P: Provider<T, AnyNetwork>, T: Transport + Clone>(
     tx: &WithOtherFields<TransactionRequest>,
     factory_deps: Vec<Vec<u8>>,
+    paymaster_data: Option<PaymasterParams>,
+    gas_per_pubdata: Option<rU256>,
     provider: P,
 ) -> Result<EstimatedGas> {
     let to = tx
@@ -178,7 +275,7 @@ pub async fn estimate_gas<P: Provider<T, AnyNetwork>, T: Transport + Clone>(
         provider.get_gas_price().await?
     };
     let data = tx.input.clone().into_input().unwrap_or_default();
-    let custom_data = Eip712Meta::new().factory_deps(factory_deps);
+    let custom_data = new_eip712_meta(factory_deps, paymaster_data, gas_per_pubdata);
 
     let mut deploy_request = Eip712TransactionRequest::new()
         .r#type(EIP712_TX_TYPE)
@@ -221,3 +318,21 @@ pub fn to_safe_address(address: Address) -> Address {
         address
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_apply_l1_to_l2_alias() {
+        assert_eq!(
+            apply_l1_to_l2_alias(address!("0000000000000000000000000000000000000001")),
+            address!("1111000000000000000000000000000000001112")
+        );
+        // the alias wraps around
+        assert_eq!(
+            apply_l1_to_l2_alias(address!("ffffffffffffffffffffffffffffffffffffffff")),
+            address!("1111000000000000000000000000000000001110")
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use revm::primitives::{Address as rAddress, U256 as rU256};
 
 use zksync_types::{
-    get_nonce_key,
+    get_code_key, get_nonce_key,
     utils::{decompose_full_nonce, nonces_to_full_nonce, storage_key_for_eth_balance},
 };
 
@@ -23,6 +23,14 @@ pub fn get_nonce_storage(address: rAddress) -> (rAddress, rU256) {
     (account, slot)
 }
 
+/// Returns account code storage slot
+pub fn get_account_code_storage(address: rAddress) -> (rAddress, rU256) {
+    let code_key = get_code_key(&address.to_h160());
+    let account = code_key.address().to_address();
+    let slot = code_key.key().to_ru256();
+    (account, slot)
+}
+
 /// Returns full nonce value
 pub fn new_full_nonce(tx_nonce: u64, deploy_nonce: u64) -> rU256 {
     nonces_to_full_nonce(tx_nonce.into(), deploy_nonce.into()).to_ru256()
@@ -4,33 +4,49 @@
 /// in the Database object.
 /// This code doesn't do any mutatios to Database: after each transaction run, the Revm
 /// is usually collecing all the diffs - and applies them to database itself.
-use std::{collections::HashMap, fmt::Debug};
+use std::{collections::HashMap, fmt::Debug, sync::Arc};
 
-use alloy_primitives::{Address, U256 as rU256};
+use alloy_primitives::{keccak256, Address, U256 as rU256};
 use foundry_cheatcodes_common::record::RecordAccess;
-use revm::{primitives::Account, Database, EvmContext, InnerEvmContext};
+use revm::{primitives::Account, Database, InnerEvmContext};
 use zksync_basic_types::{L2ChainId, H160, H256, U256};
 use zksync_state::ReadStorage;
 use zksync_types::{
     get_code_key, get_nonce_key, get_system_context_init_logs,
     utils::{decompose_full_nonce, storage_key_for_eth_balance},
-    Nonce, StorageKey, StorageLog, StorageLogKind, StorageValue,
+    Nonce, StorageKey, StorageLogKind, StorageValue, CONTRACT_DEPLOYER_ADDRESS,
+    NONCE_HOLDER_ADDRESS,
 };
 
-use zksync_utils::{bytecode::hash_bytecode, h256_to_u256};
+use zksync_utils::h256_to_u256;
 
-use crate::convert::{ConvertAddress, ConvertH160, ConvertH256, ConvertRU256, ConvertU256};
+use crate::{
+    convert::{ConvertAddress, ConvertH160, ConvertH256, ConvertRU256, ConvertU256},
+    vm::{env::LoadedSystemContracts, ZkSystemContracts},
+};
 
 /// Default chain id
 pub(crate) const DEFAULT_CHAIN_ID: u32 = 31337;
 
+/// Storage slot of the `accountInfo` mapping of the `ContractDeployer`.
+const ACCOUNT_INFO_SLOT: u64 = 0;
+
+/// Storage slot of the `nonceValues` mapping of the `NonceHolder`.
+const NONCE_VALUES_SLOT: u64 = 1;
+
+/// `AccountNonceOrdering.Arbitrary`, for accounts that can use their nonces in any order.
+const NONCE_ORDERING_ARBITRARY: u8 = 1;
+
 pub struct ZKVMData<'a, DB: Database> {
     // pub db: &'a mut DB,
     // pub journaled_state: &'a mut JournaledState,
     ecx: &'a mut InnerEvmContext<DB>,
     pub factory_deps: HashMap<H256, Vec<u8>>,
+    system_contracts: Option<Arc<LoadedSystemContracts>>,
     pub override_keys: HashMap<StorageKey, StorageValue>,
     pub accesses: Option<&'a mut RecordAccess>,
+    /// The storage keys read while recording the accesses, in order
+    pub storage_reads: Vec<StorageKey>,
 }
 
 impl<'a, DB> Debug for ZKVMData<'a, DB>
@@ -53,69 +69,42 @@ where
     <DB as Database>::Error: Debug,
 {
     /// Create a new instance of [ZKEVMData].
+    ///
+    /// The bytecodes deployed in the JournaledState are loaded lazily as factory deps.
     pub fn new(ecx: &'a mut InnerEvmContext<DB>) -> Self {
-        // load all deployed contract bytecodes from the JournaledState as factory deps
-        let mut factory_deps = ecx
-            .journaled_state
-            .state
-            .values()
-            .flat_map(|account| {
-                if account.info.is_empty_code_hash() {
-                    None
-                } else {
-                    account.info.code.as_ref().map(|code| {
-                        (H256::from(account.info.code_hash.0), code.bytecode().to_vec())
-                    })
-                }
-            })
-            .collect::<HashMap<_, _>>();
-
-        let empty_code = vec![0u8; 32];
-        let empty_code_hash = hash_bytecode(&empty_code);
-        factory_deps.insert(empty_code_hash, empty_code);
-        Self { ecx, factory_deps, override_keys: Default::default(), accesses: None }
+        Self {
+            ecx,
+            factory_deps: Default::default(),
+            system_contracts: None,
+            override_keys: Default::default(),
+            accesses: None,
+            storage_reads: Default::default(),
+        }
     }
 
     /// Create a new instance of [ZKEVMData] with system contracts.
-    pub fn new_with_system_contracts(ecx: &'a mut EvmContext<DB>, chain_id: L2ChainId) -> Self {
-        let contracts = era_test_node::system_contracts::get_deployed_contracts(
-            &era_test_node::system_contracts::Options::BuiltInWithoutSecurity,
-        );
-        let system_context_init_log = get_system_context_init_logs(chain_id);
-
-        let mut override_keys = HashMap::default();
-        contracts
-            .iter()
-            .map(|contract| {
-                let deployer_code_key = get_code_key(contract.account_id.address());
-                StorageLog::new_write_log(deployer_code_key, hash_bytecode(&contract.bytecode))
-            })
-            .chain(system_context_init_log)
-            .for_each(|log| {
-                (log.kind == StorageLogKind::Write)
-                    .then_some(override_keys.insert(log.key, log.value));
-            });
-
-        let mut factory_deps = contracts
-            .into_iter()
-            .map(|contract| (hash_bytecode(&contract.bytecode), contract.bytecode))
-            .collect::<HashMap<_, _>>();
-        factory_deps.extend(ecx.journaled_state.state.values().flat_map(|account| {
-            if account.info.is_empty_code_hash() {
-                None
-            } else {
-                account
-                    .info
-                    .code
-                    .as_ref()
-                    .map(|code| (H256::from(account.info.code_hash.0), code.bytecode().to_vec()))
+    pub fn new_with_system_contracts(
+        ecx: &'a mut InnerEvmContext<DB>,
+        chain_id: L2ChainId,
+        system_contracts: &ZkSystemContracts,
+    ) -> Self {
+        let system_contracts = system_contracts.load();
+
+        let mut override_keys = system_contracts.code_storage.clone();
+        for log in get_system_context_init_logs(chain_id) {
+            if log.kind == StorageLogKind::Write {
+                override_keys.insert(log.key, log.value);
             }
-        }));
-        let empty_code = vec![0u8; 32];
-        let empty_code_hash = hash_bytecode(&empty_code);
-        factory_deps.insert(empty_code_hash, empty_code);
+        }
 
-        Self { ecx, factory_deps, override_keys, accesses: None }
+        Self {
+            ecx,
+            factory_deps: Default::default(),
+            system_contracts: Some(system_contracts),
+            override_keys,
+            accesses: None,
+            storage_reads: Default::default(),
+        }
     }
 
     /// Extends the currently known factory deps with the provided input
@@ -146,6 +135,36 @@ where
         Nonce(tx_nonce.as_u32())
     }
 
+    /// Returns the nonce to send the next transaction of the given account with.
+    ///
+    /// Accounts with arbitrary nonce ordering can use any nonce that was not used yet, which are
+    /// the ones above their minimal nonce with no value set in the NonceHolder, so the lowest one
+    /// is returned.
+    pub fn get_next_tx_nonce(&mut self, address: Address) -> Nonce {
+        let mut nonce = self.get_tx_nonce(address);
+        if self.get_nonce_ordering(address) != NONCE_ORDERING_ARBITRARY {
+            return nonce
+        }
+
+        let nonce_values = mapping_key(address.to_h256(), H256::from_low_u64_be(NONCE_VALUES_SLOT));
+        loop {
+            let nonce_key = mapping_key(H256::from_low_u64_be(nonce.0.into()), nonce_values);
+            if self.read_db(NONCE_HOLDER_ADDRESS, h256_to_u256(nonce_key)).is_zero() {
+                return nonce
+            }
+            nonce = Nonce(nonce.0 + 1);
+        }
+    }
+
+    /// Returns the nonce ordering of the given account from ContractDeployer storage.
+    fn get_nonce_ordering(&mut self, address: Address) -> u8 {
+        let key = mapping_key(address.to_h256(), H256::from_low_u64_be(ACCOUNT_INFO_SLOT));
+        let account_info = self.read_db(CONTRACT_DEPLOYER_ADDRESS, h256_to_u256(key));
+        // `AccountInfo` packs the account abstraction version in the lowest byte, followed by
+        // the nonce ordering
+        account_info.0[30]
+    }
+
     /// Returns the deployment nonce for a given account from NonceHolder storage.
     pub fn get_deploy_nonce(&mut self, address: Address) -> Nonce {
         let address = address.to_h160();
@@ -185,6 +204,12 @@ where
     }
 }
 
+/// Returns the storage slot of the value under the given key of a solidity mapping stored at the
+/// given slot.
+fn mapping_key(key: H256, slot: H256) -> H256 {
+    H256(keccak256([key.as_bytes(), slot.as_bytes()].concat()).0)
+}
+
 impl<'a, DB> ReadStorage for &mut ZKVMData<'a, DB>
 where
     DB: Database,
@@ -193,6 +218,7 @@ where
     fn read_value(&mut self, key: &StorageKey) -> zksync_types::StorageValue {
         if let Some(access) = &mut self.accesses {
             access.reads.entry(key.address().to_address()).or_default().push(key.key().to_ru256());
+            self.storage_reads.push(*key);
         }
         self.read_db(*key.address(), h256_to_u256(*key.key()))
     }
@@ -202,26 +228,32 @@ where
     }
 
     fn load_factory_dep(&mut self, hash: H256) -> Option<Vec<u8>> {
-        self.factory_deps.get(&hash).cloned().or_else(|| {
-            let hash_b256 = hash.to_b256();
-            self.ecx
-                .journaled_state
-                .state
-                .values()
-                .find_map(|account| {
-                    if account.info.code_hash == hash_b256 {
-                        return Some(account.info.code.clone().map(|code| code.bytecode().to_vec()))
-                    }
-                    None
-                })
-                .unwrap_or_else(|| {
-                    self.ecx
-                        .db
-                        .code_by_hash(hash_b256)
-                        .ok()
-                        .map(|bytecode| bytecode.bytecode().to_vec())
-                })
-        })
+        self.factory_deps
+            .get(&hash)
+            .or_else(|| self.system_contracts.as_ref()?.factory_deps.get(&hash))
+            .cloned()
+            .or_else(|| {
+                let hash_b256 = hash.to_b256();
+                self.ecx
+                    .journaled_state
+                    .state
+                    .values()
+                    .find_map(|account| {
+                        if account.info.code_hash == hash_b256 {
+                            return Some(
+                                account.info.code.clone().map(|code| code.bytecode().to_vec()),
+                            )
+                        }
+                        None
+                    })
+                    .unwrap_or_else(|| {
+                        self.ecx
+                            .db
+                            .code_by_hash(hash_b256)
+                            .ok()
+                            .map(|bytecode| bytecode.bytecode().to_vec())
+                    })
+            })
     }
 
     fn get_enumeration_index(&mut self, _key: &StorageKey) -> Option<u64> {
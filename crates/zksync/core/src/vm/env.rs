@@ -1,19 +1,207 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use era_test_node::system_contracts::{get_deployed_contracts, Options, SystemContracts};
 use multivm::{
     interface::{L1BatchEnv, L2BlockEnv, SystemEnv},
+    utils::derive_base_fee_and_gas_per_pubdata,
     vm_latest::{
         constants::BATCH_COMPUTATIONAL_GAS_LIMIT, utils::l2_blocks::load_last_l2_block,
         TxExecutionMode,
     },
 };
-use zksync_basic_types::{AccountTreeId, L1BatchNumber, L2BlockNumber, L2ChainId, H160};
-use zksync_contracts::BaseSystemContracts;
+use once_cell::sync::Lazy;
+use tracing::{debug, warn};
+use zksync_basic_types::{AccountTreeId, L1BatchNumber, L2BlockNumber, L2ChainId, H160, H256};
+use zksync_contracts::{
+    BaseSystemContracts, ContractLanguage, SystemContractCode, SystemContractsRepo,
+};
 use zksync_state::{ReadStorage, StoragePtr};
 use zksync_types::{
-    block::{unpack_block_info, L2BlockHasher},
-    fee_model::L1PeggedBatchFeeModelInput,
-    StorageKey, SYSTEM_CONTEXT_ADDRESS, SYSTEM_CONTEXT_BLOCK_INFO_POSITION,
+    block::{unpack_block_info, DeployedContract, L2BlockHasher},
+    fee_model::{BatchFeeInput, L1PeggedBatchFeeModelInput},
+    get_code_key,
+    system_contracts::get_system_smart_contracts_from_dir,
+    ProtocolVersionId, StorageKey, StorageValue, SYSTEM_CONTEXT_ADDRESS,
+    SYSTEM_CONTEXT_BLOCK_INFO_POSITION,
 };
-use zksync_utils::h256_to_u256;
+use zksync_utils::{bytecode::hash_bytecode, bytes_to_be_words, h256_to_u256};
+
+/// Minimal L1 gas price used when not forking a zkSync chain.
+const MIN_L1_GAS_PRICE: u64 = 1000;
+
+/// The first protocol version of the VM 1.5.0, which is the one executing the transactions.
+const MIN_PROTOCOL_VERSION: ProtocolVersionId = ProtocolVersionId::Version24;
+
+/// The batch fee parameters and protocol version of the zkSync chain being forked, so transactions
+/// are executed as on the network.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ZkEnv {
+    /// The L1 gas price, in wei.
+    pub l1_gas_price: u64,
+    /// The fair L2 gas price, in wei.
+    pub fair_l2_gas_price: u64,
+    /// The protocol version of the forked block, if known.
+    pub protocol_version: Option<ProtocolVersionId>,
+}
+
+impl ZkEnv {
+    /// Returns the batch fee parameters used when not forking, derived from the block base fee.
+    pub fn local(block_basefee: u64) -> Self {
+        Self {
+            l1_gas_price: block_basefee.max(MIN_L1_GAS_PRICE),
+            fair_l2_gas_price: block_basefee,
+            protocol_version: None,
+        }
+    }
+
+    /// Returns the base fee and the gas per pubdata byte charged by the zkVM for these batch fee
+    /// parameters.
+    pub fn fee_params(&self) -> (u64, u64) {
+        let fee_input = BatchFeeInput::L1Pegged(L1PeggedBatchFeeModelInput {
+            fair_l2_gas_price: self.fair_l2_gas_price,
+            l1_gas_price: self.l1_gas_price,
+        });
+        let protocol_version = self.protocol_version.unwrap_or_else(ProtocolVersionId::latest);
+        derive_base_fee_and_gas_per_pubdata(fee_input, protocol_version.into())
+    }
+}
+
+/// The system contracts and bootloader used by the zkVM.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ZkSystemContracts {
+    /// The built-in system contracts, without the security checks of the default account so
+    /// transactions don't need to be signed.
+    #[default]
+    BuiltInWithoutSecurity,
+    /// The built-in system contracts.
+    BuiltIn,
+    /// The system contracts compiled in the given `zksync-era` checkout.
+    Local(PathBuf),
+}
+
+impl ZkSystemContracts {
+    /// Returns the loaded system contracts.
+    ///
+    /// Loading the system contracts decodes and hashes all of their bytecodes, so they are only
+    /// loaded once per process and shared by all the executions using the same configuration.
+    pub(crate) fn load(&self) -> Arc<LoadedSystemContracts> {
+        static LOADED: Lazy<Mutex<HashMap<ZkSystemContracts, Arc<LoadedSystemContracts>>>> =
+            Lazy::new(Default::default);
+
+        let mut loaded = LOADED.lock().unwrap_or_else(|err| err.into_inner());
+        loaded
+            .entry(self.clone())
+            .or_insert_with(|| {
+                debug!(system_contracts = ?self, "loading zk system contracts");
+                Arc::new(LoadedSystemContracts::new(self))
+            })
+            .clone()
+    }
+}
+
+/// The system contracts and bootloader of the zkVM, along with the storage they are deployed
+/// with.
+#[derive(Debug)]
+pub(crate) struct LoadedSystemContracts {
+    /// The bootloader and default account.
+    pub(crate) base_system_contracts: BaseSystemContracts,
+    /// The bytecodes of the system contracts, by their hash.
+    pub(crate) factory_deps: HashMap<H256, Vec<u8>>,
+    /// The account code storage of the system contracts, by their code key.
+    pub(crate) code_storage: HashMap<StorageKey, StorageValue>,
+}
+
+impl LoadedSystemContracts {
+    fn new(system_contracts: &ZkSystemContracts) -> Self {
+        let (base_system_contracts, deployed_contracts) = match system_contracts {
+            ZkSystemContracts::BuiltInWithoutSecurity => {
+                built_in_system_contracts(&Options::BuiltInWithoutSecurity)
+            }
+            ZkSystemContracts::BuiltIn => built_in_system_contracts(&Options::BuiltIn),
+            ZkSystemContracts::Local(path) => local_system_contracts(path),
+        };
+
+        let mut factory_deps = HashMap::new();
+        let mut code_storage = HashMap::new();
+        for contract in deployed_contracts {
+            let hash = hash_bytecode(&contract.bytecode);
+            code_storage.insert(get_code_key(contract.account_id.address()), hash);
+            factory_deps.insert(hash, contract.bytecode);
+        }
+        let empty_code = vec![0u8; 32];
+        factory_deps.insert(hash_bytecode(&empty_code), empty_code);
+
+        Self { base_system_contracts, factory_deps, code_storage }
+    }
+}
+
+/// Returns the built-in bootloader, default account and system contracts.
+fn built_in_system_contracts(options: &Options) -> (BaseSystemContracts, Vec<DeployedContract>) {
+    (SystemContracts::from_options(options).baseline_contracts, get_deployed_contracts(options))
+}
+
+/// Returns the bootloader, default account and system contracts compiled in the given `zksync-era`
+/// checkout.
+///
+/// They are read from the paths `zksync-era` reads them from relative to `ZKSYNC_HOME`, without
+/// relying on the environment, which can't be modified safely while the tests are running.
+fn local_system_contracts(path: &Path) -> (BaseSystemContracts, Vec<DeployedContract>) {
+    let repo = SystemContractsRepo { root: path.join("contracts/system-contracts") };
+
+    let artifacts = repo.root.join("bootloader/build/artifacts");
+    let bootloader = [
+        artifacts.join("proved_batch.yul.zbin"),
+        artifacts.join("proved_batch.yul/proved_batch.yul.zbin"),
+    ]
+    .iter()
+    .find_map(|path| std::fs::read(path).ok())
+    .unwrap_or_else(|| panic!("no compiled bootloader found in {}", artifacts.display()));
+    let default_account =
+        repo.read_sys_contract_bytecode("", "DefaultAccount", ContractLanguage::Sol);
+
+    // the built-in bootloader and default account are replaced by the local ones
+    let mut base_system_contracts =
+        SystemContracts::from_options(&Options::BuiltIn).baseline_contracts;
+    base_system_contracts.bootloader = system_contract_code(bootloader);
+    base_system_contracts.default_aa = system_contract_code(default_account);
+
+    (base_system_contracts, get_system_smart_contracts_from_dir(repo.root))
+}
+
+fn system_contract_code(bytecode: Vec<u8>) -> SystemContractCode {
+    SystemContractCode { hash: hash_bytecode(&bytecode), code: bytes_to_be_words(bytecode) }
+}
+
+/// The system environment of the zkVM, as configured by the user.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ZkSystemEnv {
+    /// The protocol version to execute transactions with, which defaults to the one of the forked
+    /// chain or to the latest one.
+    pub protocol_version: Option<ProtocolVersionId>,
+    /// The system contracts and bootloader to execute transactions with.
+    pub system_contracts: ZkSystemContracts,
+}
+
+impl ZkSystemEnv {
+    /// Creates the system environment from the configured protocol version number and system
+    /// contracts.
+    pub fn new(protocol_version: Option<u16>, system_contracts: ZkSystemContracts) -> Self {
+        let protocol_version = protocol_version.and_then(|version| {
+            ProtocolVersionId::try_from(version)
+                .ok()
+                .filter(|version| *version >= MIN_PROTOCOL_VERSION)
+                .or_else(|| {
+                    warn!(version, "unsupported zk protocol version, ignoring it");
+                    None
+                })
+        });
+        Self { protocol_version, system_contracts }
+    }
+}
 
 pub(crate) fn create_l1_batch_env<ST: ReadStorage>(
     storage: StoragePtr<ST>,
@@ -53,7 +241,7 @@ pub(crate) fn create_l1_batch_env<ST: ReadStorage>(
         fee_account: H160::zero(),
         enforced_base_fee: None,
         first_l2_block,
-        fee_input: zksync_types::fee_model::BatchFeeInput::L1Pegged(L1PeggedBatchFeeModelInput {
+        fee_input: BatchFeeInput::L1Pegged(L1PeggedBatchFeeModelInput {
             fair_l2_gas_price,
             l1_gas_price,
         }),
@@ -63,12 +251,11 @@ pub(crate) fn create_l1_batch_env<ST: ReadStorage>(
 pub(crate) fn create_system_env(
     base_system_contracts: BaseSystemContracts,
     chain_id: L2ChainId,
+    version: ProtocolVersionId,
 ) -> SystemEnv {
     SystemEnv {
         zk_porter_available: false,
-        // TODO: when forking, we could consider taking the protocol version id from the fork
-        // itself.
-        version: zksync_types::ProtocolVersionId::latest(),
+        version,
         base_system_smart_contracts: base_system_contracts,
         bootloader_gas_limit: BATCH_COMPUTATIONAL_GAS_LIMIT,
         execution_mode: TxExecutionMode::VerifyExecute,
@@ -93,3 +280,21 @@ pub(crate) fn load_last_l1_batch<S: ReadStorage>(storage: StoragePtr<S>) -> Opti
     }
     Some((batch_number, batch_timestamp))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ignores_unsupported_protocol_versions() {
+        let system_env = ZkSystemEnv::new(Some(24), Default::default());
+        assert_eq!(system_env.protocol_version, Some(ProtocolVersionId::Version24));
+
+        // versions older than the VM 1.5.0 can't be executed
+        let system_env = ZkSystemEnv::new(Some(23), Default::default());
+        assert_eq!(system_env.protocol_version, None);
+
+        let system_env = ZkSystemEnv::new(Some(u16::MAX), Default::default());
+        assert_eq!(system_env.protocol_version, None);
+    }
+}
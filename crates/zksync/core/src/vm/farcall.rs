@@ -119,16 +119,32 @@ pub(crate) struct FarCallHandler {
     pub(crate) before_far_call_stack: Option<CallStackEntry>,
     pub(crate) after_far_call_stack: Option<CallStackEntry>,
     pub(crate) current_far_call: Option<FarCallOpcode>,
-    pub(crate) immediate_return: Option<Vec<u8>>,
+    pub(crate) immediate_return: Option<ImmediateReturn>,
     call_actions: CallActions,
 }
 
+/// The data to return immediately from a FarCall.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct ImmediateReturn {
+    /// The return data, or revert data.
+    pub(crate) data: Vec<u8>,
+    /// Whether the FarCall reverts.
+    pub(crate) revert: bool,
+}
+
 impl FarCallHandler {
     /// Marks the current FarCall opcode to return immediately during `finish_cycle`.
     /// Must be called during either `before_execution` or `after_execution`.
     /// Must _always_ be called always within a `Farcall` opcode.
     pub(crate) fn set_immediate_return(&mut self, return_data: Vec<u8>) {
-        self.immediate_return.replace(return_data);
+        self.immediate_return.replace(ImmediateReturn { data: return_data, revert: false });
+    }
+
+    /// Marks the current FarCall opcode to revert immediately during `finish_cycle`.
+    /// Must be called during either `before_execution` or `after_execution`.
+    /// Must _always_ be called always within a `Farcall` opcode.
+    pub(crate) fn set_immediate_revert(&mut self, revert_data: Vec<u8>) {
+        self.immediate_return.replace(ImmediateReturn { data: revert_data, revert: true });
     }
 
     /// Sets a [CallAction] for the current or subsequent FarCalls during `finish_cycle`.
@@ -156,7 +172,7 @@ impl FarCallHandler {
         state: &mut ZkSyncVmState<S, H>,
         _bootloader_state: &mut BootloaderState,
     ) {
-        if let Some(return_data) = self.immediate_return.take() {
+        if let Some(ImmediateReturn { data: return_data, revert }) = self.immediate_return.take() {
             // set return data
             let current = *state.local_state.callstack.get_current_stack();
             let return_memory_page = vm_state::heap_page_from_base(current.base_memory_page);
@@ -182,7 +198,7 @@ impl FarCallHandler {
             let immediate_return_opcode = DecodedOpcode {
                 inner: DecodedOpcodeDef {
                     variant: OpcodeVariant {
-                        opcode: Opcode::Ret(RetOpcode::Ok),
+                        opcode: Opcode::Ret(if revert { RetOpcode::Revert } else { RetOpcode::Ok }),
                         src0_operand_type: Operand::RegOnly,
                         dst0_operand_type: Operand::RegOnly,
                         flags: [false; 2],
@@ -236,89 +252,6 @@ fn populate_page_with_data<S: WriteStorage, H: HistoryMode>(
     state.memory.populate_page(page.0 as usize, data, Timestamp(state.local_state.timestamp));
 }
 
-/// Defines the [MockCall]s return type.
-type MockCallReturn = Vec<u8>;
-
-/// Defines the match criteria of a mocked call.
-#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
-pub(crate) struct MockCall {
-    pub(crate) address: H160,
-    pub(crate) value: Option<U256>,
-    pub(crate) calldata: Vec<u8>,
-}
-
-/// Contains the list of mocked calls.
-/// Note that mocked calls with value take precedence of the ones without.
-#[derive(Default, Debug, Clone)]
-pub(crate) struct MockedCalls {
-    /// List of mocked calls with the value parameter.
-    pub(crate) with_value: HashMap<MockCall, MockCallReturn>,
-
-    /// List of mocked calls without the value parameter.
-    pub(crate) without_value: HashMap<MockCall, MockCallReturn>,
-}
-
-impl MockedCalls {
-    /// Insert a mocked call with its return data.
-    pub(crate) fn insert(&mut self, call: MockCall, return_data: MockCallReturn) {
-        if call.value.is_some() {
-            self.with_value.insert(call, return_data);
-        } else {
-            self.without_value.insert(call, return_data);
-        }
-    }
-
-    /// Clear all mocked calls.
-    pub(crate) fn clear(&mut self) {
-        self.with_value.clear();
-        self.without_value.clear();
-    }
-
-    /// Matches the mocked calls based on foundry rules. The matching is in the precedence order of:
-    /// * Calls with value parameter and exact calldata match
-    /// * Exact calldata matches
-    /// * Partial calldata matches
-    pub(crate) fn get_matching_return_data(
-        &self,
-        code_address: H160,
-        actual_calldata: &[u8],
-        actual_value: U256,
-    ) -> Option<Vec<u8>> {
-        let mut best_match = None;
-
-        for (call, call_return_data) in self.with_value.iter().chain(self.without_value.iter()) {
-            if call.address == code_address {
-                let value_matches = call.value.map_or(true, |value| value == actual_value);
-                if !value_matches {
-                    continue
-                }
-
-                if actual_calldata.starts_with(&call.calldata) {
-                    // return early if exact match
-                    if call.calldata.len() == actual_calldata.len() {
-                        return Some(call_return_data.clone())
-                    }
-
-                    // else check for partial matches and pick the best
-                    let matched_len = call.calldata.len();
-                    best_match = best_match.map_or(
-                        Some((matched_len, call_return_data)),
-                        |(best_match, best_match_return_data)| {
-                            if matched_len > best_match {
-                                Some((matched_len, call_return_data))
-                            } else {
-                                Some((best_match, best_match_return_data))
-                            }
-                        },
-                    );
-                }
-            }
-        }
-
-        best_match.map(|(_, return_data)| return_data.clone())
-    }
-}
-
 /// Selector for `L2EthToken::balanceOf(uint256)`
 pub const SELECTOR_L2_ETH_BALANCE_OF: &str = "9cc7f708";
 /// Selector for `SystemContext::getBlockNumber()`
@@ -1,17 +1,14 @@
-use alloy_primitives::{hex, Log};
-use era_test_node::{
-    config::node::ShowCalls,
-    formatter,
-    system_contracts::{Options, SystemContracts},
-    utils::bytecode_to_factory_dep,
-};
+use alloy_primitives::{hex, keccak256, Log};
+use era_test_node::{config::node::ShowCalls, formatter, utils::bytecode_to_factory_dep};
 use itertools::Itertools;
 use multivm::{
-    interface::{Halt, VmInterface, VmRevertReason},
+    interface::{
+        Halt, L1BatchEnv, SystemEnv, VmInterface, VmInterfaceHistoryEnabled, VmRevertReason,
+    },
     tracers::CallTracer,
     vm_latest::{
-        ExecutionResult, HistoryDisabled, ToTracerPointer, Vm, VmExecutionMode,
-        VmExecutionResultAndLogs,
+        ExecutionResult, HistoryDisabled, HistoryEnabled, HistoryMode, ToTracerPointer, Vm,
+        VmExecutionMode, VmExecutionResultAndLogs,
     },
 };
 use once_cell::sync::OnceCell;
@@ -22,26 +19,32 @@ use revm::{
         HaltReason, HashMap as rHashMap, Log as rLog, OutOfGasError, Output, SuccessReason, B256,
         U256 as rU256,
     },
-    Database, EvmContext,
+    Database, EvmContext, InnerEvmContext,
 };
 use tracing::{debug, error, info, trace, warn};
 use zksync_basic_types::{ethabi, L2ChainId, Nonce, H160, H256, U256};
 use zksync_state::{ReadStorage, StoragePtr, WriteStorage};
 use zksync_types::{
-    l2::L2Tx, vm_trace::Call, PackedEthSignature, StorageKey, Transaction, VmEvent,
-    ACCOUNT_CODE_STORAGE_ADDRESS,
+    get_code_key, get_nonce_key,
+    l1::L1Tx,
+    l2::L2Tx,
+    utils::storage_key_for_eth_balance,
+    vm_trace::{Call, CallType},
+    PackedEthSignature, ProtocolVersionId, StorageKey, Transaction, VmEvent,
+    ACCOUNT_CODE_STORAGE_ADDRESS, BOOTLOADER_ADDRESS, L1_MESSENGER_ADDRESS,
 };
 use zksync_utils::{h256_to_account_address, h256_to_u256, u256_to_h256};
 
-use std::{collections::HashMap, fmt::Debug, sync::Arc};
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, collections::HashMap, fmt::Debug, ops::AddAssign, sync::Arc};
 
 use crate::{
     convert::{ConvertAddress, ConvertH160, ConvertH256, ConvertU256},
     is_system_address,
     vm::{
         db::{ZKVMData, DEFAULT_CHAIN_ID},
-        env::{create_l1_batch_env, create_system_env},
-        storage_view::StorageView,
+        env::{create_l1_batch_env, create_system_env, LoadedSystemContracts, ZkEnv},
+        storage_view::{SharedStorage, StorageView},
         tracer::{CallContext, CheatcodeTracer, CheatcodeTracerContext},
     },
 };
@@ -49,18 +52,74 @@ use foundry_evm_abi::{
     patch_hh_console_selector, Console, HardhatConsole, HARDHAT_CONSOLE_ADDRESS,
 };
 
-/// Maximum gas price allowed for L1.
-const MAX_L1_GAS_PRICE: u64 = 1000;
-
 /// Represents the result of execution a [`L2Tx`] on EraVM
 #[derive(Debug)]
 pub struct ZKVMExecutionResult {
     /// The logs of a given execution
     pub logs: Vec<rLog>,
+    /// The call traces of a given execution, rooted at the calls made by the executed
+    /// transaction
+    pub call_traces: Vec<Call>,
+    /// The gas usage breakdown of a given execution
+    pub gas_usage: ZkGasUsage,
+    /// The L2 to L1 logs emitted by a given execution, both user and system ones
+    pub l2_to_l1_logs: Vec<ZkL2ToL1Log>,
+    /// The L2 to L1 messages sent through the `L1Messenger` system contract by a given execution
+    pub l2_to_l1_messages: Vec<ZkL2ToL1Message>,
     /// The result of a given execution
     pub execution_result: rExecutionResult,
 }
 
+/// An L2 to L1 log emitted by a transaction executed on EraVM.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ZkL2ToL1Log {
+    /// Whether the log is a service log, which are emitted by the system contracts.
+    pub is_service: bool,
+    /// The address that emitted the log.
+    pub sender: Address,
+    /// The key of the log.
+    pub key: B256,
+    /// The value of the log.
+    pub value: B256,
+}
+
+/// An arbitrary length message sent to L1 with `L1Messenger.sendToL1`.
+///
+/// Only the hash of the message is part of the corresponding [ZkL2ToL1Log], the message itself is
+/// taken from the `L1MessageSent` event emitted by the `L1Messenger`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ZkL2ToL1Message {
+    /// The address that sent the message.
+    pub sender: Address,
+    /// The message.
+    pub data: Bytes,
+}
+
+/// Breakdown of the gas used by a transaction executed on EraVM.
+///
+/// The total gas reported by the VM mixes computational ergs, pubdata cost and bootloader
+/// overhead, which is split here as reported by the VM execution statistics.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZkGasUsage {
+    /// The total gas used, including pubdata cost and bootloader overhead.
+    pub gas_used: u64,
+    /// The gas spent on computation, in ergs.
+    pub computation: u64,
+    /// The number of pubdata bytes published.
+    pub pubdata: u64,
+    /// The number of storage slots written.
+    pub storage_writes: u64,
+}
+
+impl AddAssign for ZkGasUsage {
+    fn add_assign(&mut self, other: Self) {
+        self.gas_used += other.gas_used;
+        self.computation += other.computation;
+        self.pubdata += other.pubdata;
+        self.storage_writes += other.storage_writes;
+    }
+}
+
 /// Revm-style result with ZKVM Execution
 pub type ZKVMResult<E> = EVMResultGeneric<ZKVMExecutionResult, E>;
 
@@ -99,15 +158,32 @@ where
 
         match (&mut aggregated_result, result.execution_result) {
             (_, exec @ rExecutionResult::Revert { .. } | exec @ rExecutionResult::Halt { .. }) => {
-                return Ok(ZKVMExecutionResult { logs: result.logs, execution_result: exec });
+                return Ok(ZKVMExecutionResult {
+                    logs: result.logs,
+                    call_traces: result.call_traces,
+                    gas_usage: result.gas_usage,
+                    l2_to_l1_logs: result.l2_to_l1_logs,
+                    l2_to_l1_messages: result.l2_to_l1_messages,
+                    execution_result: exec,
+                });
             }
             (None, exec) => {
-                aggregated_result
-                    .replace(ZKVMExecutionResult { logs: result.logs, execution_result: exec });
+                aggregated_result.replace(ZKVMExecutionResult {
+                    logs: result.logs,
+                    call_traces: result.call_traces,
+                    gas_usage: result.gas_usage,
+                    l2_to_l1_logs: result.l2_to_l1_logs,
+                    l2_to_l1_messages: result.l2_to_l1_messages,
+                    execution_result: exec,
+                });
             }
             (
                 Some(ZKVMExecutionResult {
                     logs: aggregated_logs,
+                    call_traces: aggregated_call_traces,
+                    gas_usage: aggregated_gas_usage,
+                    l2_to_l1_logs: aggregated_l2_to_l1_logs,
+                    l2_to_l1_messages: aggregated_l2_to_l1_messages,
                     execution_result:
                         rExecutionResult::Success {
                             reason: agg_reason,
@@ -120,6 +196,10 @@ where
                 rExecutionResult::Success { reason, gas_used, gas_refunded, logs, output },
             ) => {
                 aggregated_logs.append(&mut result.logs);
+                aggregated_call_traces.append(&mut result.call_traces);
+                *aggregated_gas_usage += result.gas_usage;
+                aggregated_l2_to_l1_logs.append(&mut result.l2_to_l1_logs);
+                aggregated_l2_to_l1_messages.append(&mut result.l2_to_l1_messages);
                 *agg_reason = reason;
                 *agg_gas_used += gas_used;
                 *agg_gas_refunded += gas_refunded;
@@ -142,6 +222,41 @@ pub fn inspect<DB, E>(
     ccx: &mut CheatcodeTracerContext,
     call_ctx: CallContext,
 ) -> ZKVMResult<E>
+where
+    DB: Database,
+    <DB as Database>::Error: Debug,
+{
+    if tx.common_data.signature.is_empty() {
+        // FIXME: This is a hack to make sure that the signature is not empty.
+        // Fails without a signature here: https://github.com/matter-labs/zksync-era/blob/73a1e8ff564025d06e02c2689da238ae47bb10c3/core/lib/types/src/transaction_request.rs#L381
+        tx.common_data.signature = PackedEthSignature::default().serialize_packed().into();
+    }
+
+    inspect_transaction(tx.into(), ecx, ccx, call_ctx)
+}
+
+/// Processes a priority [`L1Tx`] with EraVM and returns the final execution result and logs.
+///
+/// State changes will be reflected in the given `Env`, `DB`, `JournaledState`.
+pub fn inspect_l1_tx<DB, E>(
+    tx: L1Tx,
+    ecx: &mut InnerEvmContext<DB>,
+    ccx: &mut CheatcodeTracerContext,
+    call_ctx: CallContext,
+) -> ZKVMResult<E>
+where
+    DB: Database,
+    <DB as Database>::Error: Debug,
+{
+    inspect_transaction(tx.into(), ecx, ccx, call_ctx)
+}
+
+fn inspect_transaction<DB, E>(
+    tx: Transaction,
+    ecx: &mut InnerEvmContext<DB>,
+    ccx: &mut CheatcodeTracerContext,
+    call_ctx: CallContext,
+) -> ZKVMResult<E>
 where
     DB: Database,
     <DB as Database>::Error: Debug,
@@ -160,32 +275,54 @@ where
         .unwrap_or_default();
 
     let env_tx_gas_limit = ecx.env.tx.gas_limit;
-    let mut era_db = ZKVMData::new_with_system_contracts(ecx, chain_id)
-        .with_extra_factory_deps(persisted_factory_deps)
-        .with_storage_accesses(ccx.accesses.take());
+    let mut era_db =
+        ZKVMData::new_with_system_contracts(ecx, chain_id, &ccx.system_env.system_contracts)
+            .with_extra_factory_deps(persisted_factory_deps)
+            .with_storage_accesses(ccx.accesses.take());
 
     let is_create = call_ctx.is_create;
+    let initiator = tx.initiator_account();
+    let target = tx.execute.contract_address;
     info!(?call_ctx, "executing transaction in zk vm");
 
-    if tx.common_data.signature.is_empty() {
-        // FIXME: This is a hack to make sure that the signature is not empty.
-        // Fails without a signature here: https://github.com/matter-labs/zksync-era/blob/73a1e8ff564025d06e02c2689da238ae47bb10c3/core/lib/types/src/transaction_request.rs#L381
-        tx.common_data.signature = PackedEthSignature::default().serialize_packed().into();
-    }
-
     let modified_storage_keys = era_db.override_keys.clone();
-    let storage_ptr =
-        StorageView::new(&mut era_db, modified_storage_keys, tx.common_data.initiator_address)
-            .into_rc_ptr();
-    let (tx_result, bytecodes, modified_storage) =
-        inspect_inner(tx, storage_ptr, chain_id, ccx, call_ctx);
+    let caller = (!call_ctx.is_account_abstraction).then_some(initiator);
+    let storage = StorageView::new(SharedStorage::default(), modified_storage_keys, caller);
+    let (tx_result, bytecodes, modified_storage, call_traces) =
+        inspect_inner(tx, &mut &mut era_db, storage, chain_id, ccx, call_ctx);
 
     if let Some(record) = &mut era_db.accesses {
+        // balances, nonces and code hashes are stored by system contracts, so the accesses are
+        // also attributed to the account they belong to
+        let owners = system_storage_owners(&call_traces, [initiator, target]);
+        for k in &era_db.storage_reads {
+            if let Some(owner) = owners.get(k) {
+                record.reads.entry(owner.to_address()).or_default().push(k.key().to_ru256());
+            }
+        }
         for k in modified_storage.keys() {
-            record.writes.entry(k.address().to_address()).or_default().push(k.key().to_ru256());
+            let slot = k.key().to_ru256();
+            record.writes.entry(k.address().to_address()).or_default().push(slot);
+            if let Some(owner) = owners.get(k) {
+                record.writes.entry(owner.to_address()).or_default().push(slot);
+            }
         }
     }
 
+    let call_traces = get_user_call_traces(call_traces, initiator, target, is_create);
+
+    let gas_usage = ZkGasUsage {
+        gas_used: tx_result.statistics.gas_used,
+        computation: tx_result.statistics.computational_gas_used.into(),
+        pubdata: tx_result.statistics.pubdata_published.into(),
+        // the modified keys are seeded with the overridden system keys, which are not written by
+        // the transaction itself
+        storage_writes: modified_storage
+            .iter()
+            .filter(|(key, value)| era_db.override_keys.get(key) != Some(value))
+            .count() as u64,
+    };
+
     let logs = tx_result
         .logs
         .events
@@ -200,6 +337,21 @@ where
         })
         .collect_vec();
 
+    let l2_to_l1_logs = tx_result
+        .logs
+        .user_l2_to_l1_logs
+        .iter()
+        .map(|log| &log.0)
+        .chain(tx_result.logs.system_l2_to_l1_logs.iter().map(|log| &log.0))
+        .map(|log| ZkL2ToL1Log {
+            is_service: log.is_service,
+            sender: log.sender.to_address(),
+            key: log.key.to_b256(),
+            value: log.value.to_b256(),
+        })
+        .collect_vec();
+    let l2_to_l1_messages = get_l2_to_l1_messages(&tx_result.logs.events);
+
     let execution_result = match tx_result.result {
         ExecutionResult::Success { output, .. } => {
             let result = ethabi::decode(&[ethabi::ParamType::Bytes], &output)
@@ -222,6 +374,10 @@ where
 
             ZKVMExecutionResult {
                 logs: logs.clone(),
+                call_traces,
+                gas_usage,
+                l2_to_l1_logs,
+                l2_to_l1_messages,
                 execution_result: rExecutionResult::Success {
                     reason: SuccessReason::Return,
                     gas_used: tx_result.statistics.gas_used,
@@ -240,6 +396,10 @@ where
 
             ZKVMExecutionResult {
                 logs,
+                call_traces,
+                gas_usage,
+                l2_to_l1_logs,
+                l2_to_l1_messages,
                 execution_result: rExecutionResult::Revert {
                     gas_used: env_tx_gas_limit - tx_result.refunds.gas_refunded,
                     output: Bytes::from(output),
@@ -255,6 +415,10 @@ where
 
             ZKVMExecutionResult {
                 logs,
+                call_traces,
+                gas_usage,
+                l2_to_l1_logs,
+                l2_to_l1_messages,
                 execution_result: rExecutionResult::Halt {
                     reason: mapped_reason,
                     gas_used: env_tx_gas_limit - tx_result.refunds.gas_refunded,
@@ -331,25 +495,133 @@ where
     Ok(execution_result)
 }
 
-fn inspect_inner<S: ReadStorage>(
-    l2_tx: L2Tx,
-    storage: StoragePtr<StorageView<S>>,
+fn inspect_inner(
+    tx: Transaction,
+    era_db: &mut dyn ReadStorage,
+    storage: StorageView<SharedStorage>,
     chain_id: L2ChainId,
     ccx: &mut CheatcodeTracerContext,
     call_ctx: CallContext,
-) -> (VmExecutionResultAndLogs, HashMap<U256, Vec<U256>>, HashMap<StorageKey, H256>) {
-    let l1_gas_price = call_ctx.block_basefee.to::<u64>().max(MAX_L1_GAS_PRICE);
-    let fair_l2_gas_price = call_ctx.block_basefee.saturating_to::<u64>();
-    let batch_env = create_l1_batch_env(storage.clone(), l1_gas_price, fair_l2_gas_price);
+) -> InspectedTransaction {
+    let storage = storage.into_rc_ptr();
+    let _attached = SharedStorage::attach(&storage, era_db);
+
+    let zk_env = ccx.zk_env.unwrap_or_else(|| ZkEnv::local(call_ctx.block_basefee.saturating_to()));
+    let batch_env =
+        create_l1_batch_env(storage.clone(), zk_env.l1_gas_price, zk_env.fair_l2_gas_price);
+
+    let protocol_version = ccx
+        .system_env
+        .protocol_version
+        .or_else(|| ccx.zk_env.and_then(|zk_env| zk_env.protocol_version))
+        .unwrap_or_else(ProtocolVersionId::latest);
+    let system_contracts = ccx.system_env.system_contracts.load();
+    let system_env = create_system_env(
+        system_contracts.base_system_contracts.clone(),
+        chain_id,
+        protocol_version,
+    );
+
+    if !*VM_REUSE {
+        let mut vm: Vm<_, HistoryDisabled> = Vm::new(batch_env, system_env, storage.clone());
+        return inspect_with_vm(&mut vm, &storage, tx, ccx, call_ctx)
+    }
+
+    // The VM is reused by the transactions executed with the same environment, like the calls of
+    // the runs of a fuzz test. The storage of the transaction is swapped in the view of the VM,
+    // which is rolled back to its initial state after the transaction.
+    let key = ReusableVmKey::new(&batch_env, &zk_env, &system_env, &system_contracts);
+    let mut reusable = REUSABLE_VMS.with_borrow_mut(|vms| vms.remove(&key)).unwrap_or_else(|| {
+        let vm_storage =
+            StorageView::new(SharedStorage::default(), Default::default(), None).into_rc_ptr();
+        vm_storage.swap(&storage);
+        let vm = Vm::new(batch_env, system_env, vm_storage.clone());
+        vm_storage.swap(&storage);
+        ReusableVm { vm, storage: vm_storage }
+    });
+
+    reusable.storage.swap(&storage);
+    reusable.vm.make_snapshot();
+    let result = inspect_with_vm(&mut reusable.vm, &reusable.storage, tx, ccx, call_ctx);
+    reusable.vm.rollback_to_the_latest_snapshot();
+    reusable.storage.swap(&storage);
+
+    REUSABLE_VMS.with_borrow_mut(|vms| {
+        if vms.len() >= MAX_REUSABLE_VMS {
+            vms.clear();
+        }
+        vms.insert(key, reusable);
+    });
+
+    result
+}
+
+/// The result of a transaction executed by [inspect_inner]: its result and logs, the bytecodes it
+/// published, the storage it modified and its call traces.
+type InspectedTransaction =
+    (VmExecutionResultAndLogs, HashMap<U256, Vec<U256>>, HashMap<StorageKey, H256>, Vec<Call>);
+
+/// Maximum number of VMs kept for reuse by each thread.
+const MAX_REUSABLE_VMS: usize = 4;
+
+thread_local! {
+    /// The VMs kept for reuse by this thread, by the environment they were created with.
+    static REUSABLE_VMS: RefCell<HashMap<ReusableVmKey, ReusableVm>> = Default::default();
+}
 
-    let system_contracts = SystemContracts::from_options(&Options::BuiltInWithoutSecurity);
-    let system_env = create_system_env(system_contracts.baseline_contracts, chain_id);
+/// A VM kept for reuse along with its storage view.
+struct ReusableVm {
+    vm: Vm<StorageView<SharedStorage>, HistoryEnabled>,
+    storage: StoragePtr<StorageView<SharedStorage>>,
+}
 
-    let mut vm: Vm<_, HistoryDisabled> = Vm::new(batch_env.clone(), system_env, storage.clone());
+/// The environment a VM was created with, which must be the one of the transactions it executes.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct ReusableVmKey {
+    batch_number: u32,
+    batch_timestamp: u64,
+    l2_block_number: u32,
+    l2_block_timestamp: u64,
+    prev_l2_block_hash: H256,
+    l1_gas_price: u64,
+    fair_l2_gas_price: u64,
+    chain_id: u64,
+    protocol_version: u16,
+    /// The address of the loaded system contracts, which are never unloaded.
+    system_contracts: usize,
+}
 
-    let tx: Transaction = l2_tx.clone().into();
+impl ReusableVmKey {
+    fn new(
+        batch_env: &L1BatchEnv,
+        zk_env: &ZkEnv,
+        system_env: &SystemEnv,
+        system_contracts: &Arc<LoadedSystemContracts>,
+    ) -> Self {
+        Self {
+            batch_number: batch_env.number.0,
+            batch_timestamp: batch_env.timestamp,
+            l2_block_number: batch_env.first_l2_block.number,
+            l2_block_timestamp: batch_env.first_l2_block.timestamp,
+            prev_l2_block_hash: batch_env.first_l2_block.prev_block_hash,
+            l1_gas_price: zk_env.l1_gas_price,
+            fair_l2_gas_price: zk_env.fair_l2_gas_price,
+            chain_id: system_env.chain_id.as_u64(),
+            protocol_version: system_env.version as u16,
+            system_contracts: Arc::as_ptr(system_contracts) as usize,
+        }
+    }
+}
 
-    vm.push_transaction(tx.clone());
+/// Executes the transaction with the given VM, whose storage is `storage`.
+fn inspect_with_vm<H: HistoryMode>(
+    vm: &mut Vm<StorageView<SharedStorage>, H>,
+    storage: &StoragePtr<StorageView<SharedStorage>>,
+    tx: Transaction,
+    ccx: &mut CheatcodeTracerContext,
+    call_ctx: CallContext,
+) -> InspectedTransaction {
+    vm.push_transaction(tx);
     let call_tracer_result = Arc::new(OnceCell::default());
     let cheatcode_tracer_result = Arc::new(OnceCell::default());
     let mut expected_calls = HashMap::<_, _>::new();
@@ -366,6 +638,8 @@ fn inspect_inner<S: ReadStorage>(
             expected_calls,
             cheatcode_tracer_result.clone(),
             call_ctx,
+            ccx.coverage.is_some(),
+            ccx.debug_steps.is_some(),
         )
         .into_tracer_pointer(),
     ];
@@ -392,6 +666,22 @@ fn inspect_inner<S: ReadStorage>(
         expected_calls.extend(cheatcode_result.expected_calls);
     }
 
+    // merge the instruction hits from cheatcode tracer's result
+    if let (Some(coverage), Some(hits)) = (ccx.coverage.as_mut(), cheatcode_result.coverage) {
+        for (code_hash, hits) in hits {
+            let entry = coverage.entry(code_hash).or_default();
+            for (pc, count) in hits {
+                *entry.entry(pc).or_default() += count;
+            }
+        }
+    }
+
+    if let (Some(debug_steps), Some(steps)) =
+        (ccx.debug_steps.as_mut(), cheatcode_result.debug_steps)
+    {
+        debug_steps.extend(steps);
+    }
+
     formatter::print_vm_details(&tx_result);
 
     info!("=== Console Logs: ");
@@ -431,7 +721,96 @@ fn inspect_inner<S: ReadStorage>(
     } else {
         storage.borrow().modified_storage_keys().clone()
     };
-    (tx_result, bytecodes, modified_keys)
+    (tx_result, bytecodes, modified_keys, call_traces)
+}
+
+/// Maps the system storage keys holding the balance, nonce and code hash of the accounts that
+/// took part in the given calls to the account they belong to.
+fn system_storage_owners(
+    call_traces: &[Call],
+    accounts: impl IntoIterator<Item = H160>,
+) -> HashMap<StorageKey, H160> {
+    let mut owners = HashMap::new();
+    let mut insert = |account: H160| {
+        if is_system_address(account.to_address()) {
+            return
+        }
+        owners.insert(storage_key_for_eth_balance(&account), account);
+        owners.insert(get_nonce_key(&account), account);
+        owners.insert(get_code_key(&account), account);
+    };
+
+    accounts.into_iter().for_each(&mut insert);
+    let mut stack = call_traces.iter().collect_vec();
+    while let Some(call) = stack.pop() {
+        insert(call.from);
+        insert(call.to);
+        stack.extend(call.calls.iter());
+    }
+
+    owners
+}
+
+/// Extracts the calls performed by the executed transaction from the full EraVM call traces.
+///
+/// The traces returned by the [CallTracer] are rooted at the bootloader and also contain the
+/// account validation and fee payment frames. Since the originating call is already recorded by
+/// the EVM, we only return the calls nested within it. For CREATEs these are the calls made by the
+/// deployed contract's constructor.
+fn get_user_call_traces(
+    call_traces: Vec<Call>,
+    initiator: H160,
+    target: H160,
+    is_create: bool,
+) -> Vec<Call> {
+    fn find_call(calls: Vec<Call>, predicate: &impl Fn(&Call) -> bool) -> Option<Call> {
+        let mut stack = calls;
+        stack.reverse();
+        while let Some(call) = stack.pop() {
+            if predicate(&call) {
+                return Some(call)
+            }
+            stack.extend(call.calls.into_iter().rev());
+        }
+        None
+    }
+
+    let Some(user_call) =
+        find_call(call_traces, &|call| call.to == target && call.from != BOOTLOADER_ADDRESS)
+    else {
+        warn!(?initiator, ?target, "failed finding the transaction call in zk vm traces");
+        return vec![]
+    };
+
+    if is_create {
+        find_call(user_call.calls, &|call| call.r#type == CallType::Create)
+            .map(|call| call.calls)
+            .unwrap_or_default()
+    } else {
+        user_call.calls
+    }
+}
+
+/// Extracts the messages sent to L1 from the `L1MessageSent` events emitted by the `L1Messenger`
+/// system contract.
+fn get_l2_to_l1_messages(events: &[VmEvent]) -> Vec<ZkL2ToL1Message> {
+    let l1_message_sent = keccak256("L1MessageSent(address,bytes32,bytes)");
+    events
+        .iter()
+        .filter(|event| {
+            event.address == L1_MESSENGER_ADDRESS &&
+                event.indexed_topics.first().map(|topic| topic.0) == Some(l1_message_sent.0)
+        })
+        .filter_map(|event| {
+            let sender = h256_to_account_address(event.indexed_topics.get(1)?);
+            let data = ethabi::decode(&[ethabi::ParamType::Bytes], &event.value)
+                .ok()?
+                .into_iter()
+                .next()?
+                .into_bytes()?;
+            Some(ZkL2ToL1Message { sender: sender.to_address(), data: data.into() })
+        })
+        .collect()
 }
 
 /// Parse solidity's `console.log` events
@@ -512,6 +891,10 @@ where
 }
 
 lazy_static::lazy_static! {
+    /// Whether the VMs are reused by the transactions executed with the same environment, see
+    /// [inspect_inner]. Setting `ZK_DISABLE_VM_REUSE=true` creates a new VM for each transaction.
+    static ref VM_REUSE: bool = !get_env_var::<bool>("ZK_DISABLE_VM_REUSE");
+
     /// Maximum size allowed for factory_deps during create.
     /// We batch factory_deps till this upper limit if there are multiple deps.
     /// These batches are then deployed individually.
@@ -579,7 +962,7 @@ fn split_tx_by_factory_deps(mut tx: L2Tx) -> Vec<L2Tx> {
 
     let mut txs = Vec::with_capacity(batched.len() + 1);
     for deps in batched.into_iter() {
-        txs.push(L2Tx::new(
+        let mut deps_tx = L2Tx::new(
             H160::zero(),
             Vec::default(),
             tx.common_data.nonce,
@@ -588,7 +971,10 @@ fn split_tx_by_factory_deps(mut tx: L2Tx) -> Vec<L2Tx> {
             Default::default(),
             deps,
             tx.common_data.paymaster_params.clone(),
-        ));
+        );
+        // the dependencies are published by the same sender, which may be a smart account
+        deps_tx.common_data.signature = tx.common_data.signature.clone();
+        txs.push(deps_tx);
         tx.common_data.nonce = Nonce(tx.common_data.nonce.0.saturating_add(1));
     }
 
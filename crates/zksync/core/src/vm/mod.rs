@@ -6,8 +6,18 @@ mod runner;
 mod storage_view;
 mod tracer;
 
+pub use env::{ZkEnv, ZkSystemContracts, ZkSystemEnv};
 pub use inspect::{
-    batch_factory_dependencies, inspect, inspect_as_batch, ZKVMExecutionResult, ZKVMResult,
+    batch_factory_dependencies, inspect, inspect_as_batch, inspect_l1_tx, ZKVMExecutionResult,
+    ZKVMResult, ZkGasUsage, ZkL2ToL1Log, ZkL2ToL1Message,
+};
+pub use runner::{
+    balance, call, code_hash, create, decode_create_params, encode_create_params, execute_l1_tx,
+    nonce, transact, transact_with_call_traces, ZkL1Tx, ZkL1TxResult,
+};
+pub use tracer::{CheatcodeTracerContext, EraVmStep, ZkHitMaps};
+pub use zksync_types::{
+    vm_trace::{Call, CallType},
+    zk_evm_types::FarCallOpcode,
+    ProtocolVersionId,
 };
-pub use runner::{balance, call, code_hash, create, encode_create_params, nonce, transact};
-pub use tracer::CheatcodeTracerContext;
@@ -1,26 +1,37 @@
-use alloy_primitives::hex;
+use alloy_primitives::{hex, keccak256, Bytes};
 use foundry_zksync_compiler::DualCompiledContract;
 use itertools::Itertools;
 use revm::{
     interpreter::{CallInputs, CallScheme, CallValue, CreateInputs},
-    primitives::{Address, CreateScheme, Env, ResultAndState, TransactTo, B256, U256 as rU256},
+    primitives::{
+        Address, CreateScheme, EVMResultGeneric, Env, ExecutionResult, ResultAndState, TransactTo,
+        B256, U256 as rU256,
+    },
     Database, EvmContext, InnerEvmContext,
 };
 use tracing::{debug, error, info};
 use zksync_basic_types::H256;
 use zksync_types::{
-    ethabi, fee::Fee, l2::L2Tx, transaction_request::PaymasterParams, CONTRACT_DEPLOYER_ADDRESS,
-    U256,
+    ethabi::{self, Token},
+    fee::Fee,
+    l1::{L1Tx, L1TxCommonData, OpProcessingType, PriorityQueueType},
+    l2::L2Tx,
+    transaction_request::PaymasterParams,
+    vm_trace::Call,
+    Execute, PriorityOpId, CONTRACT_DEPLOYER_ADDRESS, PRIORITY_OPERATION_L2_TX_TYPE,
+    REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_BYTE, U256,
 };
+use zksync_utils::{address_to_u256, bytecode::hash_bytecode, h256_to_u256};
 
 use std::{cmp::min, collections::HashMap, fmt::Debug};
 
 use crate::{
-    convert::{ConvertAddress, ConvertH160, ConvertRU256, ConvertU256},
+    apply_l1_to_l2_alias,
+    convert::{ConvertAddress, ConvertB256, ConvertH160, ConvertRU256, ConvertU256},
     fix_l2_gas_limit, fix_l2_gas_price,
     vm::{
         db::ZKVMData,
-        inspect::{inspect, inspect_as_batch, ZKVMExecutionResult, ZKVMResult},
+        inspect::{inspect, inspect_as_batch, inspect_l1_tx, ZKVMExecutionResult, ZKVMResult},
         tracer::{CallContext, CheatcodeTracerContext},
     },
 };
@@ -29,9 +40,26 @@ use crate::{
 pub fn transact<'a, DB>(
     persisted_factory_deps: Option<&'a mut HashMap<H256, Vec<u8>>>,
     factory_deps: Option<Vec<Vec<u8>>>,
+    paymaster_data: Option<PaymasterParams>,
     env: &'a mut Env,
     db: &'a mut DB,
 ) -> eyre::Result<ResultAndState>
+where
+    DB: Database,
+    <DB as Database>::Error: Debug,
+{
+    transact_with_call_traces(persisted_factory_deps, factory_deps, paymaster_data, env, db)
+        .map(|(result, _)| result)
+}
+
+/// Transacts, also returning the call traces of the transaction, rooted at the calls it made.
+pub fn transact_with_call_traces<'a, DB>(
+    persisted_factory_deps: Option<&'a mut HashMap<H256, Vec<u8>>>,
+    factory_deps: Option<Vec<Vec<u8>>>,
+    paymaster_data: Option<PaymasterParams>,
+    env: &'a mut Env,
+    db: &'a mut DB,
+) -> eyre::Result<(ResultAndState, Vec<Call>)>
 where
     DB: Database,
     <DB as Database>::Error: Debug,
@@ -46,7 +74,8 @@ where
         TransactTo::Create => (CONTRACT_DEPLOYER_ADDRESS, true),
     };
 
-    let (gas_limit, max_fee_per_gas) = gas_params(&mut ecx, caller);
+    let paymaster_params = paymaster_data.unwrap_or_default();
+    let (gas_limit, max_fee_per_gas) = gas_params(&mut ecx, caller, &paymaster_params);
     debug!(?gas_limit, ?max_fee_per_gas, "tx gas parameters");
     let tx = L2Tx::new(
         transact_to,
@@ -61,7 +90,7 @@ where
         caller.to_h160(),
         env.tx.value.to_u256(),
         factory_deps.unwrap_or_default(),
-        PaymasterParams::default(),
+        paymaster_params,
     );
 
     let call_ctx = CallContext {
@@ -75,13 +104,14 @@ where
         block_basefee: min(max_fee_per_gas.to_ru256(), env.block.basefee),
         is_create,
         is_static: false,
+        is_account_abstraction: false,
     };
 
     let mut ccx = CheatcodeTracerContext { persisted_factory_deps, ..Default::default() };
 
     match inspect::<_, DB::Error>(tx, &mut ecx, &mut ccx, call_ctx) {
-        Ok(ZKVMExecutionResult { execution_result: result, .. }) => {
-            Ok(ResultAndState { result, state: ecx.journaled_state.finalize().0 })
+        Ok(ZKVMExecutionResult { execution_result: result, call_traces, .. }) => {
+            Ok((ResultAndState { result, state: ecx.journaled_state.finalize().0 }, call_traces))
         }
         Err(err) => eyre::bail!("zk backend: failed while inspecting: {err:?}"),
     }
@@ -120,6 +150,7 @@ where
 pub fn create<DB, E>(
     call: &CreateInputs,
     contract: &DualCompiledContract,
+    constructor_input: Vec<u8>,
     factory_deps: Vec<Vec<u8>>,
     ecx: &mut EvmContext<DB>,
     mut ccx: CheatcodeTracerContext,
@@ -129,15 +160,22 @@ where
     <DB as Database>::Error: Debug,
 {
     info!(?call, "create tx {}", hex::encode(&call.init_code));
-    let constructor_input = call.init_code[contract.evm_bytecode.len()..].to_vec();
-    let caller = ecx.env.tx.caller;
+    // transactions sent from a smart account have the account as both the tx caller and the
+    // deployer of the contract, unless the deployer is pranked.
+    let (caller, msg_sender) = match &ccx.account_data {
+        Some(account_data) => {
+            (account_data.address, ccx.pranked_sender.unwrap_or(account_data.address))
+        }
+        None => (ecx.env.tx.caller, call.caller),
+    };
     let calldata = encode_create_params(&call.scheme, contract.zk_bytecode_hash, constructor_input);
-    let nonce = ZKVMData::new(ecx).get_tx_nonce(caller);
+    let nonce = ZKVMData::new(ecx).get_next_tx_nonce(caller);
 
-    let (gas_limit, max_fee_per_gas) = gas_params(ecx, caller);
+    let paymaster_params = get_paymaster_params(&ccx);
+    let (gas_limit, max_fee_per_gas) = gas_params(ecx, caller, &paymaster_params);
     info!(?gas_limit, ?max_fee_per_gas, "tx gas parameters");
 
-    let tx = L2Tx::new(
+    let mut tx = L2Tx::new(
         CONTRACT_DEPLOYER_ADDRESS,
         calldata,
         nonce,
@@ -150,12 +188,15 @@ where
         caller.to_h160(),
         call.value.to_u256(),
         factory_deps,
-        PaymasterParams::default(),
+        paymaster_params,
     );
+    if let Some(account_data) = &ccx.account_data {
+        tx.common_data.signature = account_data.signature.to_vec();
+    }
 
     let call_ctx = CallContext {
-        tx_caller: ecx.env.tx.caller,
-        msg_sender: call.caller,
+        tx_caller: caller,
+        msg_sender,
         contract: CONTRACT_DEPLOYER_ADDRESS.to_address(),
         delegate_as: None,
         block_number: ecx.env.block.number,
@@ -164,6 +205,7 @@ where
         block_hashes: get_historical_block_hashes(ecx),
         is_create: true,
         is_static: false,
+        is_account_abstraction: ccx.account_data.is_some(),
     };
 
     inspect_as_batch(tx, ecx, &mut ccx, call_ctx)
@@ -180,12 +222,20 @@ where
     <DB as Database>::Error: Debug,
 {
     info!(?call, "call tx {}", hex::encode(&call.input));
-    let caller = ecx.env.tx.caller;
-    let nonce: zksync_types::Nonce = ZKVMData::new(ecx).get_tx_nonce(caller);
+    // transactions sent from a smart account have the account as both the tx caller and the
+    // `msg.sender` of the call, unless the `msg.sender` is pranked.
+    let (caller, msg_sender) = match &ccx.account_data {
+        Some(account_data) => {
+            (account_data.address, ccx.pranked_sender.unwrap_or(account_data.address))
+        }
+        None => (ecx.env.tx.caller, call.caller),
+    };
+    let nonce: zksync_types::Nonce = ZKVMData::new(ecx).get_next_tx_nonce(caller);
 
-    let (gas_limit, max_fee_per_gas) = gas_params(ecx, caller);
+    let paymaster_params = get_paymaster_params(&ccx);
+    let (gas_limit, max_fee_per_gas) = gas_params(ecx, caller, &paymaster_params);
     info!(?gas_limit, ?max_fee_per_gas, "tx gas parameters");
-    let tx = L2Tx::new(
+    let mut tx = L2Tx::new(
         call.bytecode_address.to_h160(),
         call.input.to_vec(),
         nonce,
@@ -201,16 +251,19 @@ where
             _ => U256::zero(),
         },
         Default::default(),
-        PaymasterParams::default(),
+        paymaster_params,
     );
+    if let Some(account_data) = &ccx.account_data {
+        tx.common_data.signature = account_data.signature.to_vec();
+    }
 
     // address and caller are specific to the type of call:
     // Call | StaticCall => { address: to, caller: contract.address }
     // CallCode          => { address: contract.address, caller: contract.address }
     // DelegateCall      => { address: contract.address, caller: contract.caller }
     let call_ctx = CallContext {
-        tx_caller: ecx.env.tx.caller,
-        msg_sender: call.caller,
+        tx_caller: caller,
+        msg_sender,
         contract: call.bytecode_address,
         delegate_as: match call.scheme {
             CallScheme::DelegateCall => Some(call.target_address),
@@ -222,21 +275,193 @@ where
         block_basefee: min(max_fee_per_gas.to_ru256(), ecx.env.block.basefee),
         is_create: false,
         is_static: call.is_static,
+        is_account_abstraction: ccx.account_data.is_some(),
     };
 
     inspect(tx, ecx, &mut ccx, call_ctx)
 }
 
+/// A priority (L1 to L2) transaction, as requested on L1 through the `Mailbox`.
+#[derive(Clone, Debug, Default)]
+pub struct ZkL1Tx {
+    /// The L1 address requesting the transaction, which is aliased to get the L2 sender.
+    pub from: Address,
+    /// The called address.
+    pub to: Address,
+    /// The value sent with the call.
+    pub value: rU256,
+    /// The calldata.
+    pub calldata: Bytes,
+    /// The gas limit of the transaction.
+    pub gas_limit: u64,
+    /// The factory deps published by the transaction.
+    pub factory_deps: Vec<Vec<u8>>,
+    /// The address receiving the unspent fees, and the value if the transaction fails.
+    pub refund_recipient: Address,
+    /// The serial id of the transaction in the priority queue.
+    pub serial_id: u64,
+}
+
+/// The result of a priority transaction executed with [execute_l1_tx].
+#[derive(Debug)]
+pub struct ZkL1TxResult {
+    /// The execution result of the transaction.
+    pub result: ZKVMExecutionResult,
+    /// The gas that was not spent by the transaction, out of its gas limit.
+    pub gas_refunded: u64,
+    /// The amount of ETH received by the refund recipient, measured as the change of its balance.
+    pub refund: rU256,
+}
+
+/// Executes a priority (L1 to L2) transaction on the ZK-VM.
+///
+/// As on L1, the transaction is sent from the aliased `from` address and mints the ETH needed to
+/// cover its value and fees, so the sender doesn't need to be funded on L2. The unspent fees are
+/// refunded to the refund recipient, along with the value if the transaction fails.
+pub fn execute_l1_tx<DB, E>(
+    l1_tx: ZkL1Tx,
+    ecx: &mut InnerEvmContext<DB>,
+    mut ccx: CheatcodeTracerContext,
+) -> EVMResultGeneric<ZkL1TxResult, E>
+where
+    DB: Database,
+    <DB as Database>::Error: Debug,
+{
+    info!(?l1_tx, "l1 tx {}", hex::encode(&l1_tx.calldata));
+    let sender = apply_l1_to_l2_alias(l1_tx.from);
+    let max_fee_per_gas = fix_l2_gas_price(ecx.env.tx.gas_price.to_u256());
+    let gas_limit = U256::from(l1_tx.gas_limit);
+    let value = l1_tx.value.to_u256();
+
+    let mut tx = L1Tx {
+        execute: Execute {
+            contract_address: l1_tx.to.to_h160(),
+            calldata: l1_tx.calldata.to_vec(),
+            value,
+            factory_deps: l1_tx.factory_deps,
+        },
+        common_data: L1TxCommonData {
+            sender: sender.to_h160(),
+            serial_id: PriorityOpId(l1_tx.serial_id),
+            max_fee_per_gas,
+            gas_limit,
+            gas_per_pubdata_limit: U256::from(REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_BYTE),
+            op_processing_type: OpProcessingType::Common,
+            priority_queue_type: PriorityQueueType::Deque,
+            // the deposited ETH must cover both the value and the fees of the transaction
+            to_mint: value + gas_limit * max_fee_per_gas,
+            refund_recipient: l1_tx.refund_recipient.to_h160(),
+            ..Default::default()
+        },
+        received_timestamp_ms: 0,
+    };
+    tx.common_data.canonical_tx_hash = canonical_l1_tx_hash(&tx);
+
+    let call_ctx = CallContext {
+        tx_caller: sender,
+        msg_sender: sender,
+        contract: l1_tx.to,
+        delegate_as: None,
+        block_number: ecx.env.block.number,
+        block_timestamp: ecx.env.block.timestamp,
+        block_hashes: get_historical_block_hashes(ecx),
+        block_basefee: min(max_fee_per_gas.to_ru256(), ecx.env.block.basefee),
+        is_create: false,
+        is_static: false,
+        is_account_abstraction: false,
+    };
+
+    let refund_recipient_balance = ZKVMData::new(ecx).get_balance(l1_tx.refund_recipient);
+
+    // the gas used by failed transactions is derived from the gas limit of the environment, which
+    // is the one of the L1 transaction here
+    let env_gas_limit = std::mem::replace(&mut ecx.env.tx.gas_limit, l1_tx.gas_limit);
+    let result = inspect_l1_tx(tx, ecx, &mut ccx, call_ctx);
+    ecx.env.tx.gas_limit = env_gas_limit;
+    let result = result?;
+
+    let gas_refunded = match &result.execution_result {
+        ExecutionResult::Success { gas_refunded, .. } => *gas_refunded,
+        ExecutionResult::Revert { gas_used, .. } | ExecutionResult::Halt { gas_used, .. } => {
+            l1_tx.gas_limit.saturating_sub(*gas_used)
+        }
+    };
+    let refund = ZKVMData::new(ecx)
+        .get_balance(l1_tx.refund_recipient)
+        .saturating_sub(refund_recipient_balance)
+        .to_ru256();
+
+    Ok(ZkL1TxResult { result, gas_refunded, refund })
+}
+
+/// Returns the hash of the priority transaction as computed by the `Mailbox` on L1, which is the
+/// hash of its ABI-encoded `L2CanonicalTransaction`.
+fn canonical_l1_tx_hash(tx: &L1Tx) -> H256 {
+    let common_data = &tx.common_data;
+    let factory_deps = tx
+        .execute
+        .factory_deps
+        .iter()
+        .map(|bytecode| Token::Uint(h256_to_u256(hash_bytecode(bytecode))))
+        .collect();
+
+    let encoded = ethabi::encode(&[Token::Tuple(vec![
+        Token::Uint(PRIORITY_OPERATION_L2_TX_TYPE.into()),
+        Token::Uint(address_to_u256(&common_data.sender)),
+        Token::Uint(address_to_u256(&tx.execute.contract_address)),
+        Token::Uint(common_data.gas_limit),
+        Token::Uint(common_data.gas_per_pubdata_limit),
+        Token::Uint(common_data.max_fee_per_gas),
+        // max priority fee per gas and paymaster
+        Token::Uint(U256::zero()),
+        Token::Uint(U256::zero()),
+        Token::Uint(common_data.serial_id.0.into()),
+        Token::Uint(tx.execute.value),
+        Token::FixedArray(vec![
+            Token::Uint(common_data.to_mint),
+            Token::Uint(address_to_u256(&common_data.refund_recipient)),
+            Token::Uint(U256::zero()),
+            Token::Uint(U256::zero()),
+        ]),
+        Token::Bytes(tx.execute.calldata.clone()),
+        // signature
+        Token::Bytes(vec![]),
+        Token::Array(factory_deps),
+        // paymaster input and reserved dynamic
+        Token::Bytes(vec![]),
+        Token::Bytes(vec![]),
+    ])]);
+
+    H256(keccak256(encoded).0)
+}
+
+/// Returns the [PaymasterParams] for the transaction, or the default (no paymaster) if unset.
+fn get_paymaster_params(ccx: &CheatcodeTracerContext) -> PaymasterParams {
+    ccx.paymaster_data.clone().unwrap_or_default()
+}
+
 /// Assign gas parameters that satisfy zkSync's fee model.
-fn gas_params<DB>(ecx: &mut EvmContext<DB>, caller: Address) -> (U256, U256)
+///
+/// If a paymaster is provided, the gas limit is bound by the paymaster's balance instead of the
+/// caller's, since the paymaster is the one paying for the transaction fees.
+fn gas_params<DB>(
+    ecx: &mut EvmContext<DB>,
+    caller: Address,
+    paymaster_params: &PaymasterParams,
+) -> (U256, U256)
 where
     DB: Database,
     <DB as Database>::Error: Debug,
 {
     let value = ecx.env.tx.value.to_u256();
-    let balance = ZKVMData::new(ecx).get_balance(caller);
+    let fee_payer = if paymaster_params.paymaster.is_zero() {
+        caller
+    } else {
+        paymaster_params.paymaster.to_address()
+    };
+    let balance = ZKVMData::new(ecx).get_balance(fee_payer);
     if balance.is_zero() {
-        error!("balance is 0 for {caller:?}, transaction will fail");
+        error!("balance is 0 for {fee_payer:?}, transaction will fail");
     }
     let max_fee_per_gas = fix_l2_gas_price(ecx.env.tx.gas_price.to_u256());
     let gas_limit = fix_l2_gas_limit(ecx.env.tx.gas_limit.into(), max_fee_per_gas, value, balance);
@@ -275,8 +500,35 @@ pub fn encode_create_params(
     signature.iter().copied().chain(params).collect()
 }
 
+/// Decodes the calldata of a `create` or `create2` call to the deployer contract, returning the
+/// bytecode hash of the deployed contract and its constructor input.
+pub fn decode_create_params(calldata: &[u8]) -> Option<(H256, Vec<u8>)> {
+    if calldata.len() < 4 {
+        return None
+    }
+    let (selector, params) = calldata.split_at(4);
+    let param_types = [
+        ethabi::ParamType::FixedBytes(32),
+        ethabi::ParamType::FixedBytes(32),
+        ethabi::ParamType::Bytes,
+    ];
+    let is_create = ["create", "create2"]
+        .into_iter()
+        .any(|name| ethabi::short_signature(name, &param_types) == selector);
+    if !is_create {
+        return None
+    }
+
+    let mut tokens = ethabi::decode(&param_types, params).ok()?.into_iter().skip(1);
+    let contract_hash = tokens.next()?.into_fixed_bytes()?;
+    let constructor_input = tokens.next()?.into_bytes()?;
+    Some((H256::from_slice(&contract_hash), constructor_input))
+}
+
 /// Get last 256 block hashes mapped to block numbers. This excludes the current block.
-fn get_historical_block_hashes<DB: Database>(ecx: &mut EvmContext<DB>) -> HashMap<rU256, B256> {
+fn get_historical_block_hashes<DB: Database>(
+    ecx: &mut InnerEvmContext<DB>,
+) -> HashMap<rU256, B256> {
     let mut block_hashes = HashMap::default();
     for i in 1..=256u32 {
         let (block_number, overflow) =
@@ -1,6 +1,6 @@
-use std::{cell::RefCell, collections::HashMap, fmt, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, fmt, marker::PhantomData, ptr::NonNull, rc::Rc};
 
-use zksync_state::{ReadStorage, WriteStorage};
+use zksync_state::{ReadStorage, StoragePtr, WriteStorage};
 use zksync_types::{StorageKey, StorageValue, ACCOUNT_CODE_STORAGE_ADDRESS, H160, H256};
 
 use crate::convert::ConvertH160;
@@ -25,8 +25,9 @@ pub(crate) struct StorageView<S> {
     pub(crate) read_storage_keys: HashMap<StorageKey, StorageValue>,
     /// Cache for `contains_key()` checks. The cache is only valid within one L1 batch execution.
     initial_writes_cache: HashMap<StorageKey, bool>,
-    /// The tx caller.
-    caller: H160,
+    /// The tx caller, whose account code is overridden to be empty. This is `None` for
+    /// transactions sent from smart accounts, as their code must be executed.
+    caller: Option<H160>,
 }
 
 impl<S: ReadStorage + fmt::Debug> StorageView<S> {
@@ -34,7 +35,7 @@ impl<S: ReadStorage + fmt::Debug> StorageView<S> {
     pub(crate) fn new(
         storage_handle: S,
         modified_storage_keys: HashMap<StorageKey, StorageValue>,
-        caller: H160,
+        caller: Option<H160>,
     ) -> Self {
         Self {
             storage_handle,
@@ -73,7 +74,9 @@ impl<S: ReadStorage + fmt::Debug> ReadStorage for StorageView<S> {
         let value = self.get_value_no_log(key);
 
         // We override the caller's account code storage to allow for calls
-        if key.address() == &ACCOUNT_CODE_STORAGE_ADDRESS && key.key() == &self.caller.to_h256() {
+        if key.address() == &ACCOUNT_CODE_STORAGE_ADDRESS &&
+            self.caller.is_some_and(|caller| key.key() == &caller.to_h256())
+        {
             let value = StorageValue::zero();
             tracing::trace!(
                 hashed_key = ?key.hashed_key(),
@@ -148,6 +151,74 @@ impl<S: ReadStorage + fmt::Debug> WriteStorage for StorageView<S> {
     }
 }
 
+/// A [ReadStorage] delegating to the storage attached with [SharedStorage::attach].
+///
+/// The storage of a VM is fixed when it is created, so this allows reusing a VM for transactions
+/// executed over different storages, as long as a storage is attached while the VM runs.
+#[derive(Default)]
+pub(crate) struct SharedStorage {
+    attached: Option<NonNull<dyn ReadStorage>>,
+}
+
+impl SharedStorage {
+    /// Attaches `storage` to the view until the returned guard is dropped.
+    pub(crate) fn attach<'a>(
+        view: &'a StoragePtr<StorageView<Self>>,
+        storage: &'a mut dyn ReadStorage,
+    ) -> AttachedStorage<'a> {
+        let storage = NonNull::from(storage);
+        // SAFETY: the pointer is only dereferenced while the returned guard, which holds the
+        // borrow of the storage, is alive, as it detaches the storage when dropped.
+        let storage = unsafe {
+            std::mem::transmute::<NonNull<dyn ReadStorage + 'a>, NonNull<dyn ReadStorage>>(storage)
+        };
+        view.borrow_mut().storage_handle.attached = Some(storage);
+        AttachedStorage { view, _storage: PhantomData }
+    }
+
+    fn storage(&mut self) -> &mut dyn ReadStorage {
+        let mut storage = self.attached.expect("no storage attached to the VM");
+        // SAFETY: the storage is attached, so it is borrowed by an `AttachedStorage` guard.
+        unsafe { storage.as_mut() }
+    }
+}
+
+impl fmt::Debug for SharedStorage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedStorage").field("attached", &self.attached.is_some()).finish()
+    }
+}
+
+impl ReadStorage for SharedStorage {
+    fn read_value(&mut self, key: &StorageKey) -> StorageValue {
+        self.storage().read_value(key)
+    }
+
+    fn is_write_initial(&mut self, key: &StorageKey) -> bool {
+        self.storage().is_write_initial(key)
+    }
+
+    fn load_factory_dep(&mut self, hash: H256) -> Option<Vec<u8>> {
+        self.storage().load_factory_dep(hash)
+    }
+
+    fn get_enumeration_index(&mut self, key: &StorageKey) -> Option<u64> {
+        self.storage().get_enumeration_index(key)
+    }
+}
+
+/// Detaches the storage attached with [SharedStorage::attach] when dropped.
+pub(crate) struct AttachedStorage<'a> {
+    view: &'a StoragePtr<StorageView<SharedStorage>>,
+    _storage: PhantomData<&'a mut dyn ReadStorage>,
+}
+
+impl Drop for AttachedStorage<'_> {
+    fn drop(&mut self) {
+        self.view.borrow_mut().storage_handle.attached = None;
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -189,4 +260,25 @@ mod test {
         assert_eq!(storage_view.read_value(&new_key), new_value);
         assert!(storage_view.is_write_initial(&new_key));
     }
+
+    #[test]
+    fn test_shared_storage() {
+        let account: AccountTreeId = AccountTreeId::new(Address::from([0xfe; 20]));
+        let key = StorageKey::new(account, H256::from_low_u64_be(61));
+        let value = H256::from_low_u64_be(73);
+
+        let view =
+            StorageView::new(SharedStorage::default(), Default::default(), Default::default())
+                .into_rc_ptr();
+        for value in [value, H256::zero()] {
+            let mut raw_storage = InMemoryStorage::default();
+            raw_storage.set_value(key, value);
+            let mut storage = &raw_storage;
+
+            let _attached = SharedStorage::attach(&view, &mut storage);
+            view.borrow_mut().clean_cache();
+            assert_eq!(view.borrow_mut().read_value(&key), value);
+        }
+        assert!(view.borrow().storage_handle.attached.is_none());
+    }
 }
@@ -3,10 +3,10 @@ use std::{
     sync::Arc,
 };
 
-use alloy_primitives::{hex, Address, Bytes, U256 as rU256};
+use alloy_primitives::{hex, Address, Bytes, B256, U256 as rU256};
 use foundry_cheatcodes_common::{
     expect::ExpectedCallTracker,
-    mock::{MockCallDataContext, MockCallReturnData},
+    mock::{find_mock, MockCallDataContext, MockCallReturnData},
     record::RecordAccess,
 };
 use multivm::{
@@ -14,21 +14,28 @@ use multivm::{
     vm_latest::{BootloaderState, HistoryMode, SimpleMemory, VmTracer, ZkSyncVmState},
     zk_evm_latest::{
         tracing::{AfterDecodingData, AfterExecutionData, BeforeExecutionData, VmLocalStateData},
-        zkevm_opcode_defs::{FatPointer, Opcode, CALL_IMPLICIT_CALLDATA_FAT_PTR_REGISTER},
+        zkevm_opcode_defs::{
+            FatPointer, LogOpcode, Opcode, UMAOpcode, CALL_IMPLICIT_CALLDATA_FAT_PTR_REGISTER,
+        },
     },
 };
 use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
 use zksync_state::{ReadStorage, StoragePtr, WriteStorage};
 use zksync_types::{
-    get_code_key, StorageValue, BOOTLOADER_ADDRESS, CONTRACT_DEPLOYER_ADDRESS, H256,
-    SYSTEM_CONTEXT_ADDRESS, U256,
+    get_code_key, StorageValue, BOOTLOADER_ADDRESS, CONTRACT_DEPLOYER_ADDRESS, H160, H256,
+    MSG_VALUE_SIMULATOR_ADDRESS, SYSTEM_CONTEXT_ADDRESS, U256,
 };
 use zksync_utils::bytecode::hash_bytecode;
 
 use crate::{
     convert::{ConvertAddress, ConvertH160, ConvertH256, ConvertU256},
-    vm::farcall::{CallAction, CallDepth},
-    EMPTY_CODE,
+    is_system_address,
+    vm::{
+        farcall::{CallAction, CallDepth},
+        ZkEnv, ZkSystemEnv,
+    },
+    PaymasterParams, ZkAccountData, EMPTY_CODE,
 };
 
 use super::farcall::FarCallHandler;
@@ -71,6 +78,55 @@ const SELECTOR_BASE_FEE: [u8; 4] = hex!("6ef25c3a");
 /// Selector for `getBlockHashEVM(uint256)`
 const SELECTOR_BLOCK_HASH: [u8; 4] = hex!("80b41246");
 
+/// Maximum number of stack words recorded for each debug step, counted from the top of the stack.
+const MAX_DEBUG_STACK_WORDS: u16 = 64;
+
+/// Maximum number of heap bytes recorded for each debug step.
+const MAX_DEBUG_HEAP_BYTES: u32 = 1 << 16;
+
+/// Maximum number of heap bytes recorded over all the debug steps of an execution. Heap snapshots
+/// are no longer recorded past this limit.
+const MAX_DEBUG_TOTAL_HEAP_BYTES: usize = 1 << 28;
+
+/// Maximum number of debug steps recorded for an execution. The following instructions are not
+/// recorded.
+const MAX_DEBUG_STEPS: usize = 1 << 16;
+
+/// Instruction hit counts, keyed by the program counter, for each executed EraVM bytecode hash.
+pub type ZkHitMaps = HashMap<H256, BTreeMap<usize, u64>>;
+
+/// The state of the EraVM before executing an instruction, recorded for the debugger.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EraVmStep {
+    /// The address of the executed code.
+    pub address: Address,
+    /// The bytecode hash of the executed code.
+    pub code_hash: B256,
+    /// The depth of the call stack.
+    pub depth: usize,
+    /// The program counter.
+    pub pc: u16,
+    /// The decoded instruction.
+    pub opcode: String,
+    /// Whether the instruction is a jump.
+    pub is_jump: bool,
+    /// The values of the registers `r1` to `r15`.
+    pub registers: Vec<rU256>,
+    /// The top words of the stack page, up to the stack pointer.
+    pub stack: Vec<rU256>,
+    /// The contents of the heap page, up to the heap bound, recorded only if they may have
+    /// changed since the previous step. Otherwise they are the same as in the latest step that
+    /// recorded them, unless `heap_truncated` is set.
+    pub heap: Option<Bytes>,
+    /// Whether the heap was no longer recorded because the total heap recording limit was
+    /// reached, in which case the contents of the heap at this step are unknown.
+    pub heap_truncated: bool,
+    /// The memory page holding the executed code.
+    pub code_page: u32,
+    /// The ergs remaining in the current frame.
+    pub ergs_remaining: u32,
+}
+
 /// Represents the context for [CheatcodeContext]
 #[derive(Debug, Default)]
 pub struct CheatcodeTracerContext<'a> {
@@ -82,12 +138,29 @@ pub struct CheatcodeTracerContext<'a> {
     pub accesses: Option<&'a mut RecordAccess>,
     /// Factory deps that were persisted across calls
     pub persisted_factory_deps: Option<&'a mut HashMap<H256, Vec<u8>>>,
+    /// Paymaster data
+    pub paymaster_data: Option<PaymasterParams>,
+    /// Account to send the transaction from, with account abstraction
+    pub account_data: Option<ZkAccountData>,
+    /// Pranked `msg.sender` of the call, which also applies to the transactions sent from a
+    /// smart account
+    pub pranked_sender: Option<Address>,
+    /// Instruction coverage recorder, if coverage is enabled
+    pub coverage: Option<&'a mut ZkHitMaps>,
+    /// Debug steps recorder, if debugging is enabled
+    pub debug_steps: Option<&'a mut Vec<EraVmStep>>,
+    /// Batch fee parameters of the forked zkSync chain, if any
+    pub zk_env: Option<ZkEnv>,
+    /// Protocol version and system contracts configured by the user
+    pub system_env: ZkSystemEnv,
 }
 
 /// Tracer result to return back to foundry.
 #[derive(Debug, Default)]
 pub struct CheatcodeTracerResult {
     pub expected_calls: ExpectedCallTracker,
+    pub coverage: Option<ZkHitMaps>,
+    pub debug_steps: Option<Vec<EraVmStep>>,
 }
 
 /// Defines the context for a Vm call.
@@ -112,6 +185,9 @@ pub struct CallContext {
     pub is_create: bool,
     /// Whether the current call is a static call.
     pub is_static: bool,
+    /// Whether the transaction is sent from a smart account, in which case the account's
+    /// validation and execution are run as-is.
+    pub is_account_abstraction: bool,
     /// L1 block hashes to return when `BLOCKHASH` opcode is encountered. This ensures consistency
     /// when returning environment data in L2.
     pub block_hashes: HashMap<alloy_primitives::U256, alloy_primitives::FixedBytes<32>>,
@@ -128,8 +204,27 @@ pub struct CheatcodeTracer {
     pub call_context: CallContext,
     /// Result to send back.
     pub result: Arc<OnceCell<CheatcodeTracerResult>>,
+    /// Instruction hit counts of the executed contracts, if coverage is enabled.
+    pub coverage: Option<ZkHitMaps>,
+    /// Executed instructions of the user contracts, if debugging is enabled.
+    pub debug_steps: Option<Vec<EraVmStep>>,
+    /// Heap page and heap bound of the latest heap snapshot recorded in the debug steps, unless
+    /// the heap was written to since.
+    last_debug_heap: Option<(u32, u32)>,
+    /// Total number of heap bytes recorded in the debug steps.
+    debug_heap_bytes: usize,
+    /// Whether the debug steps limit was reached.
+    debug_steps_truncated: bool,
+    /// Whether a heap snapshot was skipped because of the total heap recording limit, after
+    /// which the heap is no longer recorded.
+    debug_heap_truncated: bool,
     /// Handle farcall state.
     farcall_handler: FarCallHandler,
+    /// Whether the account is executing the transaction and has not called the target contract
+    /// yet, whose `msg.sender` is then overridden.
+    msg_sender_override_pending: bool,
+    /// Cached bytecode hashes of the executed contracts, used for coverage and debugging.
+    code_hashes: HashMap<H160, H256>,
 }
 
 impl CheatcodeTracer {
@@ -139,8 +234,28 @@ impl CheatcodeTracer {
         expected_calls: ExpectedCallTracker,
         result: Arc<OnceCell<CheatcodeTracerResult>>,
         call_context: CallContext,
+        collect_coverage: bool,
+        record_debug_steps: bool,
     ) -> Self {
-        CheatcodeTracer { mocked_calls, expected_calls, call_context, result, ..Default::default() }
+        CheatcodeTracer {
+            mocked_calls,
+            expected_calls,
+            call_context,
+            result,
+            coverage: collect_coverage.then(Default::default),
+            debug_steps: record_debug_steps.then(Default::default),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the bytecode hash of the code at the given address, with the "is constructing"
+    /// marker cleared so constructor and runtime instructions are attributed to the same bytecode.
+    fn code_hash<S: ReadStorage>(&mut self, storage: &StoragePtr<S>, address: H160) -> H256 {
+        *self.code_hashes.entry(address).or_insert_with(|| {
+            let mut code_hash = storage.borrow_mut().read_value(&get_code_key(&address));
+            code_hash.0[1] = 0;
+            code_hash
+        })
     }
 
     /// Check if the given address's code is empty
@@ -170,11 +285,94 @@ impl<S: ReadStorage, H: HistoryMode> DynTracer<S, SimpleMemory<H>> for Cheatcode
 
     fn before_execution(
         &mut self,
-        _state: VmLocalStateData<'_>,
-        _data: BeforeExecutionData,
-        _memory: &SimpleMemory<H>,
-        _storage: StoragePtr<S>,
+        state: VmLocalStateData<'_>,
+        data: BeforeExecutionData,
+        memory: &SimpleMemory<H>,
+        storage: StoragePtr<S>,
     ) {
+        // Only user contracts are of interest for coverage and debugging, the bootloader and
+        // system contracts are skipped.
+        let current = state.vm_local_state.callstack.current;
+        if (self.coverage.is_none() && self.debug_steps.is_none()) ||
+            is_system_address(current.code_address.to_address())
+        {
+            return
+        }
+        let code_hash = self.code_hash(&storage, current.code_address);
+
+        if let Some(coverage) = &mut self.coverage {
+            *coverage.entry(code_hash).or_default().entry(current.pc as usize).or_default() += 1;
+        }
+
+        if let Some(debug_steps) = &mut self.debug_steps {
+            if debug_steps.len() >= MAX_DEBUG_STEPS {
+                if !self.debug_steps_truncated {
+                    self.debug_steps_truncated = true;
+                    tracing::warn!(
+                        "reached the limit of {MAX_DEBUG_STEPS} debug steps, the following \
+                         instructions are not recorded"
+                    );
+                }
+                return
+            }
+
+            // The stack and heap pages of a frame directly follow its base memory page.
+            let stack_page = current.base_memory_page.0 + 1;
+            let heap_page = current.base_memory_page.0 + 2;
+            let stack_start = current.sp.saturating_sub(MAX_DEBUG_STACK_WORDS);
+
+            // The heap is only recorded when it may have changed since the latest snapshot, that
+            // is when entering another frame, after its bound grew or after it was written to.
+            let heap_state = (heap_page, current.heap_bound);
+            let heap_changed = self.last_debug_heap != Some(heap_state);
+            if heap_changed && self.debug_heap_bytes >= MAX_DEBUG_TOTAL_HEAP_BYTES {
+                self.debug_heap_truncated = true;
+            }
+            let heap = (heap_changed && !self.debug_heap_truncated).then(|| {
+                Bytes::from(memory.read_unaligned_bytes(
+                    heap_page as usize,
+                    0,
+                    current.heap_bound.min(MAX_DEBUG_HEAP_BYTES) as usize,
+                ))
+            });
+            if let Some(heap) = &heap {
+                self.debug_heap_bytes += heap.len();
+                self.last_debug_heap = Some(heap_state);
+            }
+            if matches!(
+                data.opcode.variant.opcode,
+                Opcode::UMA(UMAOpcode::HeapWrite) | Opcode::Log(LogOpcode::PrecompileCall)
+            ) {
+                self.last_debug_heap = None;
+            }
+
+            debug_steps.push(EraVmStep {
+                address: current.code_address.to_address(),
+                code_hash: code_hash.to_b256(),
+                depth: state.vm_local_state.callstack.depth(),
+                pc: current.pc,
+                opcode: format!("{:?}", data.opcode.variant.opcode),
+                is_jump: matches!(data.opcode.variant.opcode, Opcode::Jump(_)),
+                registers: state
+                    .vm_local_state
+                    .registers
+                    .iter()
+                    .map(|register| register.value.to_ru256())
+                    .collect(),
+                stack: memory
+                    .dump_page_content_as_u256_words(
+                        stack_page,
+                        stack_start as u32..current.sp as u32,
+                    )
+                    .into_iter()
+                    .map(ConvertU256::to_ru256)
+                    .collect(),
+                heap,
+                heap_truncated: self.debug_heap_truncated,
+                code_page: current.code_page.0,
+                ergs_remaining: current.ergs_remaining,
+            });
+        }
     }
 
     fn after_execution(
@@ -219,28 +417,30 @@ impl<S: ReadStorage, H: HistoryMode> DynTracer<S, SimpleMemory<H>> for Cheatcode
             let call_contract = current.code_address.to_address();
             let call_value = U256::from(current.context_u128_value).to_ru256();
 
-            let mocks = self.mocked_calls.get(&call_contract);
+            // the calls made by the bootloader itself (e.g. to charge the fees) are never mocked,
+            // so mocks on system contracts only apply to the calls of the transaction
+            let mocks = (current.msg_sender != BOOTLOADER_ADDRESS)
+                .then(|| self.mocked_calls.get(&call_contract))
+                .flatten();
             if let Some(mocks) = &mocks {
-                let ctx = MockCallDataContext {
-                    calldata: Bytes::from(call_input.clone()),
-                    value: Some(call_value),
-                };
-                if let Some(return_data) = mocks.get(&ctx).or_else(|| {
-                    mocks
-                        .iter()
-                        .find(|(mock, _)| {
-                            call_input.get(..mock.calldata.len()) == Some(&mock.calldata[..]) &&
-                                mock.value.map_or(true, |value| value == call_value)
-                        })
-                        .map(|(_, v)| v)
-                }) {
-                    let return_data = return_data.data.clone().to_vec();
-                    tracing::info!(
-                        "returning mocked value {:?} for {:?}",
-                        hex::encode(&call_input),
-                        hex::encode(&return_data)
-                    );
-                    self.farcall_handler.set_immediate_return(return_data);
+                let calldata = Bytes::from(call_input.clone());
+                if let Some(return_data) = find_mock(mocks, &calldata, Some(call_value)) {
+                    let return_data_bytes = return_data.data.to_vec();
+                    if return_data.ret_type.is_revert() {
+                        tracing::info!(
+                            "reverting with mocked value {:?} for {:?}",
+                            hex::encode(&call_input),
+                            hex::encode(&return_data_bytes)
+                        );
+                        self.farcall_handler.set_immediate_revert(return_data_bytes);
+                    } else {
+                        tracing::info!(
+                            "returning mocked value {:?} for {:?}",
+                            hex::encode(&call_input),
+                            hex::encode(&return_data_bytes)
+                        );
+                        self.farcall_handler.set_immediate_return(return_data_bytes);
+                    }
                     return;
                 }
             }
@@ -284,18 +484,29 @@ impl<S: ReadStorage, H: HistoryMode> DynTracer<S, SimpleMemory<H>> for Cheatcode
             }
         }
 
-        // Override msg.sender for the transaction
+        // Override msg.sender for the transaction. The account executing the transaction may do
+        // other far calls before calling the target contract, so the override is applied to the
+        // first call of the account into either the target contract or the `MsgValueSimulator`,
+        // which forwards its own `msg.sender` to the target when value is sent.
         if let Opcode::FarCall(_call) = data.opcode.variant.opcode {
-            let calldata = get_calldata(&state, memory);
             let current = state.vm_local_state.callstack.current;
 
-            if current.msg_sender == BOOTLOADER_ADDRESS &&
-                calldata.starts_with(&SELECTOR_EXECUTE_TRANSACTION)
-            {
-                self.farcall_handler.set_action(
-                    CallDepth::next(),
-                    CallAction::SetMessageSender(self.call_context.msg_sender),
-                );
+            if self.call_context.msg_sender != self.call_context.tx_caller {
+                if current.msg_sender == BOOTLOADER_ADDRESS &&
+                    get_calldata(&state, memory).starts_with(&SELECTOR_EXECUTE_TRANSACTION)
+                {
+                    self.msg_sender_override_pending = true;
+                } else if self.msg_sender_override_pending &&
+                    current.msg_sender.to_address() == self.call_context.tx_caller &&
+                    (current.code_address.to_address() == self.call_context.contract ||
+                        current.code_address == MSG_VALUE_SIMULATOR_ADDRESS)
+                {
+                    self.msg_sender_override_pending = false;
+                    self.farcall_handler.set_action(
+                        CallDepth::current(),
+                        CallAction::SetMessageSender(self.call_context.msg_sender),
+                    );
+                }
             }
         }
 
@@ -396,7 +607,12 @@ impl<S: WriteStorage, H: HistoryMode> VmTracer<S, H> for CheatcodeTracer {
         _stop_reason: multivm::interface::tracer::VmExecutionStopReason,
     ) {
         let cell = self.result.as_ref();
-        cell.set(CheatcodeTracerResult { expected_calls: self.expected_calls.clone() }).unwrap();
+        cell.set(CheatcodeTracerResult {
+            expected_calls: self.expected_calls.clone(),
+            coverage: self.coverage.take(),
+            debug_steps: self.debug_steps.take(),
+        })
+        .unwrap();
     }
 }
 
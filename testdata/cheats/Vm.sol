@@ -130,6 +130,25 @@ interface Vm {
         uint64 gasRemaining;
     }
 
+    struct L2ToL1Log {
+        bool isService;
+        address sender;
+        bytes32 key;
+        bytes32 value;
+    }
+
+    struct L2ToL1Message {
+        address sender;
+        bytes data;
+    }
+
+    struct L1TxResult {
+        bool success;
+        bytes returnData;
+        uint256 gasUsed;
+        uint256 refund;
+    }
+
     function _expectCheatcodeRevert() external;
     function _expectCheatcodeRevert(bytes4 revertData) external;
     function _expectCheatcodeRevert(bytes calldata revertData) external;
@@ -419,6 +438,7 @@ interface Vm {
         external;
     function expectEmit() external;
     function expectEmit(address emitter) external;
+    function expectL2ToL1Message(address sender, bytes calldata data) external;
     function expectRevert() external;
     function expectRevert(bytes4 revertData) external;
     function expectRevert(bytes calldata revertData) external;
@@ -688,6 +708,27 @@ interface Vm {
     function writeLine(string calldata path, string calldata data) external;
     function writeToml(string calldata json, string calldata path) external;
     function writeToml(string calldata json, string calldata path, string calldata valueKey) external;
+    function zkExecuteL1Tx(
+        address from,
+        address to,
+        uint256 value,
+        bytes calldata data,
+        uint256 gasLimit,
+        bytes[] calldata factoryDeps
+    ) external returns (L1TxResult memory result);
+    function zkExecuteL1Tx(
+        address from,
+        address to,
+        uint256 value,
+        bytes calldata data,
+        uint256 gasLimit,
+        bytes[] calldata factoryDeps,
+        address refundRecipient
+    ) external returns (L1TxResult memory result);
+    function zkGetAccountCodeHash(address account) external view returns (bytes32 codeHash);
+    function zkGetDeployNonce(address account) external view returns (uint64 nonce);
+    function zkGetRecordedL2ToL1Logs() external returns (L2ToL1Log[] memory logs);
+    function zkGetRecordedL2ToL1Messages() external returns (L2ToL1Message[] memory messages);
     function zkRegisterContract(
         string calldata name,
         bytes32 evmBytecodeHash,
@@ -696,5 +737,8 @@ interface Vm {
         bytes32 zkBytecodeHash,
         bytes calldata zkDeployedBytecode
     ) external pure;
+    function zkSetDeployNonce(address account, uint64 newNonce) external;
+    function zkUseAccount(address account, bytes calldata signature) external pure;
+    function zkUsePaymaster(address paymaster_address, bytes calldata paymaster_input) external pure;
     function zkVm(bool enable) external pure;
 }
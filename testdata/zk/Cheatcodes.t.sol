@@ -67,6 +67,12 @@ contract Emitter {
     function emitConsole(string memory message) public view {
         console.log(message);
     }
+
+    function functionEmitWithConsole() public {
+        emit EventFunction(FUNCTION_MESSAGE);
+        console.log(FUNCTION_MESSAGE);
+        emit EventConstructor(CONSTRUCTOR_MESSAGE);
+    }
 }
 
 contract ZkCheatcodesTest is DSTest {
@@ -172,6 +178,16 @@ contract ZkCheatcodesTest is DSTest {
         emitter.functionEmit();
     }
 
+    function testExpectEmitSkipsConsoleLogs() public {
+        Emitter emitter = new Emitter();
+
+        vm.expectEmit(true, true, true, true, address(emitter));
+        emit EventFunction("function");
+        vm.expectEmit(true, true, true, true, address(emitter));
+        emit EventConstructor("constructor");
+        emitter.functionEmitWithConsole();
+    }
+
     function testZkCheatcodesValueFunctionMockReturn() public {
         InnerMock inner = new InnerMock();
         // Send some funds to so it can pay for the inner call
@@ -222,22 +238,35 @@ contract ZkCheatcodesTest is DSTest {
         // ensure we are in zkvm
         vm.zkVm(true);
         vm.recordLogs();
-        Emitter emitter = new Emitter(); // +7 logs from system contracts
-        emitter.functionEmit(); // +3 from system contracts
+        Emitter emitter = new Emitter();
+        emitter.functionEmit();
+
+        // events emitted by the system contracts are not recorded, like in the EVM
+        Vm.Log[] memory entries = vm.getRecordedLogs();
+        assertEq(entries.length, 2);
+        assertEq(entries[0].topics.length, 1);
+        assertEq(entries[0].topics[0], keccak256("EventConstructor(string)"));
+        assertEq(entries[0].data, abi.encode("constructor"));
+        assertEq(entries[0].emitter, address(emitter));
+
+        assertEq(entries[1].topics.length, 1);
+        assertEq(entries[1].topics[0], keccak256("EventFunction(string)"));
+        assertEq(entries[1].data, abi.encode("function"));
+        assertEq(entries[1].emitter, address(emitter));
+    }
+
+    function testRecordLogsInZkVmSkipsConsoleLogs() public {
+        Emitter emitter = new Emitter();
+
+        vm.recordLogs();
+        emitter.functionEmitWithConsole();
 
         Vm.Log[] memory entries = vm.getRecordedLogs();
-        assertEq(entries.length, 12);
-        // 0,1: EthToken, 2,3: L1 Messanger, 4: Known Code Storage
-        assertEq(entries[5].topics.length, 1);
-        assertEq(entries[5].topics[0], keccak256("EventConstructor(string)"));
-        assertEq(entries[5].data, abi.encode("constructor"));
-        // 6: L2 Deployer, 7: EthToken
-
-        // 8,9: EthToken
-        assertEq(entries[10].topics.length, 1);
-        assertEq(entries[10].topics[0], keccak256("EventFunction(string)"));
-        assertEq(entries[10].data, abi.encode("function"));
-        // 11: EthToken
+        assertEq(entries.length, 2);
+        assertEq(entries[0].topics[0], keccak256("EventFunction(string)"));
+        assertEq(entries[0].emitter, address(emitter));
+        assertEq(entries[1].topics[0], keccak256("EventConstructor(string)"));
+        assertEq(entries[1].emitter, address(emitter));
     }
 
     function testRecordConsoleLogsLikeEVM() public {
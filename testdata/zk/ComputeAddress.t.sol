@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.18;
+
+import "ds-test/test.sol";
+import "../cheats/Vm.sol";
+
+interface ISystemContractDeployer {
+    function getNewAddressCreate2(address _sender, bytes32 _bytecodeHash, bytes32 _salt, bytes calldata _input)
+        external
+        view
+        returns (address newAddress);
+}
+
+contract AddressTarget {
+    uint256 public number = 42;
+}
+
+contract AddressTargetWithArgs {
+    uint256 public number;
+
+    constructor(uint256 _number) {
+        number = _number;
+    }
+}
+
+contract ZkComputeAddressTest is DSTest {
+    Vm constant vm = Vm(HEVM_ADDRESS);
+
+    function testZkComputeCreateAddress() public {
+        uint64 deployNonce = vm.zkGetDeployNonce(address(this));
+        address expected = vm.computeCreateAddress(address(this), deployNonce);
+
+        AddressTarget target = new AddressTarget();
+        assertEq(address(target), expected);
+        assertEq(target.number(), 42);
+    }
+
+    function testZkComputeCreate2Address() public {
+        bytes32 salt = bytes32(uint256(1234));
+        address expected =
+            vm.computeCreate2Address(salt, keccak256(type(AddressTarget).creationCode), address(this));
+
+        AddressTarget target = new AddressTarget{salt: salt}();
+        assertEq(address(target), expected);
+        assertEq(target.number(), 42);
+    }
+
+    function testZkComputeCreate2AddressMatchesContractDeployer() public {
+        bytes32 salt = bytes32(uint256(1234));
+        address expected =
+            vm.computeCreate2Address(salt, keccak256(type(AddressTarget).creationCode), address(this));
+
+        AddressTarget target = new AddressTarget{salt: salt}();
+        bytes32 bytecodeHash = vm.zkGetAccountCodeHash(address(target));
+        // the `ContractDeployer` system contract is the reference derivation of the addresses
+        ISystemContractDeployer deployer = ISystemContractDeployer(address(0x8006));
+        assertEq(deployer.getNewAddressCreate2(address(this), bytecodeHash, salt, ""), expected);
+    }
+
+    function testZkComputeCreate2AddressWithDefaultDeployer() public {
+        bytes32 salt = bytes32(uint256(1234));
+        address expected = vm.computeCreate2Address(salt, keccak256(type(AddressTarget).creationCode));
+
+        AddressTarget target = new AddressTarget{salt: salt}();
+        bytes32 bytecodeHash = vm.zkGetAccountCodeHash(address(target));
+        // the default deployer is the `Create2Factory` rather than the deterministic deployment proxy
+        ISystemContractDeployer deployer = ISystemContractDeployer(address(0x8006));
+        assertEq(deployer.getNewAddressCreate2(address(0x10000), bytecodeHash, salt, ""), expected);
+    }
+
+    function testZkComputeCreate2AddressWithConstructorArgsFails() public {
+        bytes32 initCodeHash =
+            keccak256(abi.encodePacked(type(AddressTargetWithArgs).creationCode, abi.encode(uint256(7))));
+
+        vm._expectCheatcodeRevert(
+            bytes(
+                string.concat(
+                    "no zksolc contract found for the init code hash ",
+                    vm.toString(initCodeHash),
+                    "; contracts with constructor arguments are not supported in the zkVM"
+                )
+            )
+        );
+        vm.computeCreate2Address(bytes32(0), initCodeHash, address(this));
+    }
+}
@@ -0,0 +1,235 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.18;
+
+import "ds-test/test.sol";
+import "../cheats/Vm.sol";
+
+struct AccountTransaction {
+    uint256 txType;
+    uint256 from;
+    uint256 to;
+    uint256 gasLimit;
+    uint256 gasPerPubdataByteLimit;
+    uint256 maxFeePerGas;
+    uint256 maxPriorityFeePerGas;
+    uint256 paymaster;
+    uint256 nonce;
+    uint256 value;
+    uint256[4] reserved;
+    bytes data;
+    bytes signature;
+    bytes32[] factoryDeps;
+    bytes paymasterInput;
+    bytes reservedDynamic;
+}
+
+interface INonceHolder {
+    function incrementMinNonceIfEquals(uint256 _expectedNonce) external;
+    function setValueUnderNonce(uint256 _key, uint256 _value) external;
+}
+
+interface IContractDeployer {
+    enum AccountNonceOrdering {
+        Sequential,
+        Arbitrary
+    }
+
+    function updateNonceOrdering(AccountNonceOrdering _nonceOrdering) external;
+}
+
+/// A minimal smart account accepting transactions signed with "valid", which uses sequential nonces.
+///
+/// It has no constructor arguments nor immutables so that it can be etched.
+contract MyAccount {
+    address constant BOOTLOADER_FORMAL_ADDRESS = address(0x8001);
+    address constant NONCE_HOLDER_SYSTEM_CONTRACT = address(0x8003);
+    address constant DEPLOYER_SYSTEM_CONTRACT = address(0x8006);
+    address constant SYSTEM_CALL_CALL_ADDRESS = address((1 << 16) - 11);
+
+    modifier onlyBootloader() {
+        require(msg.sender == BOOTLOADER_FORMAL_ADDRESS, "Only bootloader can call this method");
+        _;
+    }
+
+    function validateTransaction(bytes32, bytes32, AccountTransaction calldata _transaction)
+        external
+        payable
+        onlyBootloader
+        returns (bytes4 magic)
+    {
+        _useNonce(_transaction.nonce);
+
+        if (keccak256(_transaction.signature) == keccak256("valid")) {
+            magic = MyAccount.validateTransaction.selector;
+        }
+    }
+
+    function payForTransaction(bytes32, bytes32, AccountTransaction calldata _transaction)
+        external
+        payable
+        onlyBootloader
+    {
+        uint256 requiredETH = _transaction.gasLimit * _transaction.maxFeePerGas;
+        (bool success,) = payable(BOOTLOADER_FORMAL_ADDRESS).call{value: requiredETH}("");
+        require(success, "Failed to pay the fee to the operator");
+    }
+
+    /// Executes the transaction, returning its result like the default account does.
+    function executeTransaction(bytes32, bytes32, AccountTransaction calldata _transaction)
+        external
+        payable
+        onlyBootloader
+        returns (bytes memory)
+    {
+        address to = address(uint160(_transaction.to));
+        // deployments can only be requested with a system call
+        if (to == DEPLOYER_SYSTEM_CONTRACT) {
+            require(_transaction.value == 0, "Deployments with value are not supported");
+            return _systemCall(to, _transaction.data);
+        }
+
+        (bool success, bytes memory result) = to.call{value: _transaction.value}(_transaction.data);
+        require(success, "Failed to execute transaction");
+        return result;
+    }
+
+    function _useNonce(uint256 nonce) internal virtual {
+        _systemCall(NONCE_HOLDER_SYSTEM_CONTRACT, abi.encodeCall(INonceHolder.incrementMinNonceIfEquals, (nonce)));
+    }
+
+    function _systemCall(address to, bytes memory data) internal returns (bytes memory result) {
+        address callAddr = SYSTEM_CALL_CALL_ADDRESS;
+        uint256 farCallAbi;
+        assembly {
+            farCallAbi := or(shl(64, add(data, 0x20)), shl(96, mload(data)))
+        }
+        farCallAbi |= uint256(uint32(gasleft())) << 192;
+        farCallAbi |= 1 << 248;
+
+        bool success;
+        assembly {
+            success := call(to, callAddr, 0, 0, farCallAbi, 0, 0)
+        }
+        require(success, "System call failed");
+
+        assembly {
+            result := mload(0x40)
+            mstore(result, returndatasize())
+            returndatacopy(add(result, 0x20), 0, returndatasize())
+            mstore(0x40, add(add(result, 0x20), returndatasize()))
+        }
+    }
+
+    receive() external payable {}
+}
+
+/// A smart account that uses arbitrary nonce ordering, marking each nonce as used.
+contract MyArbitraryNonceAccount is MyAccount {
+    constructor() {
+        _systemCall(
+            DEPLOYER_SYSTEM_CONTRACT,
+            abi.encodeCall(IContractDeployer.updateNonceOrdering, (IContractDeployer.AccountNonceOrdering.Arbitrary))
+        );
+    }
+
+    function _useNonce(uint256 nonce) internal override {
+        _systemCall(NONCE_HOLDER_SYSTEM_CONTRACT, abi.encodeCall(INonceHolder.setValueUnderNonce, (nonce, 1)));
+    }
+}
+
+contract AccountCounter {
+    uint256 public number;
+    address public lastCaller;
+    address public deployer;
+
+    constructor() {
+        deployer = msg.sender;
+    }
+
+    function increment() public {
+        number += 1;
+        lastCaller = msg.sender;
+    }
+}
+
+/// Runs with the EraVM extensions enabled, which `MyAccount` needs for its system calls.
+contract ZkCustomAccountTest is DSTest {
+    Vm constant vm = Vm(HEVM_ADDRESS);
+
+    MyAccount account;
+    MyArbitraryNonceAccount arbitraryAccount;
+    AccountCounter counter;
+
+    function setUp() public {
+        account = new MyAccount();
+        arbitraryAccount = new MyArbitraryNonceAccount();
+        counter = new AccountCounter();
+        vm.deal(address(account), 1 ether);
+        vm.deal(address(arbitraryAccount), 1 ether);
+    }
+
+    function testZkCallFromCustomAccount() public {
+        uint256 accountPreBalance = address(account).balance;
+
+        vm.zkUseAccount(address(account), "valid");
+        counter.increment();
+
+        assertEq(counter.number(), 1);
+        assertEq(counter.lastCaller(), address(account));
+        assertEq(vm.getNonce(address(account)), 1);
+        assertLt(address(account).balance, accountPreBalance);
+
+        // only the next call is sent from the account
+        counter.increment();
+        assertEq(counter.lastCaller(), address(this));
+    }
+
+    function testFailZkCallFromCustomAccountWithInvalidSignature() public {
+        vm.zkUseAccount(address(account), "invalid");
+        counter.increment();
+    }
+
+    function testZkCreateFromCustomAccount() public {
+        vm.zkUseAccount(address(account), "valid");
+        AccountCounter deployed = new AccountCounter();
+
+        assertEq(deployed.deployer(), address(account));
+        assertEq(vm.getNonce(address(account)), 1);
+        assertEq(vm.zkGetDeployNonce(address(account)), 1);
+    }
+
+    function testZkCallFromCustomAccountWithArbitraryNonceOrdering() public {
+        vm.zkUseAccount(address(arbitraryAccount), "valid");
+        counter.increment();
+        // the nonce used by the previous transaction is skipped
+        vm.zkUseAccount(address(arbitraryAccount), "valid");
+        counter.increment();
+
+        assertEq(counter.number(), 2);
+        assertEq(counter.lastCaller(), address(arbitraryAccount));
+        // the nonces are marked as used without increasing the minimal nonce
+        assertEq(vm.getNonce(address(arbitraryAccount)), 0);
+    }
+}
+
+/// Runs with `zksync.custom_account` set to `ACCOUNT`.
+contract ZkCustomAccountConfigTest is DSTest {
+    Vm constant vm = Vm(HEVM_ADDRESS);
+
+    address constant ACCOUNT = address(0xc0ffee);
+
+    function setUp() public {
+        string memory artifact = vm.readFile("./zk/zkout-eravm-extensions/CustomAccount.t.sol/MyAccount.json");
+        vm.etch(ACCOUNT, vm.parseJsonBytes(artifact, ".bytecode.object"));
+        vm.deal(ACCOUNT, 1 ether);
+    }
+
+    function testZkCustomAccountFromConfig() public {
+        AccountCounter counter = new AccountCounter();
+        assertEq(counter.deployer(), ACCOUNT);
+
+        counter.increment();
+        assertEq(counter.number(), 1);
+        assertEq(counter.lastCaller(), ACCOUNT);
+    }
+}
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.18;
+
+import "ds-test/test.sol";
+import "../cheats/Vm.sol";
+import {ConstantNumber} from "./ConstantNumber.sol";
+
+contract TestContractWithArgs {
+    uint256 public a;
+    uint256 public b;
+
+    constructor(uint256 _a, uint256 _b) {
+        a = _a;
+        b = _b;
+    }
+}
+
+contract ZkDeployCodeTest is DSTest {
+    Vm constant vm = Vm(HEVM_ADDRESS);
+
+    function testZkGetCodeReturnsZksolcBytecode() public {
+        string memory artifact = vm.readFile("./zk/zkout/ConstantNumber.sol/ConstantNumber.json");
+        bytes memory zkBytecode = vm.parseJsonBytes(artifact, ".bytecode.object");
+
+        assertEq(vm.getCode("ConstantNumber.sol:ConstantNumber"), zkBytecode);
+    }
+
+    function testZkGetCodeReturnsSolcBytecodeInEvm() public {
+        vm.zkVm(false);
+
+        assertEq(vm.getCode("ConstantNumber.sol:ConstantNumber"), type(ConstantNumber).creationCode);
+        assertEq(vm.getDeployedCode("ConstantNumber.sol:ConstantNumber"), address(new ConstantNumber()).code);
+    }
+
+    function testZkDeployCode() public {
+        address addr = vm.deployCode("ConstantNumber.sol:ConstantNumber");
+        assertEq(ConstantNumber(addr).ten(), 10);
+    }
+
+    function testZkDeployCodeFromArtifactPath() public {
+        address addr = vm.deployCode("./zk/zkout/ConstantNumber.sol/ConstantNumber.json");
+        assertEq(ConstantNumber(addr).ten(), 10);
+    }
+
+    function testZkDeployCodeWithArgs() public {
+        TestContractWithArgs deployed =
+            TestContractWithArgs(vm.deployCode("DeployCode.t.sol:TestContractWithArgs", abi.encode(3, 4)));
+
+        assertEq(deployed.a(), 3);
+        assertEq(deployed.b(), 4);
+    }
+
+    function testZkDeployRawEraVmBytecode() public {
+        bytes memory code = vm.getCode("ConstantNumber.sol:ConstantNumber");
+        // changing the trailing metadata makes the bytecode unknown without changing its behavior
+        code[code.length - 1] = bytes1(uint8(code[code.length - 1]) ^ 0xff);
+
+        address addr;
+        assembly {
+            addr := create(0, add(code, 0x20), mload(code))
+        }
+        assertTrue(addr != address(0));
+        assertEq(ConstantNumber(addr).ten(), 10);
+    }
+
+    function testZkDeployInvalidBytecodeFails() public {
+        bytes memory code = hex"deadbeef";
+
+        address addr;
+        bytes memory revertData;
+        assembly {
+            addr := create(0, add(code, 0x20), mload(code))
+            revertData := mload(0x40)
+            mstore(revertData, returndatasize())
+            returndatacopy(add(revertData, 0x20), 0, returndatasize())
+            mstore(0x40, add(add(revertData, 0x20), returndatasize()))
+        }
+        assertEq(addr, address(0));
+        assertTrue(revertData.length > 4);
+        assertEq(bytes4(revertData), bytes4(keccak256("CheatcodeError(string)")));
+    }
+}
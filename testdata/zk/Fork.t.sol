@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+pragma solidity ^0.8.18;
+
+import "ds-test/test.sol";
+import "../cheats/Vm.sol";
+import {Globals} from "./Globals.sol";
+
+interface IERC20 {
+    function decimals() external view returns (uint8);
+    function totalSupply() external view returns (uint256);
+}
+
+contract ZkForkDeployedContractsTest is DSTest {
+    Vm constant vm = Vm(HEVM_ADDRESS);
+
+    uint256 constant ERA_FORK_BLOCK = 19579636;
+
+    // Bridged USDC, an upgradeable proxy deployed on zkSync Era mainnet before the fork block.
+    IERC20 constant USDC = IERC20(0x3355df6D4c9C3035724Fd0e3914dE96A5a83aaf4);
+
+    function setUp() public {
+        vm.createSelectFork(Globals.ZKSYNC_MAINNET_URL, ERA_FORK_BLOCK);
+    }
+
+    function testZkForkCanCallDeployedContracts() public {
+        require(USDC.decimals() == 6, "decimals mismatch");
+        require(USDC.totalSupply() > 0, "total supply mismatch");
+    }
+}
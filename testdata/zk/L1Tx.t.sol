@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.18;
+
+import "ds-test/test.sol";
+import "../cheats/Vm.sol";
+
+contract DepositReceiver {
+    address public lastSender;
+    uint256 public lastAmount;
+
+    function finalizeDeposit(uint256 amount) public payable {
+        require(msg.value == amount, "invalid deposit amount");
+        lastSender = msg.sender;
+        lastAmount = amount;
+    }
+}
+
+contract ZkL1TxTest is DSTest {
+    Vm constant vm = Vm(HEVM_ADDRESS);
+
+    address constant L1_BRIDGE = address(0x1234);
+    address constant REFUND_RECIPIENT = address(0xbeef);
+    uint256 constant GAS_LIMIT = 5_000_000;
+
+    DepositReceiver receiver;
+
+    function setUp() public {
+        receiver = new DepositReceiver();
+    }
+
+    function aliased(address l1Address) internal pure returns (address) {
+        unchecked {
+            return address(uint160(l1Address) + uint160(0x1111000000000000000000000000000000001111));
+        }
+    }
+
+    function testZkExecuteL1Tx() public {
+        Vm.L1TxResult memory result = vm.zkExecuteL1Tx(
+            L1_BRIDGE,
+            address(receiver),
+            1 ether,
+            abi.encodeCall(DepositReceiver.finalizeDeposit, (1 ether)),
+            GAS_LIMIT,
+            new bytes[](0)
+        );
+
+        assertTrue(result.success);
+        assertEq(receiver.lastSender(), aliased(L1_BRIDGE));
+        assertEq(receiver.lastAmount(), 1 ether);
+        assertEq(address(receiver).balance, 1 ether);
+
+        assertTrue(result.gasUsed > 0 && result.gasUsed < GAS_LIMIT);
+        // the unspent fees are refunded to the sender, which is not aliased as it has no code
+        assertTrue(result.refund > 0);
+        assertEq(L1_BRIDGE.balance, result.refund);
+        assertEq(aliased(L1_BRIDGE).balance, 0);
+    }
+
+    function testZkExecuteL1TxRefundsAliasedContractSender() public {
+        Vm.L1TxResult memory result = vm.zkExecuteL1Tx(
+            address(this),
+            address(receiver),
+            1 ether,
+            abi.encodeCall(DepositReceiver.finalizeDeposit, (1 ether)),
+            GAS_LIMIT,
+            new bytes[](0)
+        );
+
+        assertTrue(result.success);
+        assertEq(receiver.lastSender(), aliased(address(this)));
+
+        // contracts are refunded to their aliased address
+        assertTrue(result.refund > 0);
+        assertEq(aliased(address(this)).balance, result.refund);
+    }
+
+    function testZkExecuteL1TxRefundsValueOnFailure() public {
+        vm.deal(REFUND_RECIPIENT, 1 ether);
+
+        Vm.L1TxResult memory result = vm.zkExecuteL1Tx(
+            L1_BRIDGE,
+            address(receiver),
+            1 ether,
+            abi.encodeCall(DepositReceiver.finalizeDeposit, (2 ether)),
+            GAS_LIMIT,
+            new bytes[](0),
+            REFUND_RECIPIENT
+        );
+
+        assertTrue(!result.success);
+        assertEq(receiver.lastSender(), address(0));
+        assertEq(address(receiver).balance, 0);
+
+        // the value is refunded along with the unspent fees, on top of the existing balance
+        assertTrue(result.refund > 1 ether);
+        assertEq(REFUND_RECIPIENT.balance, 1 ether + result.refund);
+    }
+}
@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.18;
+
+import "ds-test/test.sol";
+import "../cheats/Vm.sol";
+
+interface IL1Messenger {
+    function sendToL1(bytes memory message) external returns (bytes32);
+}
+
+contract Withdrawer {
+    /// Address of the `L1Messenger` system contract.
+    IL1Messenger constant L1_MESSENGER = IL1Messenger(address(0x8008));
+
+    function withdraw(bytes memory message) public returns (bytes32) {
+        return L1_MESSENGER.sendToL1(message);
+    }
+
+    function withdrawAndRevert(bytes memory message) public {
+        L1_MESSENGER.sendToL1(message);
+        revert("withdrawal failed");
+    }
+}
+
+contract ZkL2ToL1MessagesTest is DSTest {
+    Vm constant vm = Vm(HEVM_ADDRESS);
+
+    Withdrawer withdrawer;
+
+    function setUp() public {
+        withdrawer = new Withdrawer();
+    }
+
+    function testZkRecordL2ToL1Messages() public {
+        bytes32 hash = withdrawer.withdraw("hello");
+        assertEq(hash, keccak256("hello"));
+        withdrawer.withdraw("world");
+
+        Vm.L2ToL1Message[] memory messages = vm.zkGetRecordedL2ToL1Messages();
+        assertEq(messages.length, 2);
+        assertEq(messages[0].sender, address(withdrawer));
+        assertEq(messages[0].data, bytes("hello"));
+        assertEq(messages[1].sender, address(withdrawer));
+        assertEq(messages[1].data, bytes("world"));
+
+        // the recorded messages are consumed
+        assertEq(vm.zkGetRecordedL2ToL1Messages().length, 0);
+    }
+
+    function testZkRecordL2ToL1Logs() public {
+        withdrawer.withdraw("hello");
+
+        // the `L1Messenger` sends the hash of the message to L1, keyed by its sender
+        Vm.L2ToL1Log[] memory logs = vm.zkGetRecordedL2ToL1Logs();
+        bool found;
+        for (uint256 i = 0; i < logs.length; i++) {
+            if (
+                logs[i].sender == address(0x8008) && logs[i].key == bytes32(uint256(uint160(address(withdrawer))))
+                    && logs[i].value == keccak256("hello")
+            ) {
+                assertTrue(logs[i].isService);
+                found = true;
+            }
+        }
+        assertTrue(found);
+
+        // the recorded logs are consumed
+        assertEq(vm.zkGetRecordedL2ToL1Logs().length, 0);
+    }
+
+    function testZkRevertedL2ToL1MessagesAreNotRecorded() public {
+        // drop the logs of the deployment in `setUp`
+        vm.zkGetRecordedL2ToL1Logs();
+
+        try withdrawer.withdrawAndRevert("hello") {} catch {}
+
+        assertEq(vm.zkGetRecordedL2ToL1Messages().length, 0);
+        assertEq(vm.zkGetRecordedL2ToL1Logs().length, 0);
+    }
+
+    function testZkExpectL2ToL1Message() public {
+        vm.expectL2ToL1Message(address(withdrawer), "hello");
+        withdrawer.withdraw("hello");
+    }
+
+    function testFailZkExpectL2ToL1MessageNotSent() public {
+        vm.expectL2ToL1Message(address(withdrawer), "hello");
+        withdrawer.withdraw("world");
+    }
+}
@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.18;
+
+import "ds-test/test.sol";
+import "../cheats/Vm.sol";
+
+contract Target {
+    function get(uint256 x) public payable returns (uint256) {
+        return x;
+    }
+}
+
+contract Caller {
+    function callTarget(Target target, uint256 x) public returns (uint256) {
+        return target.get(x);
+    }
+
+    function callTargetWithValue(Target target, uint256 x, uint256 value) public returns (uint256) {
+        return target.get{value: value}(x);
+    }
+
+    function tryCallTarget(Target target, uint256 x) public returns (bool, bytes memory) {
+        return address(target).call(abi.encodeWithSelector(Target.get.selector, x));
+    }
+
+    function balanceOf(address account) public view returns (uint256) {
+        return account.balance;
+    }
+}
+
+contract ZkMockCallsTest is DSTest {
+    Vm constant vm = Vm(HEVM_ADDRESS);
+
+    /// Address of the `L2BaseToken` system contract, which holds the balances.
+    address constant L2_BASE_TOKEN = address(0x800a);
+
+    Target target;
+    Caller caller;
+
+    function setUp() public {
+        target = new Target();
+        caller = new Caller();
+        vm.deal(address(caller), 1 ether);
+    }
+
+    function testZkMockCallRevert() public {
+        vm.mockCallRevert(address(target), abi.encodeWithSelector(Target.get.selector, 1), bytes("mocked revert"));
+
+        (bool success, bytes memory data) = caller.tryCallTarget(target, 1);
+        assertTrue(!success);
+        assertEq(data, bytes("mocked revert"));
+
+        // other calldata is not mocked
+        (success, data) = caller.tryCallTarget(target, 2);
+        assertTrue(success);
+        assertEq(abi.decode(data, (uint256)), 2);
+    }
+
+    function testZkMockCallRevertBubblesUp() public {
+        bytes memory revertData = abi.encodeWithSignature("Error(string)", "mocked revert");
+        vm.mockCallRevert(address(target), abi.encodeWithSelector(Target.get.selector), revertData);
+
+        vm.expectRevert("mocked revert");
+        caller.callTarget(target, 1);
+    }
+
+    function testZkMockCallPartialCalldata() public {
+        vm.mockCall(address(target), abi.encodeWithSelector(Target.get.selector), abi.encode(10));
+        vm.mockCall(address(target), abi.encodeWithSelector(Target.get.selector, 2), abi.encode(20));
+
+        // the most specific mock is used
+        assertEq(caller.callTarget(target, 1), 10);
+        assertEq(caller.callTarget(target, 2), 20);
+    }
+
+    function testZkMockCallWithValue() public {
+        vm.mockCall(address(target), 1, abi.encodeWithSelector(Target.get.selector, 1), abi.encode(10));
+
+        assertEq(caller.callTargetWithValue(target, 1, 1), 10);
+        // calls with a different value are not mocked
+        assertEq(caller.callTargetWithValue(target, 1, 2), 1);
+        assertEq(caller.callTarget(target, 1), 1);
+    }
+
+    function testZkMockCallRevertWithValue() public {
+        vm.mockCallRevert(address(target), 1, abi.encodeWithSelector(Target.get.selector), bytes("mocked revert"));
+
+        vm.expectRevert();
+        caller.callTargetWithValue(target, 1, 1);
+
+        assertEq(caller.callTargetWithValue(target, 1, 2), 1);
+    }
+
+    function testZkClearMockedCalls() public {
+        vm.mockCall(address(target), abi.encodeWithSelector(Target.get.selector), abi.encode(10));
+        assertEq(caller.callTarget(target, 1), 10);
+
+        vm.clearMockedCalls();
+        assertEq(caller.callTarget(target, 1), 1);
+    }
+
+    function testZkMockCallOnSystemContract() public {
+        address account = address(0xabcd);
+        vm.deal(account, 1 ether);
+
+        // `address.balance` is a call to `L2BaseToken.balanceOf(uint256)` in the zkVM
+        vm.mockCall(
+            L2_BASE_TOKEN,
+            abi.encodeWithSignature("balanceOf(uint256)", uint256(uint160(account))),
+            abi.encode(42)
+        );
+
+        assertEq(caller.balanceOf(account), 42);
+        assertEq(caller.balanceOf(address(0xdcba)), 0);
+    }
+}
@@ -0,0 +1,150 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.13;
+
+import "ds-test/test.sol";
+import "../cheats/Vm.sol";
+
+struct Transaction {
+    uint256 txType;
+    uint256 from;
+    uint256 to;
+    uint256 gasLimit;
+    uint256 gasPerPubdataByteLimit;
+    uint256 maxFeePerGas;
+    uint256 maxPriorityFeePerGas;
+    uint256 paymaster;
+    uint256 nonce;
+    uint256 value;
+    uint256[4] reserved;
+    bytes data;
+    bytes signature;
+    bytes32[] factoryDeps;
+    bytes paymasterInput;
+    bytes reservedDynamic;
+}
+
+enum ExecutionResult {
+    Revert,
+    Success
+}
+
+interface IPaymasterFlow {
+    function general(bytes calldata input) external;
+}
+
+contract MyPaymaster {
+    address constant BOOTLOADER_FORMAL_ADDRESS = address(0x8001);
+
+    modifier onlyBootloader() {
+        require(msg.sender == BOOTLOADER_FORMAL_ADDRESS, "Only bootloader can call this method");
+        _;
+    }
+
+    function validateAndPayForPaymasterTransaction(bytes32, bytes32, Transaction calldata _transaction)
+        external
+        payable
+        onlyBootloader
+        returns (bytes4 magic, bytes memory context)
+    {
+        magic = this.validateAndPayForPaymasterTransaction.selector;
+        require(_transaction.paymasterInput.length >= 4, "The standard paymaster input must be at least 4 bytes long");
+
+        bytes4 paymasterInputSelector = bytes4(_transaction.paymasterInput[0:4]);
+        require(paymasterInputSelector == IPaymasterFlow.general.selector, "Unsupported paymaster flow");
+
+        uint256 requiredETH = _transaction.gasLimit * _transaction.maxFeePerGas;
+        (bool success,) = payable(BOOTLOADER_FORMAL_ADDRESS).call{value: requiredETH}("");
+        require(success, "Failed to transfer tx fee to the bootloader");
+    }
+
+    function postTransaction(
+        bytes calldata,
+        Transaction calldata,
+        bytes32,
+        bytes32,
+        ExecutionResult,
+        uint256
+    ) external payable onlyBootloader {}
+
+    receive() external payable {}
+}
+
+contract DoStuff {
+    uint256 public counter;
+
+    function doStuff() public {
+        counter += 1;
+    }
+}
+
+contract ZkPaymasterTest is DSTest {
+    Vm constant vm = Vm(HEVM_ADDRESS);
+    address alice = makeAddr("Alice");
+    MyPaymaster paymaster;
+    DoStuff doStuff;
+    bytes paymasterEncodedInput;
+
+    function setUp() public {
+        paymaster = new MyPaymaster();
+        doStuff = new DoStuff();
+
+        vm.deal(address(paymaster), 10 ether);
+        vm.deal(alice, 1 ether);
+
+        paymasterEncodedInput = abi.encodeWithSelector(IPaymasterFlow.general.selector, bytes("0x"));
+    }
+
+    function makeAddr(string memory name) internal returns (address addr) {
+        addr = vm.addr(uint256(keccak256(abi.encodePacked(name))));
+        vm.label(addr, name);
+    }
+
+    function testCallWithPaymaster() public {
+        uint256 alicePreBalance = alice.balance;
+        uint256 paymasterPreBalance = address(paymaster).balance;
+
+        vm.zkUsePaymaster(address(paymaster), paymasterEncodedInput);
+        vm.prank(alice, alice);
+        doStuff.doStuff();
+
+        assertEq(doStuff.counter(), 1);
+        assertEq(alice.balance, alicePreBalance);
+        assertLt(address(paymaster).balance, paymasterPreBalance);
+    }
+
+    function testCreateWithPaymaster() public {
+        uint256 alicePreBalance = alice.balance;
+        uint256 paymasterPreBalance = address(paymaster).balance;
+
+        vm.zkUsePaymaster(address(paymaster), paymasterEncodedInput);
+        vm.prank(alice, alice);
+        new DoStuff();
+
+        assertEq(alice.balance, alicePreBalance);
+        assertLt(address(paymaster).balance, paymasterPreBalance);
+    }
+
+    function testPaymasterIsOnlyUsedForNextCall() public {
+        uint256 paymasterPreBalance = address(paymaster).balance;
+
+        vm.zkUsePaymaster(address(paymaster), paymasterEncodedInput);
+        vm.prank(alice, alice);
+        doStuff.doStuff();
+
+        uint256 paymasterPostBalance = address(paymaster).balance;
+        assertLt(paymasterPostBalance, paymasterPreBalance);
+
+        uint256 alicePreBalance = alice.balance;
+        vm.prank(alice, alice);
+        doStuff.doStuff();
+
+        assertEq(address(paymaster).balance, paymasterPostBalance);
+        assertLt(alice.balance, alicePreBalance);
+    }
+
+    function testFailPaymasterWithInvalidFlow() public {
+        vm.zkUsePaymaster(address(paymaster), abi.encodeWithSelector(bytes4(0xdeadbeef), bytes("0x")));
+        vm.prank(alice, alice);
+        doStuff.doStuff();
+    }
+}
@@ -0,0 +1,259 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.18;
+
+import "ds-test/test.sol";
+import "../cheats/Vm.sol";
+import {MyAccount} from "./CustomAccount.t.sol";
+
+contract Victim {
+    function assertCallerAndOrigin(
+        address expectedSender,
+        string memory senderMessage,
+        address expectedOrigin,
+        string memory originMessage
+    ) public view {
+        require(msg.sender == expectedSender, senderMessage);
+        require(tx.origin == expectedOrigin, originMessage);
+    }
+}
+
+contract ConstructorVictim is Victim {
+    constructor(
+        address expectedSender,
+        string memory senderMessage,
+        address expectedOrigin,
+        string memory originMessage
+    ) {
+        require(msg.sender == expectedSender, senderMessage);
+        require(tx.origin == expectedOrigin, originMessage);
+    }
+}
+
+contract NestedVictim {
+    Victim innerVictim;
+
+    constructor(Victim victim) {
+        innerVictim = victim;
+    }
+
+    function assertCallerAndOrigin(
+        address expectedSender,
+        string memory senderMessage,
+        address expectedOrigin,
+        string memory originMessage
+    ) public view {
+        require(msg.sender == expectedSender, senderMessage);
+        require(tx.origin == expectedOrigin, originMessage);
+        innerVictim.assertCallerAndOrigin(
+            address(this),
+            "msg.sender was incorrectly set for nested victim",
+            expectedOrigin,
+            "tx.origin was incorrectly set for nested victim"
+        );
+    }
+}
+
+contract PayableVictim {
+    Victim innerVictim;
+
+    constructor(Victim victim) {
+        innerVictim = victim;
+    }
+
+    function assertCallerAndValue(address expectedSender, uint256 expectedValue) public payable {
+        require(msg.sender == expectedSender, "msg.sender was not set during prank");
+        require(msg.value == expectedValue, "msg.value was not forwarded");
+        innerVictim.assertCallerAndOrigin(
+            address(this),
+            "msg.sender was incorrectly set for nested victim",
+            tx.origin,
+            "tx.origin was incorrectly set for nested victim"
+        );
+    }
+}
+
+contract ZkPrankTest is DSTest {
+    Vm constant vm = Vm(HEVM_ADDRESS);
+
+    address constant SENDER = address(0x11abcd);
+    address constant ORIGIN = address(0x22abcd);
+
+    function setUp() public {
+        // the transactions are sent from `tx.origin` in the zkVM, so it pays for their fees
+        vm.deal(ORIGIN, 1 ether);
+    }
+
+    function testZkPrankSender() public {
+        Victim victim = new Victim();
+        vm.prank(SENDER);
+        victim.assertCallerAndOrigin(
+            SENDER, "msg.sender was not set during prank", tx.origin, "tx.origin invariant failed"
+        );
+
+        // Ensure we cleaned up correctly
+        victim.assertCallerAndOrigin(
+            address(this), "msg.sender was not cleaned up", tx.origin, "tx.origin invariant failed"
+        );
+    }
+
+    function testZkPrankOrigin() public {
+        address oldOrigin = tx.origin;
+
+        Victim victim = new Victim();
+        vm.prank(SENDER, ORIGIN);
+        victim.assertCallerAndOrigin(
+            SENDER, "msg.sender was not set during prank", ORIGIN, "tx.origin was not set during prank"
+        );
+
+        // Ensure we cleaned up correctly
+        victim.assertCallerAndOrigin(
+            address(this), "msg.sender was not cleaned up", oldOrigin, "tx.origin was not cleaned up"
+        );
+    }
+
+    function testZkPrankConstructorSender() public {
+        vm.prank(SENDER);
+        ConstructorVictim victim = new ConstructorVictim(
+            SENDER, "msg.sender was not set during prank", tx.origin, "tx.origin invariant failed"
+        );
+
+        // Ensure we cleaned up correctly
+        victim.assertCallerAndOrigin(
+            address(this), "msg.sender was not cleaned up", tx.origin, "tx.origin invariant failed"
+        );
+    }
+
+    function testZkPrankConstructorOrigin() public {
+        vm.prank(SENDER, ORIGIN);
+        ConstructorVictim victim = new ConstructorVictim(
+            SENDER, "msg.sender was not set during prank", ORIGIN, "tx.origin was not set during prank"
+        );
+
+        // Ensure we cleaned up correctly
+        victim.assertCallerAndOrigin(
+            address(this), "msg.sender was not cleaned up", tx.origin, "tx.origin was not cleaned up"
+        );
+    }
+
+    function testZkPrankStartStop() public {
+        address oldOrigin = tx.origin;
+
+        Victim victim = new Victim();
+        vm.startPrank(SENDER, ORIGIN);
+        victim.assertCallerAndOrigin(
+            SENDER, "msg.sender was not set during prank", ORIGIN, "tx.origin was not set during prank"
+        );
+        victim.assertCallerAndOrigin(
+            SENDER,
+            "msg.sender was not set during prank (call 2)",
+            ORIGIN,
+            "tx.origin was not set during prank (call 2)"
+        );
+        vm.stopPrank();
+
+        // Ensure we cleaned up correctly
+        victim.assertCallerAndOrigin(
+            address(this), "msg.sender was not cleaned up", oldOrigin, "tx.origin was not cleaned up"
+        );
+    }
+
+    function testZkPrankStartStopConstructor() public {
+        vm.startPrank(SENDER, ORIGIN);
+        ConstructorVictim victim = new ConstructorVictim(
+            SENDER, "msg.sender was not set during prank", ORIGIN, "tx.origin was not set during prank"
+        );
+        new ConstructorVictim(
+            SENDER,
+            "msg.sender was not set during prank (call 2)",
+            ORIGIN,
+            "tx.origin was not set during prank (call 2)"
+        );
+        vm.stopPrank();
+
+        // Ensure we cleaned up correctly
+        victim.assertCallerAndOrigin(
+            address(this), "msg.sender was not cleaned up", tx.origin, "tx.origin was not cleaned up"
+        );
+    }
+
+    function testZkPrankOverwriteAfterConstructor() public {
+        vm.startPrank(SENDER);
+        new ConstructorVictim(
+            SENDER, "msg.sender was not set during prank", tx.origin, "tx.origin invariant failed"
+        );
+
+        // the prank was applied by the deployment, so it can be overwritten
+        vm.startPrank(SENDER, ORIGIN);
+        new ConstructorVictim(
+            SENDER, "msg.sender was not set during prank", ORIGIN, "tx.origin was not set during prank"
+        );
+        vm.stopPrank();
+    }
+
+    function testZkPrankOriginInNestedCalls() public {
+        Victim innerVictim = new Victim();
+        NestedVictim victim = new NestedVictim(innerVictim);
+
+        vm.startPrank(SENDER, ORIGIN);
+        victim.assertCallerAndOrigin(
+            SENDER, "msg.sender was not set correctly", ORIGIN, "tx.origin was not set correctly"
+        );
+        victim.assertCallerAndOrigin(
+            SENDER,
+            "msg.sender was not set correctly (call 2)",
+            ORIGIN,
+            "tx.origin was not set correctly (call 2)"
+        );
+        vm.stopPrank();
+    }
+
+    function testZkPrankSenderWithValueInNestedCalls() public {
+        Victim innerVictim = new Victim();
+        PayableVictim victim = new PayableVictim(innerVictim);
+        vm.deal(SENDER, 1 ether);
+
+        vm.prank(SENDER);
+        victim.assertCallerAndValue{value: 0.1 ether}(SENDER, 0.1 ether);
+
+        // the value is sent by the pranked sender, like in the EVM
+        assertEq(SENDER.balance, 0.9 ether);
+        assertEq(address(victim).balance, 0.1 ether);
+    }
+}
+
+/// Runs with the EraVM extensions enabled, which `MyAccount` needs for its system calls.
+contract ZkPrankCustomAccountTest is DSTest {
+    Vm constant vm = Vm(HEVM_ADDRESS);
+
+    address constant SENDER = address(0x11abcd);
+
+    MyAccount account;
+
+    function setUp() public {
+        account = new MyAccount();
+        vm.deal(address(account), 1 ether);
+    }
+
+    function testZkPrankSenderFromCustomAccount() public {
+        Victim victim = new Victim();
+
+        vm.zkUseAccount(address(account), "valid");
+        vm.prank(SENDER);
+        victim.assertCallerAndOrigin(
+            SENDER, "msg.sender was not set during prank", address(account), "tx.origin was not the account"
+        );
+
+        // the transaction was still validated and executed by the account
+        assertEq(vm.getNonce(address(account)), 1);
+    }
+
+    function testZkPrankConstructorSenderFromCustomAccount() public {
+        vm.zkUseAccount(address(account), "valid");
+        vm.prank(SENDER);
+        new ConstructorVictim(
+            SENDER, "msg.sender was not set during prank", address(account), "tx.origin was not the account"
+        );
+
+        assertEq(vm.getNonce(address(account)), 1);
+    }
+}
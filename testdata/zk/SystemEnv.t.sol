@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.18;
+
+import "ds-test/test.sol";
+import "../cheats/Vm.sol";
+import {AccountCounter} from "./CustomAccount.t.sol";
+
+/// Runs with `zksync.protocol_version` set.
+contract ZkProtocolVersionTest is DSTest {
+    function testZkIncrementWithProtocolVersion() public {
+        AccountCounter counter = new AccountCounter();
+        counter.increment();
+        assertEq(counter.number(), 1);
+    }
+}
+
+/// Runs with `zksync.system_contracts_security` enabled, so that the default account requires
+/// transactions to be signed.
+contract ZkSystemContractsSecurityTest is DSTest {
+    Vm constant vm = Vm(HEVM_ADDRESS);
+
+    address constant ACCOUNT = address(0xc0ffee);
+
+    function setUp() public {
+        string memory artifact = vm.readFile("./zk/zkout-eravm-extensions/CustomAccount.t.sol/MyAccount.json");
+        vm.etch(ACCOUNT, vm.parseJsonBytes(artifact, ".bytecode.object"));
+        vm.deal(ACCOUNT, 1 ether);
+    }
+
+    function testZkSignedTransactionsWithSecurity() public {
+        vm.zkUseAccount(ACCOUNT, "valid");
+        AccountCounter counter = new AccountCounter();
+        vm.zkUseAccount(ACCOUNT, "valid");
+        counter.increment();
+
+        // reading the storage directly, as calls from the default account aren't signed
+        assertEq(uint256(vm.load(address(counter), bytes32(0))), 1);
+    }
+
+    function testFailZkUnsignedTransactionWithSecurity() public {
+        new AccountCounter();
+    }
+}
@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.18;
+
+import "ds-test/test.sol";
+import "../cheats/Vm.sol";
+
+contract Counter {
+    uint256 public number;
+
+    function increment() public {
+        number++;
+    }
+}
+
+contract Payer {
+    function pay(address payable recipient, uint256 amount) public {
+        recipient.transfer(amount);
+    }
+}
+
+contract ZkSystemStorageTest is DSTest {
+    Vm constant vm = Vm(HEVM_ADDRESS);
+
+    function testZkDeployNonce() public {
+        uint64 deployNonce = vm.zkGetDeployNonce(address(this));
+        uint64 txNonce = vm.getNonce(address(this));
+
+        new Counter();
+        assertEq(vm.zkGetDeployNonce(address(this)), deployNonce + 1);
+
+        vm.zkSetDeployNonce(address(this), 10);
+        assertEq(vm.zkGetDeployNonce(address(this)), 10);
+        // the transaction nonce is kept
+        assertEq(vm.getNonce(address(this)), txNonce);
+    }
+
+    function testZkDeployNonceAfterCreate() public {
+        vm.zkSetDeployNonce(address(this), 10);
+        uint64 txNonce = vm.getNonce(address(this));
+
+        Counter counter = new Counter();
+        assertEq(address(counter), zkCreateAddress(address(this), 10));
+        assertEq(vm.zkGetDeployNonce(address(this)), 11);
+        assertEq(vm.getNonce(address(this)), txNonce);
+
+        new Counter();
+        assertEq(vm.zkGetDeployNonce(address(this)), 12);
+    }
+
+    function testZkAccountCodeHash() public {
+        Counter counter = new Counter();
+
+        bytes32 codeHash = vm.zkGetAccountCodeHash(address(counter));
+        // EraVM bytecode hashes start with their version
+        assertEq(uint8(codeHash[0]), 1);
+        assertEq(vm.zkGetAccountCodeHash(address(0xabcd)), bytes32(0));
+    }
+
+    function testZkRecordSystemStorageWrites() public {
+        Payer payer = new Payer();
+        vm.deal(address(payer), 1 ether);
+        address payable recipient = payable(address(0xabcd));
+
+        vm.record();
+        payer.pay(recipient, 1);
+
+        // the balance is stored by the `L2BaseToken` system contract, keyed by the account
+        bytes32 balanceSlot = keccak256(abi.encode(uint256(uint160(address(recipient))), uint256(0)));
+        (bytes32[] memory reads, bytes32[] memory writes) = vm.accesses(recipient);
+        assertTrue(contains(reads, balanceSlot));
+        assertTrue(contains(writes, balanceSlot));
+    }
+
+    function testZkRecordSystemStorageReads() public {
+        Counter counter = new Counter();
+
+        vm.record();
+        counter.increment();
+        new Counter();
+
+        // the code hash is stored by the `AccountCodeStorage` system contract, keyed by the account
+        bytes32 codeSlot = bytes32(uint256(uint160(address(counter))));
+        (bytes32[] memory reads,) = vm.accesses(address(counter));
+        assertTrue(contains(reads, codeSlot));
+
+        // the nonces are stored by the `NonceHolder` system contract, keyed by the account
+        bytes32 nonceSlot = bytes32(uint256(uint160(address(this))));
+        (reads,) = vm.accesses(address(this));
+        assertTrue(contains(reads, nonceSlot));
+    }
+
+    function zkCreateAddress(address sender, uint256 deployNonce) internal pure returns (address) {
+        bytes32 hash = keccak256(
+            bytes.concat(keccak256("zksyncCreate"), bytes32(uint256(uint160(sender))), bytes32(deployNonce))
+        );
+        return address(uint160(uint256(hash)));
+    }
+
+    function contains(bytes32[] memory slots, bytes32 slot) internal pure returns (bool) {
+        for (uint256 i = 0; i < slots.length; i++) {
+            if (slots[i] == slot) {
+                return true;
+            }
+        }
+        return false;
+    }
+}
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.18;
+
+import "ds-test/test.sol";
+import "../cheats/Vm.sol";
+
+contract Number {
+    function five() public pure returns (uint8) {
+        return 5;
+    }
+}
+
+contract Adder {
+    event Added(uint8 result);
+
+    function add() public returns (uint8) {
+        Number number = new Number();
+        uint8 result = number.five() + number.five();
+        emit Added(result);
+        return result;
+    }
+}
+
+contract ZkTraceTest is DSTest {
+    Vm constant vm = Vm(HEVM_ADDRESS);
+
+    function testZkTraceOutputDuringCall() public {
+        Adder adder = new Adder();
+        adder.add();
+    }
+}